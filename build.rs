@@ -1,14 +1,18 @@
+// Generated services are committed in src/generated, so the build script only runs code
+// generation when the `regenerate` feature asks for it.
+
+#[cfg(feature = "regenerate")]
 extern crate rusoto_codegen;
 
-use std::env;
-use std::fs::copy;
-use std::path::Path;
+#[cfg(feature = "regenerate")]
+fn main() {
+    use std::env;
+    use std::path::Path;
 
-use rusoto_codegen::{AmazonService, generate};
+    use rusoto_codegen::{generate_services, rusoto_services};
 
-const BOTOCORE_DIR: &'static str = "codegen/botocore/botocore/data/";
+    const BOTOCORE_DIR: &'static str = "codegen/botocore/botocore/data/";
 
-fn main() {
     let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR not specified");
     let out_path = Path::new(&out_dir);
 
@@ -18,18 +22,14 @@ fn main() {
         None => Path::new(BOTOCORE_DIR)
     };
 
-    let services = vec![
-        AmazonService::new("dynamodb", "DynamoDBClient", "2012-08-10"),
-        AmazonService::new("kms", "KMSClient", "2014-11-01"),
-        AmazonService::new("ecs", "ECSClient", "2014-11-13"),
-        AmazonService::new("sqs", "SQSClient", "2012-11-05"),
-    ];
+    // only services whose Cargo features are enabled are generated
+    let services = rusoto_services().into_iter().filter(|service| {
+        env::var_os(format!("CARGO_FEATURE_{}", service.name().to_uppercase())).is_some()
+    });
 
-    for service in services {
-        generate(service, botocore_path, out_path);
-    }
+    generate_services(services, botocore_path, out_path);
+}
 
-    // Punting on S3 codegen by copying existing hand crafted code:
-    let s3_out = out_path.join("s3.rs");
-    copy("src/s3.rs.old", s3_out).expect("Couldn't copy existing s3 to s3.rs");
+#[cfg(not(feature = "regenerate"))]
+fn main() {
 }
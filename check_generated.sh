@@ -0,0 +1,18 @@
+#!/bin/sh
+
+# Regenerates the services from botocore and fails if they differ from the files committed in
+# src/generated, e.g. after a change to rusoto_codegen whose output wasn't committed.
+# BOTOCORE_DIR overrides the botocore data directory, as it does for build.rs.
+
+set -e
+
+botocore_dir=${BOTOCORE_DIR:-$(pwd)/codegen/botocore/botocore/data}
+output_dir=$(mktemp -d)
+trap 'rm -rf "$output_dir"' EXIT
+
+(cd codegen && cargo run --features serde_codegen -- "$botocore_dir" "$output_dir")
+
+if ! diff -r "$output_dir" src/generated; then
+    echo "src/generated is out of date: regenerate it as described in codegen/CODEGEN.md."
+    exit 1
+fi
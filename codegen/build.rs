@@ -0,0 +1,15 @@
+extern crate serde_codegen;
+extern crate syntex;
+
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR not specified");
+    let source = Path::new("src/botocore.rs.in");
+    let destination = Path::new(&out_dir).join("botocore.rs");
+
+    let mut registry = syntex::Registry::new();
+    serde_codegen::register(&mut registry);
+    registry.expand("", &source, &destination).expect("failed to generate botocore types with Serde");
+}
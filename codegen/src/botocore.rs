@@ -0,0 +1,63 @@
+//! Types for deserializing botocore service definitions.
+//!
+//! Field names match the botocore JSON keys.
+
+#![allow(dead_code, non_snake_case)]
+
+include!(concat!(env!("OUT_DIR"), "/botocore.rs"));
+
+impl Operation {
+    pub fn input_shape(&self) -> &str {
+        &self.input.as_ref().expect("Operation input undefined").shape
+    }
+
+    pub fn output_shape_or<'a>(&'a self, default: &'a str) -> &'a str {
+        match self.output {
+            Some(ref output) => &output.shape,
+            None => default,
+        }
+    }
+}
+
+impl Shape {
+    pub fn key(&self) -> &str {
+        &self.key.as_ref().expect("Key shape undefined").shape
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value.as_ref().expect("Value shape undefined").shape
+    }
+
+    pub fn member(&self) -> &str {
+        &self.member.as_ref().expect("Member shape undefined").shape
+    }
+
+    pub fn required(&self, field: &str) -> bool {
+        match self.required {
+            Some(ref required) => required.iter().any(|name| name == field),
+            None => false,
+        }
+    }
+}
+
+impl Paginator {
+    pub fn input_tokens(&self) -> Vec<String> {
+        names(&self.input_token)
+    }
+
+    pub fn output_tokens(&self) -> Vec<String> {
+        names(&self.output_token)
+    }
+
+    pub fn result_keys(&self) -> Vec<String> {
+        self.result_key.as_ref().map(names).unwrap_or(vec![])
+    }
+}
+
+fn names(value: &Value) -> Vec<String> {
+    match *value {
+        Value::String(ref name) => vec![name.to_owned()],
+        Value::Array(ref values) => values.iter().flat_map(|value| names(value)).collect(),
+        _ => vec![],
+    }
+}
@@ -0,0 +1,223 @@
+//! Constructors and chained setters for input shapes.
+//!
+//! `new` takes the required members as arguments and leaves the rest empty, and each optional
+//! member gets a `with_*` method that sets it and returns the shape, e.g.
+//! `CreateTableInput::new(attributes, key_schema, throughput, "my-table").with_stream_specification(spec)`.
+//! Arguments accept anything that converts into the member's type, such as `&str` for strings.
+//!
+//! Members marked as idempotency tokens aren't arguments: `new` fills them with a new UUID, so
+//! every send of the same input carries the same token and AWS can recognize a retry. Shapes with
+//! tokens also get `fill_idempotency_tokens`, which operations call on their copy of an input
+//! built any other way.
+//!
+//! The arguments are in alphabetical order of the members' botocore names, so a member that
+//! becomes required in a newer model can change the position of the others.
+
+use botocore::{Service, Shape};
+use super::{member_required, snake_case};
+use validation;
+
+/// Builder methods for the structures reachable from operation inputs.
+pub fn generate_builders(service: &Service) -> String {
+    let mut src = String::new();
+
+    for name in validation::input_shapes(service).iter() {
+        let shape = service.shapes.get(name).expect("Shape undefined");
+        if shape.shape_type == "structure" {
+            src.push_str(&builder_methods(service, name, shape));
+        }
+    }
+
+    src
+}
+
+fn builder_methods(service: &Service, name: &str, shape: &Shape) -> String {
+    let (required, optional): (Vec<_>, Vec<_>) = shape.members.keys()
+        .partition(|member_name| member_required(service, shape, member_name));
+
+    let mut src = format!("impl {} {{\n", name);
+
+    let mut type_params = Vec::new();
+    let mut params = Vec::new();
+    let mut fields = Vec::new();
+    for (index, member_name) in required.iter().enumerate() {
+        let field = snake_case(member_name);
+        let member = &shape.members[*member_name];
+        if is_idempotency_token(shape, member_name) {
+            fields.push(format!("{}: ::idempotency::new_token()", field));
+            continue;
+        }
+        match into_bound(service, &member.shape) {
+            Some(bound) => {
+                type_params.push(format!("V{}: {}", index, bound));
+                params.push(format!("{}: V{}", field, index));
+                fields.push(format!("{}: {}.into()", field, field));
+            }
+            None => {
+                params.push(format!("{}: {}", field, member.shape));
+                fields.push(format!("{}: {}", field, field));
+            }
+        }
+    }
+
+    for member_name in optional.iter().filter(|member_name| is_idempotency_token(shape, member_name)) {
+        fields.push(format!("{}: Some(::idempotency::new_token())", snake_case(member_name)));
+    }
+
+    if has_idempotency_tokens(shape) {
+        src.push_str(&format!("\t/// A `{}` with the given required members, a new idempotency token and no other optional members.\n", name));
+    } else {
+        src.push_str(&format!("\t/// A `{}` with the given required members and no optional ones.\n", name));
+    }
+    if type_params.is_empty() {
+        src.push_str(&format!("\tpub fn new({}) -> {} {{\n", params.join(", "), name));
+    } else {
+        src.push_str(&format!("\tpub fn new<{}>({}) -> {} {{\n", type_params.join(", "), params.join(", "), name));
+    }
+    if fields.is_empty() {
+        src.push_str("\t\tDefault::default()\n");
+    } else if optional.is_empty() {
+        src.push_str(&format!("\t\t{} {{ {} }}\n", name, fields.join(", ")));
+    } else {
+        src.push_str(&format!("\t\t{} {{ {}, ..Default::default() }}\n", name, fields.join(", ")));
+    }
+    src.push_str("\t}\n");
+
+    for member_name in optional.iter() {
+        let field = snake_case(member_name);
+        let member = &shape.members[*member_name];
+
+        src.push_str(&format!("\t/// Sets `{}`.\n", member_name));
+        match into_bound(service, &member.shape) {
+            Some(bound) => {
+                src.push_str(&format!("\tpub fn with_{}<V: {}>(mut self, value: V) -> {} {{\n", field, bound, name));
+                src.push_str(&format!("\t\tself.{} = Some(value.into());\n", field));
+            }
+            None => {
+                src.push_str(&format!("\tpub fn with_{}(mut self, value: {}) -> {} {{\n", field, member.shape, name));
+                src.push_str(&format!("\t\tself.{} = Some(value);\n", field));
+            }
+        }
+        src.push_str("\t\tself\n");
+        src.push_str("\t}\n");
+    }
+
+    src.push_str(&fill_tokens_method(service, shape));
+
+    src.push_str("}\n\n");
+    src
+}
+
+// sets the tokens that are empty and keeps the others, so it can be called on every send
+fn fill_tokens_method(service: &Service, shape: &Shape) -> String {
+    if !has_idempotency_tokens(shape) {
+        return String::new();
+    }
+
+    let mut src = String::new();
+    src.push_str("\t/// Fills each idempotency token that isn't set with a new UUID, keeping those that are.\n");
+    src.push_str("\tpub fn fill_idempotency_tokens(&mut self) {\n");
+    for member_name in shape.members.keys().filter(|member_name| is_idempotency_token(shape, member_name)) {
+        let field = snake_case(member_name);
+        if member_required(service, shape, member_name) {
+            src.push_str(&format!("\t\tif self.{}.is_empty() {{\n", field));
+            src.push_str(&format!("\t\t\tself.{} = ::idempotency::new_token();\n", field));
+        } else {
+            src.push_str(&format!("\t\tif self.{}.is_none() {{\n", field));
+            src.push_str(&format!("\t\t\tself.{} = Some(::idempotency::new_token());\n", field));
+        }
+        src.push_str("\t\t}\n");
+    }
+    src.push_str("\t}\n");
+    src
+}
+
+/// Whether any of a structure's members is an idempotency token.
+pub fn has_idempotency_tokens(shape: &Shape) -> bool {
+    shape.members.keys().any(|member_name| is_idempotency_token(shape, member_name))
+}
+
+fn is_idempotency_token(shape: &Shape, member_name: &str) -> bool {
+    shape.members[member_name].idempotencyToken.unwrap_or(false)
+}
+
+// strings, enums, blobs and timestamps convert from their usual representations; other types are
+// taken as they are, so literals, `vec![]` and `Default::default()` arguments still infer
+fn into_bound(service: &Service, shape_name: &str) -> Option<String> {
+    let shape = service.shapes.get(shape_name).expect("Shape undefined");
+    match &shape.shape_type[..] {
+        "string" | "blob" | "timestamp" => Some(format!("Into<{}>", shape_name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use botocore::Service;
+    use serde_json;
+    use super::{builder_methods, fill_tokens_method};
+
+    fn token_service() -> Service {
+        serde_json::from_str(r#"{
+            "metadata": {
+                "apiVersion": "2014-11-13",
+                "endpointPrefix": "ecs",
+                "protocol": "json",
+                "serviceFullName": "Amazon EC2 Container Service",
+                "signatureVersion": "v4"
+            },
+            "operations": {},
+            "shapes": {
+                "CreateServiceRequest": {
+                    "type": "structure",
+                    "required": ["serviceName"],
+                    "members": {
+                        "clientToken": {"shape": "String", "idempotencyToken": true},
+                        "serviceName": {"shape": "String"}
+                    }
+                },
+                "StartTaskRequest": {
+                    "type": "structure",
+                    "required": ["requestToken"],
+                    "members": {
+                        "requestToken": {"shape": "String", "idempotencyToken": true}
+                    }
+                },
+                "String": {"type": "string"}
+            }
+        }"#).unwrap()
+    }
+
+    #[test]
+    fn new_fills_idempotency_tokens() {
+        let service = token_service();
+
+        let src = builder_methods(&service, "CreateServiceRequest", &service.shapes["CreateServiceRequest"]);
+        assert!(src.contains("pub fn new<V0: Into<String>>(service_name: V0) -> CreateServiceRequest {"));
+        assert!(src.contains("client_token: Some(::idempotency::new_token())"));
+        assert!(src.contains("pub fn with_client_token<"));
+
+        let src = builder_methods(&service, "StartTaskRequest", &service.shapes["StartTaskRequest"]);
+        assert!(src.contains("pub fn new() -> StartTaskRequest {"));
+        assert!(src.contains("StartTaskRequest { request_token: ::idempotency::new_token() }"));
+    }
+
+    #[test]
+    fn fill_idempotency_tokens_keeps_set_tokens() {
+        let service = token_service();
+
+        let src = fill_tokens_method(&service, &service.shapes["CreateServiceRequest"]);
+        assert_eq!(src, "\t/// Fills each idempotency token that isn't set with a new UUID, keeping those that are.\n\
+                         \tpub fn fill_idempotency_tokens(&mut self) {\n\
+                         \t\tif self.client_token.is_none() {\n\
+                         \t\t\tself.client_token = Some(::idempotency::new_token());\n\
+                         \t\t}\n\
+                         \t}\n");
+
+        let src = fill_tokens_method(&service, &service.shapes["StartTaskRequest"]);
+        assert!(src.contains("\t\tif self.request_token.is_empty() {\n\t\t\tself.request_token = ::idempotency::new_token();\n\t\t}\n"));
+
+        let src = fill_tokens_method(&service, &service.shapes["String"]);
+        assert_eq!(src, "");
+    }
+}
@@ -0,0 +1,107 @@
+//! Botocore documentation is HTML; rustdoc wants Markdown.
+
+use regex::{Captures, Regex};
+
+/// Renders botocore HTML documentation as `///` comment lines, each prefixed with `indent`.
+pub fn rustdoc(html: &str, indent: &str) -> String {
+    let mut doc = String::new();
+
+    for line in html_to_markdown(html).lines() {
+        if line.is_empty() {
+            doc.push_str(&format!("{}///\n", indent));
+        } else {
+            doc.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+
+    doc
+}
+
+/// Converts the small subset of HTML used in botocore documentation to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    // newlines in the HTML source aren't significant
+    let mut text = html.replace("\r", "").replace("\n", " ");
+
+    // botocore often wraps list item contents in a paragraph
+    let item_paragraphs = Regex::new(r"(?i)<li>\s*<p>(.*?)</p>\s*</li>").unwrap();
+    text = item_paragraphs.replace_all(&text, |caps: &Captures| format!("<li>{}</li>", caps.at(1).unwrap_or("")));
+
+    let paragraphs = Regex::new(r"(?i)</?(p|ul|ol|dl|note|important)>|<br\s*/?>").unwrap();
+    text = paragraphs.replace_all(&text, |_: &Captures| "\n\n".to_owned());
+
+    let list_items = Regex::new(r"(?i)<(li|dt)>").unwrap();
+    text = list_items.replace_all(&text, |_: &Captures| "\n* ".to_owned());
+
+    let definitions = Regex::new(r"(?i)<dd>").unwrap();
+    text = definitions.replace_all(&text, |_: &Captures| "\n  ".to_owned());
+
+    let code = Regex::new(r"(?i)<code>(.*?)</code>").unwrap();
+    text = code.replace_all(&text, |caps: &Captures| format!("`{}`", caps.at(1).unwrap_or("").trim()));
+
+    let emphasis = Regex::new(r"(?i)<(i|em)>(.*?)</(i|em)>").unwrap();
+    text = emphasis.replace_all(&text, |caps: &Captures| format!("*{}*", caps.at(2).unwrap_or("").trim()));
+
+    let strong = Regex::new(r"(?i)<(b|strong)>(.*?)</(b|strong)>").unwrap();
+    text = strong.replace_all(&text, |caps: &Captures| format!("**{}**", caps.at(2).unwrap_or("").trim()));
+
+    let links = Regex::new(r#"(?i)<a[^>]*href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap();
+    text = links.replace_all(&text, |caps: &Captures| format!("[{}]({})", caps.at(2).unwrap_or("").trim(), caps.at(1).unwrap_or("")));
+
+    // drop anything we don't know how to render
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    text = tags.replace_all(&text, |_: &Captures| String::new());
+
+    text = text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+
+    let spaces = Regex::new(r"[ \t]+").unwrap();
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let line = spaces.replace_all(line.trim(), |_: &Captures| " ".to_owned());
+
+        // collapse runs of blank lines into one
+        if line.is_empty() && lines.last().map(|last| last.is_empty()).unwrap_or(true) {
+            continue;
+        }
+
+        lines.push(line);
+    }
+
+    while lines.last().map(|last| last.is_empty()).unwrap_or(false) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{html_to_markdown, rustdoc};
+
+    #[test]
+    fn html_to_markdown_paragraphs_and_inline_markup() {
+        let html = "<p>Returns an array of <code>TableName</code>\n values.</p> <p>See <a href=\"http://example.com\">the <i>guide</i></a> &amp; more.</p>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            "Returns an array of `TableName` values.\n\nSee [the *guide*](http://example.com) & more."
+        );
+    }
+
+    #[test]
+    fn html_to_markdown_lists() {
+        let html = "<p>Valid values:</p><ul><li><p><b>ACTIVE</b></p></li><li>INACTIVE</li></ul>";
+
+        assert_eq!(html_to_markdown(html), "Valid values:\n\n* **ACTIVE**\n* INACTIVE");
+    }
+
+    #[test]
+    fn rustdoc_prefixes_lines() {
+        assert_eq!(rustdoc("<p>One.</p><p>Two.</p>", "\t"), "\t/// One.\n\t///\n\t/// Two.\n");
+    }
+}
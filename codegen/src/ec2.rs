@@ -0,0 +1,350 @@
+//! Code generation for AWS services with protocol type "ec2".
+//!
+//! The ec2 protocol is a variant of "query": requests are encoded as form parameters, but lists
+//! are always flattened (`Name.1` rather than `Name.member.1`) and parameter names come from a
+//! member's `queryName`, or its capitalized `locationName`. Responses aren't wrapped in a result
+//! element, and lists in them are wrapped in an element named for the member.
+
+use botocore::{Member, Operation, Service, Shape};
+use doc::rustdoc;
+use enums;
+use query::{primitive_type, primitive_writer};
+use super::{prepare_input, signing_metadata, snake_case};
+
+pub fn generate_prelude(_service: &Service) -> String {
+    String::new()
+}
+
+pub fn generate_shapes(service: &Service) -> String {
+    let mut src = String::new();
+
+    for (name, shape) in service.shapes.iter() {
+        src.push_str(&rust_type(name, shape));
+        src.push_str(&type_parser(service, name, shape));
+        src.push_str(&param_writer(service, name, shape));
+    }
+
+    src
+}
+
+pub fn generate_operations(service: &Service) -> String {
+    let mut src = String::new();
+
+    for operation in service.operations.values() {
+        src.push_str(&request_method(service, operation));
+    }
+
+    src
+}
+
+fn rust_type(name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+
+    if let Some(ref docs) = shape.documentation {
+        src.push_str(&rustdoc(docs, ""));
+    }
+
+    if shape.shape_type == "structure" {
+        src.push_str(&struct_type(name, shape));
+    } else if enums::is_enum(shape) {
+        src.push_str(&enums::enum_type(name, shape));
+    } else {
+        let rust_type = match &shape.shape_type[..] {
+            "list" => format!("Vec<{}>", shape.member()),
+            primitive => primitive_type(primitive).to_owned(),
+        };
+
+        // a String is already a String in Rust
+        if name != "String" {
+            src.push_str(&format!("pub type {} = {};\n", name, rust_type));
+        }
+    }
+
+    src
+}
+
+fn struct_type(name: &str, shape: &Shape) -> String {
+    let mut src = "#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]\n".to_owned();
+
+    if shape.members.is_empty() {
+        src.push_str(&format!("pub struct {};\n\n", name));
+        return src;
+    }
+
+    src.push_str(&format!("pub struct {} {{\n", name));
+
+    for (member_name, member) in shape.members.iter() {
+        if let Some(ref docs) = member.documentation {
+            src.push_str(&rustdoc(docs, "\t"));
+        }
+
+        src.push_str(&format!("\t#[serde(rename=\"{}\")]\n", member_name));
+        if shape.required(member_name) {
+            src.push_str(&format!("\tpub {}: {},\n", snake_case(member_name), member.shape));
+        } else {
+            src.push_str(&format!("\tpub {}: Option<{}>,\n", snake_case(member_name), member.shape));
+        }
+    }
+
+    src.push_str("}\n\n");
+    src
+}
+
+// generate rust code to parse a botocore shape from XML.
+// List parsers read consecutive `tag_name` items; the wrapping element is handled by the struct parser.
+fn type_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Parse {} from XML\nstruct {}Parser;\nimpl {}Parser {{\n", name, name, name);
+    src.push_str(&format!("\tfn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<{}, XmlParseError> {{\n", name));
+
+    match &shape.shape_type[..] {
+        "list" => src.push_str(&list_parser(shape)),
+        "structure" => src.push_str(&struct_parser(service, name, shape)),
+        "string" => {
+            let (value, empty) = if enums::is_enum(shape) {
+                (format!("{}::from(&try!(characters(stack))[..])", name), format!("{}::default()", name))
+            } else {
+                ("try!(characters(stack))".to_owned(), "String::new()".to_owned())
+            };
+
+            // EC2 sends empty elements such as <description/>, which have no characters to read
+            src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+            src.push_str(&format!("\t\tlet obj = if at_end_element(stack) {{ {} }} else {{ {} }};\n", empty, value));
+            src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+        },
+        primitive => {
+            src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+            src.push_str(&format!("\t\tlet obj = {};\n", primitive_parser(primitive)));
+            src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+        }
+    }
+
+    src.push_str("\t\tOk(obj)\n\t}\n}\n");
+    src
+}
+
+// guts of the XML parser for struct shapes.
+// Elements without a matching member, such as the requestId in every response, are skipped.
+fn struct_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = "\t\ttry!(start_element(tag_name, stack));\n".to_owned();
+    src.push_str(&format!("\t\tlet mut obj = {}::default();\n", name));
+    src.push_str("\t\tloop {\n");
+    src.push_str("\t\t\tlet current_name = try!(peek_at_name(stack));\n");
+
+    for (member_name, member) in shape.members.iter() {
+        let tag_name = location_name(member_name, member);
+
+        src.push_str(&format!("\t\t\tif current_name == \"{}\" {{\n", tag_name));
+        src.push_str(&member_parser(service, shape, member_name, member, &tag_name));
+        src.push_str("\t\t\t\tcontinue;\n");
+        src.push_str("\t\t\t}\n");
+    }
+
+    // peek_at_name gives an empty name at the end of the struct
+    src.push_str("\t\t\tif current_name.is_empty() {\n");
+    src.push_str("\t\t\t\tbreak;\n");
+    src.push_str("\t\t\t}\n");
+    src.push_str("\t\t\tskip_tree(stack);\n");
+    src.push_str("\t\t}\n");
+    src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+    src
+}
+
+// parse a single struct member, unwrapping lists from their element
+fn member_parser(service: &Service, shape: &Shape, member_name: &str, member: &Member, tag_name: &str) -> String {
+    let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+    let mut src = String::new();
+
+    let mut parse_stmt = if member_shape.shape_type == "list" {
+        let item_name = shape_name(member_shape.member.as_ref().expect("Member shape undefined"), "member");
+        src.push_str(&format!("\t\t\t\ttry!(start_element(\"{}\", stack));\n", tag_name));
+        src.push_str(&format!("\t\t\t\tlet value = try!({}Parser::parse_xml(\"{}\", stack));\n", member.shape, item_name));
+        src.push_str(&format!("\t\t\t\ttry!(end_element(\"{}\", stack));\n", tag_name));
+        "value".to_owned()
+    } else {
+        format!("try!({}Parser::parse_xml(\"{}\", stack))", member.shape, tag_name)
+    };
+
+    if !shape.required(member_name) {
+        parse_stmt = format!("Some({})", parse_stmt);
+    }
+
+    src.push_str(&format!("\t\t\t\tobj.{} = {};\n", snake_case(member_name), parse_stmt));
+    src
+}
+
+// guts of the XML parser for list shapes
+fn list_parser(shape: &Shape) -> String {
+    let mut src = "\t\tlet mut obj = Vec::new();\n".to_owned();
+    src.push_str("\t\twhile try!(peek_at_name(stack)) == tag_name {\n");
+    src.push_str(&format!("\t\t\tobj.push(try!({}Parser::parse_xml(tag_name, stack)));\n", shape.member()));
+    src.push_str("\t\t}\n");
+    src
+}
+
+// generate rust code to encode a botocore shape into a map of query parameters
+fn param_writer(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Write {} contents to a SignedRequest\nstruct {}Writer;\nimpl {}Writer {{\n", name, name, name);
+    src.push_str(&format!("\tfn write_params(params: &mut Params, name: &str, obj: &{}) {{\n", name));
+
+    match &shape.shape_type[..] {
+        "list" => src.push_str(&list_writer(shape)),
+        "structure" => src.push_str(&struct_writer(service, shape)),
+        "string" if enums::is_enum(shape) => src.push_str("\t\tparams.put(name, obj.as_str());\n"),
+        primitive => src.push_str(&format!("\t\tparams.put(name, {});\n", primitive_writer(primitive))),
+    }
+
+    src.push_str("\t}\n}\n");
+    src
+}
+
+// guts of the param_writer for struct shapes
+fn struct_writer(_service: &Service, shape: &Shape) -> String {
+    let mut src = "\t\tlet mut prefix = name.to_string();\n".to_owned();
+    src.push_str("\t\tif prefix != \"\" { prefix.push_str(\".\"); }\n");
+
+    for (member_name, member) in shape.members.iter() {
+        let param_name = param_name(member_name, member);
+
+        if shape.required(member_name) {
+            src.push_str(&format!("\t\t{}Writer::write_params(params, &(prefix.to_string() + \"{}\"), &obj.{});\n",
+                member.shape, param_name, snake_case(member_name)));
+        } else {
+            src.push_str(&format!("\t\tif let Some(ref obj) = obj.{} {{\n", snake_case(member_name)));
+            src.push_str(&format!("\t\t\t{}Writer::write_params(params, &(prefix.to_string() + \"{}\"), obj);\n",
+                member.shape, param_name));
+            src.push_str("\t\t}\n");
+        }
+    }
+
+    src
+}
+
+// guts of the param_writer for list shapes, which are always flattened: Name.1, Name.2, ...
+fn list_writer(shape: &Shape) -> String {
+    let mut src = "\t\tlet mut index = 1;\n".to_owned();
+    src.push_str("\t\tfor element in obj.iter() {\n");
+    src.push_str("\t\t\tlet key = &format!(\"{}.{}\", name, index);\n");
+    src.push_str(&format!("\t\t\t{}Writer::write_params(params, key, &element);\n", shape.member()));
+    src.push_str("\t\t\tindex += 1;\n");
+    src.push_str("\t\t}\n");
+    src
+}
+
+fn request_method(service: &Service, operation: &Operation) -> String {
+    let output_type = operation.output_shape_or("()");
+    let mut src = String::new();
+
+    if let Some(ref docs) = operation.documentation {
+        src.push_str(&rustdoc(docs, "\t"));
+    }
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\tpub fn {}(&mut self, input: &{}) -> Result<{}, AWSError> {{\n",
+            snake_case(&operation.name), operation.input_shape(), output_type));
+        src.push_str(&prepare_input(service, operation));
+    } else {
+        src.push_str(&format!("\tpub fn {}(&mut self) -> Result<{}, AWSError> {{\n", snake_case(&operation.name), output_type));
+    }
+
+    src.push_str(&format!("\t\tlet mut request = SignedRequest::new(\"{}\", \"{}\", &self.region, \"{}\");\n",
+        operation.http.method, service.metadata.endpointPrefix, operation.http.requestUri));
+    src.push_str(&signing_metadata(service));
+    src.push_str("\t\tlet mut params = Params::new();\n");
+    src.push_str(&format!("\t\tparams.put(\"Action\", \"{}\");\n", operation.name));
+    src.push_str(&format!("\t\tparams.put(\"Version\", \"{}\");\n", service.metadata.apiVersion));
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\t\t{}Writer::write_params(&mut params, \"\", &input);\n", operation.input_shape()));
+    }
+
+    src.push_str("\t\trequest.set_params(params);\n");
+    src.push_str("\t\tlet mut result = request.sign_and_execute(try!(self.creds.get_credentials()));\n");
+    src.push_str("\t\tlet status = result.status.to_u16();\n");
+    src.push_str("\t\tif !result.status.is_success() {\n");
+    src.push_str("\t\t\tlet mut body = String::new();\n");
+    src.push_str("\t\t\ttry!(result.read_to_string(&mut body));\n");
+    src.push_str("\t\t\treturn Err(AWSError::new(format!(\"{}: {}\", status, body)));\n");
+    src.push_str("\t\t}\n");
+
+    match operation.output {
+        Some(ref output) => {
+            // EC2 pretty-prints its responses, and the whitespace between elements isn't significant
+            src.push_str("\t\tlet mut reader = EventReader::with_config(result, ParserConfig::new().trim_whitespace(true));\n");
+            src.push_str("\t\tlet mut stack = XmlResponseFromAws::new(reader.events().peekable());\n");
+            src.push_str("\t\tstack.next(); // xml start tag\n");
+            // the response element is named for the operation, e.g. <DescribeInstancesResponse>
+            src.push_str("\t\tlet tag_name = try!(peek_at_name(&mut stack));\n");
+            src.push_str(&format!("\t\tOk(try!({}Parser::parse_xml(&tag_name, &mut stack)))\n", output.shape));
+        },
+        None => src.push_str("\t\tOk(())\n"),
+    }
+
+    src.push_str("\t}\n");
+    src
+}
+
+// The name of a member's element in responses
+fn location_name(member_name: &str, member: &Member) -> String {
+    shape_name(member, member_name)
+}
+
+// The name of a member's request parameter: its queryName, or its locationName capitalized
+fn param_name(member_name: &str, member: &Member) -> String {
+    if let Some(ref query_name) = member.queryName {
+        return query_name.to_owned();
+    }
+
+    match member.locationName {
+        Some(ref location_name) => {
+            let mut chars = location_name.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        },
+        None => member_name.to_owned(),
+    }
+}
+
+fn shape_name(member: &Member, default: &str) -> String {
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => default.to_owned(),
+    }
+}
+
+// rust code to pull primitive types from XML
+fn primitive_parser(shape_type: &str) -> &'static str {
+    match shape_type {
+        "integer" => "try!(i32::from_str(try!(characters(stack)).as_ref()))",
+        "long" => "try!(i64::from_str(try!(characters(stack)).as_ref()))",
+        "float" => "try!(f32::from_str(try!(characters(stack)).as_ref()))",
+        "double" => "try!(f64::from_str(try!(characters(stack)).as_ref()))",
+        "blob" => "::blob::Blob(try!(::blob::decode_base64(&try!(characters(stack)))))",
+        "boolean" => "try!(bool::from_str(try!(characters(stack)).as_ref()))",
+        "timestamp" => "try!(::timestamp::Timestamp::from_iso8601(&try!(characters(stack))))",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use botocore::Member;
+    use serde_json;
+    use super::param_name;
+
+    #[test]
+    fn param_name_prefers_query_name_then_location_name() {
+        let member: Member = serde_json::from_str(r#"{"shape": "String", "locationName": "groupName"}"#).unwrap();
+        assert_eq!(param_name("GroupName", &member), "GroupName");
+
+        let member: Member = serde_json::from_str(r#"{"shape": "String", "locationName": "ipAddress", "queryName": "IpAddress"}"#).unwrap();
+        assert_eq!(param_name("PublicIp", &member), "IpAddress");
+
+        let member: Member = serde_json::from_str(r#"{"shape": "InstanceIdStringList", "locationName": "InstanceId"}"#).unwrap();
+        assert_eq!(param_name("InstanceIds", &member), "InstanceId");
+
+        let member: Member = serde_json::from_str(r#"{"shape": "Boolean"}"#).unwrap();
+        assert_eq!(param_name("DryRun", &member), "DryRun");
+    }
+}
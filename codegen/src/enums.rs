@@ -0,0 +1,140 @@
+//! Rust enums for botocore string shapes with an `enum` list of values.
+//!
+//! Each value gets a CamelCase variant, e.g. `STANDARD_IA` becomes `StandardIa`, and values the
+//! model doesn't list are kept in an `Unknown` variant, so responses from newer versions of a
+//! service still parse. Enums convert from and display as their wire strings, and implement
+//! Serde's traits in terms of them.
+
+use botocore::Shape;
+
+/// Whether a shape is generated as an enum rather than a String.
+pub fn is_enum(shape: &Shape) -> bool {
+    shape.shape_type == "string" && shape.shape_enum.is_some()
+}
+
+/// The enum type and its trait implementations.
+pub fn enum_type(name: &str, shape: &Shape) -> String {
+    let values = shape.shape_enum.as_ref().expect("Enum values undefined");
+    let variants = variant_names(values);
+
+    let mut src = format!("#[derive(Debug, Clone, PartialEq, Eq, Hash)]\npub enum {} {{\n", name);
+    for variant in variants.iter() {
+        src.push_str(&format!("\t{},\n", variant));
+    }
+    src.push_str("\t/// A value that wasn't known when this client was generated.\n");
+    src.push_str("\tUnknown(String),\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl {} {{\n", name));
+    src.push_str("\t/// The value as sent to and from AWS.\n");
+    src.push_str("\tpub fn as_str(&self) -> &str {\n");
+    src.push_str("\t\tmatch *self {\n");
+    for (value, variant) in values.iter().zip(variants.iter()) {
+        src.push_str(&format!("\t\t\t{}::{} => {:?},\n", name, variant, value));
+    }
+    src.push_str(&format!("\t\t\t{}::Unknown(ref value) => value,\n", name));
+    src.push_str("\t\t}\n");
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl Default for {} {{\n", name));
+    src.push_str(&format!("\tfn default() -> {} {{\n", name));
+    src.push_str(&format!("\t\t{}::Unknown(String::new())\n", name));
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl<'a> From<&'a str> for {} {{\n", name));
+    src.push_str(&format!("\tfn from(value: &'a str) -> {} {{\n", name));
+    src.push_str("\t\tmatch value {\n");
+    for (value, variant) in values.iter().zip(variants.iter()) {
+        src.push_str(&format!("\t\t\t{:?} => {}::{},\n", value, name, variant));
+    }
+    src.push_str(&format!("\t\t\t_ => {}::Unknown(value.to_owned()),\n", name));
+    src.push_str("\t\t}\n");
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl ::std::fmt::Display for {} {{\n", name));
+    src.push_str("\tfn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {\n");
+    src.push_str("\t\twrite!(f, \"{}\", self.as_str())\n");
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl ::serde::Serialize for {} {{\n", name));
+    src.push_str("\tfn serialize<S>(&self, serializer: &mut S) -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {\n");
+    src.push_str("\t\t::serde::Serialize::serialize(self.as_str(), serializer)\n");
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl ::serde::Deserialize for {} {{\n", name));
+    src.push_str(&format!("\tfn deserialize<D>(deserializer: &mut D) -> ::std::result::Result<{}, D::Error> where D: ::serde::Deserializer {{\n", name));
+    src.push_str("\t\tlet value: String = try!(::serde::Deserialize::deserialize(deserializer));\n");
+    src.push_str(&format!("\t\tOk({}::from(&value[..]))\n", name));
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+
+    src
+}
+
+// CamelCase variant names, kept distinct from each other and from the Unknown variant
+fn variant_names(values: &[String]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for value in values {
+        let mut name = variant_name(value);
+        while name == "Unknown" || names.contains(&name) {
+            name.push_str("Value");
+        }
+        names.push(name);
+    }
+
+    names
+}
+
+// e.g. STANDARD_IA => StandardIa, us-west-2 => UsWest2, 0.5 => Value05
+fn variant_name(value: &str) -> String {
+    let mut name = String::new();
+
+    for word in value.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()) {
+        let mut chars = word.chars();
+        let first = chars.next().unwrap();
+        let rest: String = chars.collect();
+
+        name.extend(first.to_uppercase());
+
+        // SHOUTING words are capitalized, CamelCase words keep their humps
+        if word.chars().any(|c| c.is_lowercase()) {
+            name.push_str(&rest);
+        } else {
+            name.push_str(&rest.to_lowercase());
+        }
+    }
+
+    if name.chars().next().map(|c| !c.is_alphabetic()).unwrap_or(true) {
+        name = format!("Value{}", name);
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{variant_name, variant_names};
+
+    #[test]
+    fn variant_names_from_enum_values() {
+        assert_eq!(variant_name("ACTIVE"), "Active");
+        assert_eq!(variant_name("STANDARD_IA"), "StandardIa");
+        assert_eq!(variant_name("us-west-2"), "UsWest2");
+        assert_eq!(variant_name("t2.micro"), "T2Micro");
+        assert_eq!(variant_name("AmazonCustomerByEmail"), "AmazonCustomerByEmail");
+        assert_eq!(variant_name("0.5"), "Value05");
+        assert_eq!(variant_name(""), "Value");
+    }
+
+    #[test]
+    fn variant_names_are_distinct() {
+        let values = vec!["UNKNOWN".to_owned(), "t2.micro".to_owned(), "t2-micro".to_owned()];
+        assert_eq!(variant_names(&values), vec!["UnknownValue", "T2Micro", "T2MicroValue"]);
+    }
+}
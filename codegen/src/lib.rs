@@ -1,6 +1,6 @@
-extern crate botocore_parser;
 extern crate inflector;
 extern crate regex;
+extern crate serde;
 #[cfg(feature = "serde_codegen")]
 extern crate serde_codegen;
 extern crate serde_json;
@@ -8,19 +8,70 @@ extern crate serde_json;
 extern crate syntex;
 
 use std::fs::File;
-use std::io::{Read, Write, copy};
+use std::io::{self, Read, Write};
 use std::path::Path;
-use std::process::Command;
 
-use botocore_parser::{Service, Shape};
+use botocore::{Operation, Paginators, Service, Shape, Waiters};
+use doc::rustdoc;
 use inflector::Inflector;
-
+use regex::{Captures, Regex};
+
+mod botocore;
+mod builders;
+mod doc;
+mod ec2;
+mod enums;
+mod paginators;
+mod query;
+mod rest;
+mod rest_json;
+mod rest_xml;
+mod traits;
+mod validation;
+mod waiters;
+
+/// A botocore service to generate: its directory name in botocore, the name of the generated
+/// client type and the API version to read, e.g. `AmazonService::new("sqs", "SQSClient", "2012-11-05")`.
 pub struct AmazonService {
     name: String,
     type_name: String,
     protocol_date: String
 }
 
+impl AmazonService {
+    pub fn new<S: ToString>(name: S, type_name: S, protocol_date: S) -> AmazonService {
+        AmazonService { name: name.to_string(), type_name: type_name.to_string(), protocol_date: protocol_date.to_string() }
+    }
+
+    /// The service's directory name in botocore, which is also the name of its generated file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The services included in Rusoto, each behind the Cargo feature of the same name.
+pub fn rusoto_services() -> Vec<AmazonService> {
+    vec![
+        AmazonService::new("dynamodb", "DynamoDBClient", "2012-08-10"),
+        AmazonService::new("kms", "KMSClient", "2014-11-01"),
+        AmazonService::new("ec2", "EC2Client", "2015-10-01"),
+        AmazonService::new("ecs", "ECSClient", "2014-11-13"),
+        AmazonService::new("sqs", "SQSClient", "2012-11-05"),
+        AmazonService::new("s3", "S3Client", "2006-03-01"),
+    ]
+}
+
+/// Generates each service's client into `base_destination`, as `generate` does.
+pub fn generate_services<I>(services: I, botocore_path: &Path, base_destination: &Path)
+    where I: IntoIterator<Item = AmazonService> {
+    for service in services {
+        generate(service, botocore_path, base_destination);
+    }
+}
+
+/// Generates the client for a botocore service, reading
+/// `{botocore_path}/{name}/{protocol_date}/service-2.json` and writing `{name}.rs` to
+/// `base_destination`, usually a build script's `OUT_DIR`.
 pub fn generate(
     service: AmazonService,
     botocore_path: &Path,
@@ -31,136 +82,114 @@ pub fn generate(
 
     let input = input_location.to_str().expect(&format!("Invalid service definition path for {} {}", service.protocol_date, service.name));
 
-    botocore_generate(input, &service.type_name, botocore_destination.as_path());
-    serde_generate(botocore_destination.as_path(), serde_destination.as_path());
+    if botocore_generate(input, &service.type_name, botocore_destination.as_path()) {
+        serde_generate(botocore_destination.as_path(), serde_destination.as_path());
+    }
 }
 
-fn botocore_generate(input: &str, type_name: &str, destination: &Path) {
+// whether the service could be generated; one Rusoto can't sign for is skipped with a message
+fn botocore_generate(input: &str, type_name: &str, destination: &Path) -> bool {
     let mut f = File::open(input).unwrap();
     let mut s = String::new();
     let _ = f.read_to_string(&mut s);
 
     let service: Service = serde_json::from_str(&s).expect("Invalid botocore input");
 
-    if &service.metadata.protocol == "query" {
-        return python_generate(input, type_name, destination);
+    if !signature_supported(&service) {
+        let _ = writeln!(io::stderr(), "Skipping {}: signature version '{}' isn't supported, only Signature Version 4",
+            service.metadata.endpointPrefix, service.metadata.signatureVersion);
+        return false;
     }
 
-    let mut source = String::new();
-    let error_type_name = error_type(type_name);
-
-    source.push_str("use std::io::Read;\n");
-
-    match &service.metadata.protocol[..] {
-        "json" => {
-            source.push_str(&format!("
-use std::result;
-
-use serde_json;
-
-use credentials::AWSCredentialsProvider;
-use error::AWSError;
-use regions::Region;
-use signature::SignedRequest;
-
-#[derive(Debug, Default, Deserialize)]
-pub struct {error_type_name} {{
-    __type: String,
-    message: String,
-}}
-
-pub type Result<T> = result::Result<T, {error_type_name}>;
+    // not every service has paginators or waiters
+    let paginators: Option<Paginators> = File::open(Path::new(input).with_file_name("paginators-1.json")).ok().map(|mut f| {
+        let mut s = String::new();
+        let _ = f.read_to_string(&mut s);
+        serde_json::from_str(&s).expect("Invalid botocore paginators")
+    });
 
-impl From<AWSError> for {error_type_name} {{
-    fn from(err: AWSError) -> Self {{
-        let AWSError(message) = err;
-
-        {error_type_name} {{
-            __type: \"Unknown\".to_string(),
-            message: message.to_string(),
-        }}
-    }}
-}}
+    let waiters: Option<Waiters> = File::open(Path::new(input).with_file_name("waiters-2.json")).ok().map(|mut f| {
+        let mut s = String::new();
+        let _ = f.read_to_string(&mut s);
+        serde_json::from_str(&s).expect("Invalid botocore waiters")
+    });
 
-fn parse_error(body: &str) -> {error_type_name} {{
-    if let Ok(decoded) = serde_json::from_str::<{error_type_name}>(&body) {{
-        decoded
-    }} else {{
-        {error_type_name} {{
-            __type: \"DecodeError\".to_string(),
-            message: body.to_string(),
-        }}
-    }}
-}}\n",
-                error_type_name = error_type_name,
-            ));
-        }
-        "rest-xml" => {
-            source.push_str("
-use hyper::header::Headers;
-use xml::reader::EventReader;
+    let mut source = String::new();
 
-use credentials::AWSCredentialsProvider;
-use error::AWSError;
-use regions::Region;
-use signature::SignedRequest;
-use xmlutil::{XmlResponseFromAws, XmlParseError, Next, Peek};
-
-#[derive(Debug, PartialEq)]
-pub enum ArgumentLocation {
-    Header,
-    Body,
-    Headers,
-    Querystring,
-    Uri,
-}\n");
+    match &service.metadata.protocol[..] {
+        "json" => source.push_str(&json_prelude(type_name)),
+        "rest-json" => {
+            source.push_str(&json_prelude(type_name));
+            source.push_str(&rest_json::generate_prelude(&service));
         },
+        "rest-xml" => source.push_str(&rest_xml::generate_prelude(&service)),
+        "query" => source.push_str(&query::generate_prelude(&service)),
+        "ec2" => source.push_str(&ec2::generate_prelude(&service)),
         _ => {},
     }
 
     // generate rust structs for the botocore shapes
-    source.push_str(&render_shapes(&service));
+    match &service.metadata.protocol[..] {
+        "query" => source.push_str(&query::generate_shapes(&service)),
+        "ec2" => source.push_str(&ec2::generate_shapes(&service)),
+        "rest-xml" => source.push_str(&rest_xml::generate_shapes(&service)),
+        "rest-json" => source.push_str(&rest_json::generate_shapes(&service)),
+        _ => source.push_str(&render_shapes(&service)),
+    }
 
-    // generate the service client struct
+    source.push_str(&validation::generate_validators(&service));
+    source.push_str(&builders::generate_builders(&service));
+
+    // generate the service client struct.
+    // Region is fully qualified as some services, like S3, have a shape of the same name.
+    if let Some(ref docs) = service.documentation {
+        source.push_str(&rustdoc(docs, ""));
+    }
     source.push_str(&format!("pub struct {}<'a> {{", type_name));
     source.push_str("\tcreds: Box<AWSCredentialsProvider + 'a>,");
-    source.push_str("\tregion: &'a Region");
+    source.push_str("\tregion: &'a ::regions::Region");
     source.push_str("}\n");
 
     // implement each botocore operation as function for the client
     source.push_str(&format!("impl<'a> {}<'a> {{ ", type_name));
-    source.push_str(&format!("\tpub fn new<P: AWSCredentialsProvider + 'a>(creds: P, region: &'a Region) -> {}<'a> {{", type_name));
+    source.push_str(&format!("\tpub fn new<P: AWSCredentialsProvider + 'a>(creds: P, region: &'a ::regions::Region) -> {}<'a> {{", type_name));
     source.push_str(&format!("\t\t{} {{ creds: Box::new(creds), region: region }}", type_name));
     source.push_str("\t}");
 
     // each protocol type will require operations performed in different ways
     let operations = match &*service.metadata.protocol {
-        //"rest-xml" => rest_xml_operations(&service),
-        "json" => json_operations(&service),
+        "rest-xml" => rest_xml::generate_operations(&service),
+        "rest-json" => rest_json::generate_operations(&service),
+        "json" => json_operations(&service, type_name),
+        "query" => query::generate_operations(&service),
+        "ec2" => ec2::generate_operations(&service),
         _ => panic!(format!("Unknown protocol type '{}'", service.metadata.protocol))
     };
     source.push_str(&operations);
-    source.push_str("}");
 
-    let mut outfile = File::create(destination).expect("couldn't open file for writing");
-    let _ = outfile.write_all(source.as_bytes());
-}
+    if let Some(ref paginators) = paginators {
+        source.push_str(&paginators::generate_methods(&service, paginators));
+    }
 
-fn python_generate(input: &str, type_name: &str, destination: &Path) {
-    let mut command = Command::new("codegen/botocore_parser.py");
+    if let Some(ref waiters) = waiters {
+        source.push_str(&waiters::generate_methods(&service, waiters));
+    }
 
-    command.args(&[input, type_name]);
+    source.push_str("}");
 
-    let output = command.output().expect("couldn't get output of child process");
+    source.push_str(&traits::generate_trait(&service, type_name));
 
-    if !output.status.success() {
-        println!("{}", String::from_utf8_lossy(&output.stdout[..]));
-        println!("{}", String::from_utf8_lossy(&output.stderr[..]));
-        panic!("child process was unsuccessful");
+    if let Some(ref paginators) = paginators {
+        source.push_str(&paginators::generate_iterators(&service, paginators, type_name));
     }
 
-    let mut file = File::create(destination).expect("couldn't open file for writing");
-    copy(&mut &output.stdout[..], &mut file).expect("failed to write generated code to file");
+    // syntex's pretty printer replays the source's blank lines while printing Serde's impls, and
+    // overflows its buffer on services with as many shapes as EC2, so they're left out
+    let source = source.lines().filter(|line| !line.trim().is_empty()).collect::<Vec<_>>().join("\n");
+
+    let mut outfile = File::create(destination).expect("couldn't open file for writing");
+    let _ = outfile.write_all(source.as_bytes());
+    true
 }
 
 #[cfg(feature = "serde_codegen")]
@@ -175,27 +204,81 @@ fn serde_generate(source: &Path, destination: &Path) {
 fn serde_generate(_source: &Path, _destination: &Path) {
 }
 
-impl AmazonService {
-    pub fn new<S: ToString>(name: S, type_name: S, protocol_date: S) -> AmazonService {
-        AmazonService { name: name.to_string(), type_name: type_name.to_string(), protocol_date: protocol_date.to_string() }
-    }
+// imports, error type and Result alias for services using the json protocols
+fn json_prelude(type_name: &str) -> String {
+    let error_type_name = error_type(type_name);
+    format!("use std::io::Read;
+use std::result;
+
+use serde_json;
+
+use credentials::AWSCredentialsProvider;
+use error::AWSError;
+use signature::SignedRequest;
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct {error_type_name} {{
+    pub __type: String,
+    pub message: String,
+    /// The HTTP status of the response, or 0 if the request failed before one was received.
+    #[serde(default)]
+    pub status: u16,
+}}
+
+pub type Result<T> = result::Result<T, {error_type_name}>;
+
+impl {error_type_name} {{
+    /// Whether sending the request again may succeed, as when a response failed its CRC32 check.
+    pub fn is_retryable(&self) -> bool {{
+        self.__type == \"CRC32CheckFailed\"
+    }}
+}}
+
+impl From<AWSError> for {error_type_name} {{
+    fn from(err: AWSError) -> Self {{
+        let AWSError(message) = err;
+
+        {error_type_name} {{
+            __type: \"Unknown\".to_string(),
+            message: message.to_string(),
+            status: 0,
+        }}
+    }}
+}}
+
+fn parse_error(status: u16, body: &str) -> {error_type_name} {{
+    let mut error = if let Ok(decoded) = serde_json::from_str::<{error_type_name}>(&body) {{
+        decoded
+    }} else {{
+        {error_type_name} {{
+            __type: \"DecodeError\".to_string(),
+            message: body.to_string(),
+            status: 0,
+        }}
+    }};
+    error.status = status;
+    error
+}}\n",
+        error_type_name = error_type_name,
+    )
 }
 
-fn print_docs_for_operation(op: &botocore_parser::Operation) -> String {
+fn print_docs_for_operation(op: &Operation) -> String {
     let mut doc_string = String::new();
-    match op.documentation {
-        None => (),
-        Some(ref docs) => doc_string.push_str(&format!("\t// {}\n", docs)),
+    if let Some(ref docs) = op.documentation {
+        doc_string.push_str(&rustdoc(docs, "\t"));
     }
-    match op.documentationUrl {
-        None => (),
-        Some(ref doc_uri) => doc_string.push_str(&format!("\t// {}\n", doc_uri)),
+    if let Some(ref doc_uri) = op.documentationUrl {
+        if !doc_string.is_empty() {
+            doc_string.push_str("\t///\n");
+        }
+        doc_string.push_str(&format!("\t/// <{}>\n", doc_uri));
     }
     doc_string
 }
 
 // Translate botocore operations to Rust functions for json services like DynamoDB and KMS
-fn json_operations(service: &Service) -> String {
+fn json_operations(service: &Service, type_name: &str) -> String {
     let mut src = String::new();
 
     let target_prefix = service.metadata.targetPrefix.as_ref().expect("targetPrefix not defined for json protocol operation");
@@ -205,15 +288,25 @@ fn json_operations(service: &Service) -> String {
         let output_shape = operation.output_shape_or("()");
 
         src.push_str(&format!("\tpub fn {}(&mut self, input: &{}) -> Result<{}> {{\n", operation.name.to_snake_case(), operation.input_shape(), output_shape));
+        src.push_str(&prepare_input(service, operation));
         src.push_str("\t\tlet encoded = serde_json::to_string(&input).unwrap();\n");
         src.push_str(&format!("\t\tlet mut request = SignedRequest::new(\"{}\", \"{}\", &self.region, \"{}\");\n", operation.http.method, service.metadata.endpointPrefix, operation.http.requestUri));
-        src.push_str("\t\trequest.set_content_type(\"application/x-amz-json-1.0\".to_string());\n");
+        src.push_str(&signing_metadata(service));
+        src.push_str(&format!("\t\trequest.set_content_type(\"{}\".to_owned());\n", content_type(service)));
         src.push_str(&format!("\t\trequest.add_header(\"x-amz-target\", \"{}.{}\");\n", target_prefix, operation.name));
         src.push_str("\t\trequest.set_payload(Some(encoded.as_bytes()));\n");
         src.push_str("\t\tlet mut result = request.sign_and_execute(try!(self.creds.get_credentials()));\n");
         src.push_str("\t\tlet status = result.status.to_u16();\n");
         src.push_str("\t\tlet mut body = String::new();\n");
         src.push_str("\t\tresult.read_to_string(&mut body).unwrap();\n");
+
+        // DynamoDB sends a checksum of each response body
+        if service.metadata.endpointPrefix == "dynamodb" {
+            src.push_str("\t\tif let Err(AWSError(message)) = ::crc32::verify(&result.headers, body.as_bytes()) {\n");
+            src.push_str(&format!("\t\t\treturn Err({} {{ __type: \"CRC32CheckFailed\".to_string(), message: message, status: status }});\n", error_type(type_name)));
+            src.push_str("\t\t}\n");
+        }
+
         src.push_str("\t\tmatch status {\n");
         src.push_str("\t\t\t200 => {\n");
 
@@ -226,7 +319,7 @@ fn json_operations(service: &Service) -> String {
         src.push_str("\t\t\t\tOk(decoded)\n");
         src.push_str("\t\t\t}\n");
         src.push_str("\t\t\t_ => {\n");
-        src.push_str("\t\t\t\tErr(parse_error(&body))\n");
+        src.push_str("\t\t\t\tErr(parse_error(status, &body))\n");
         src.push_str("\t\t\t}\n");
         src.push_str("\t\t}\n");
         src.push_str("\t}\n");
@@ -242,8 +335,13 @@ fn render_shapes(service: &Service) -> String {
         if name == "String" {
             continue;
         }
+        if let Some(ref docs) = shape.documentation {
+            src.push_str(&rustdoc(docs, ""));
+        }
         if shape.shape_type == "structure" {
             src = src + &format!("{}\n", struct_type(name, &shape));
+        } else if enums::is_enum(shape) {
+            src = src + &enums::enum_type(name, shape);
         } else {
             let rust_type = match &*shape.shape_type {
                 "structure" => struct_type(name, &shape),
@@ -260,23 +358,18 @@ fn render_shapes(service: &Service) -> String {
 
 fn struct_type(name: &str, shape: &Shape) -> String {
     if shape.members.is_empty() {
-        return format!("#[derive(Debug, Serialize, Deserialize, Default)]\npub struct {};", name);
+        return format!("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]\npub struct {};", name);
     }
-    let mut struct_type = format!("#[derive(Debug, Serialize, Deserialize, Default)]\npub struct {} {{\n", name);
+    let mut struct_type = format!("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]\npub struct {} {{\n", name);
     for (member_name, member) in shape.members.iter() {
-        if member.documentation.is_some() {
-            //struct_type = struct_type + "\t// documentation\n";
+        if let Some(ref docs) = member.documentation {
+            struct_type = struct_type + &rustdoc(docs, "\t");
         }
+        struct_type = struct_type + &format!("\t#[serde(rename=\"{}\")]\n", member_name);
         if shape.required(member_name) {
-            struct_type = struct_type + &format!("\tpub {}: {},\n", member_name, member.shape);
+            struct_type = struct_type + &format!("\tpub {}: {},\n", snake_case(member_name), member.shape);
         } else {
-            // There's surely a better way to do this:
-            if member_name == "type" {
-                struct_type = struct_type + &format!("\tpub aws_{}: Option<{}>,\n", member_name, member.shape)
-            }
-            else {
-                struct_type = struct_type + &format!("\tpub {}: Option<{}>,\n", member_name, member.shape)
-            }
+            struct_type = struct_type + &format!("\tpub {}: Option<{}>,\n", snake_case(member_name), member.shape);
         }
     }
     struct_type = struct_type + "}\n";
@@ -290,21 +383,215 @@ fn primitive_type(shape_type: &str) -> String {
         "long" => "i64".to_string(),
         "float" => "f32".to_string(),
         "double" => "f64".to_string(),
-        "blob" => "Vec<u8>".to_string(),
+        // sent as base64 strings
+        "blob" => "::blob::Blob".to_string(),
         "boolean" => "bool".to_string(),
-        // yes, this is a float type for a timestmap.
-        // that's how it comes back from AWS
-        "timestamp" => "f64".to_string(),
+        // sent as seconds since the epoch
+        "timestamp" => "::timestamp::Timestamp".to_string(),
         _ => panic!(format!("Unknown type '{}'", shape_type))
     }
 }
 
-fn error_type(client_type_name: &str) -> &'static str {
-    match client_type_name {
-        "DynamoDBClient" => "DynamoDBError",
-        "KMSClient" => "KMSError",
-        "ECSClient" => "ECSError",
-        "SQSClient" => "SQSError",
-        _ => panic!("Unknown client type."),
+// whether a member's field holds its value directly, rather than in an Option
+fn member_required(service: &Service, shape: &Shape, member_name: &str) -> bool {
+    match &service.metadata.protocol[..] {
+        "query" => query::is_required(shape, member_name),
+        _ => shape.required(member_name),
+    }
+}
+
+// the start of an operation with input: idempotency tokens the input leaves empty are filled on a
+// copy, and it's checked against the model. Inputs from `new` already have their tokens, so
+// sending one again reuses them
+fn prepare_input(service: &Service, operation: &Operation) -> String {
+    let shape = service.shapes.get(operation.input_shape()).expect("Shape undefined");
+    let mut src = String::new();
+
+    if builders::has_idempotency_tokens(shape) {
+        src.push_str("\t\tlet mut input = input.clone();\n");
+        src.push_str("\t\tinput.fill_idempotency_tokens();\n");
+    }
+
+    src.push_str("\t\ttry!(input.validate());\n");
+    src
+}
+
+// Rusoto only signs requests with Signature Version 4, which S3's `s3` and `s3v4` also use
+fn signature_supported(service: &Service) -> bool {
+    match &service.metadata.signatureVersion[..] {
+        "v4" | "s3" | "s3v4" => true,
+        _ => false,
+    }
+}
+
+// json services name their JSON version in the content type. rest-json services send plain JSON,
+// as botocore does, even Cognito Sync and CloudSearch Domain, whose metadata has a jsonVersion
+fn content_type(service: &Service) -> String {
+    match &service.metadata.protocol[..] {
+        "json" => format!("application/x-amz-json-{}", service.metadata.jsonVersion.as_ref().map(|version| &version[..]).unwrap_or("1.0")),
+        _ => "application/json".to_owned(),
+    }
+}
+
+// where a request is sent and how it's signed, when that differs from the endpoint prefix and the
+// client's region
+fn signing_metadata(service: &Service) -> String {
+    let metadata = &service.metadata;
+    let mut src = String::new();
+
+    if let Some(ref signing_name) = metadata.signingName {
+        if *signing_name != metadata.endpointPrefix {
+            src.push_str(&format!("\t\trequest.set_signing_name(\"{}\");\n", signing_name));
+        }
+    }
+
+    // S3 only uses its global endpoint in us-east-1, which the request's hostname already handles
+    if let Some(ref global_endpoint) = metadata.globalEndpoint {
+        if metadata.endpointPrefix != "s3" {
+            src.push_str(&format!("\t\trequest.set_hostname(Some(\"{}\".to_string()));\n", global_endpoint));
+            src.push_str("\t\trequest.set_signing_region(\"us-east-1\");\n");
+        }
+    }
+
+    src
+}
+
+// json services define a Result alias with their own error type
+fn result_type(service: &Service, type_name: &str) -> String {
+    match &service.metadata.protocol[..] {
+        "json" | "rest-json" => format!("Result<{}>", type_name),
+        _ => format!("Result<{}, AWSError>", type_name),
+    }
+}
+
+// convert CamelCase botocore names to snake_case, e.g. GetQueueUrl => get_queue_url
+fn snake_case(name: &str) -> String {
+    let words = Regex::new(r"(.)([A-Z][a-z]+)").unwrap();
+    let humps = Regex::new(r"([a-z0-9])([A-Z])").unwrap();
+
+    let split_words = words.replace_all(name, |caps: &Captures| format!("{}_{}", caps.at(1).unwrap(), caps.at(2).unwrap()));
+    let snake = humps.replace_all(&split_words, |caps: &Captures| format!("{}_{}", caps.at(1).unwrap(), caps.at(2).unwrap())).to_lowercase();
+
+    // avoid collisions with Rust keywords
+    if KEYWORDS.contains(&&snake[..]) {
+        format!("aws_{}", snake)
+    } else {
+        snake
+    }
+}
+
+// Rust keywords, including those reserved for future use
+const KEYWORDS: &'static [&'static str] = &[
+    "abstract", "alignof", "as", "become", "box", "break", "const", "continue", "crate", "do",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "offsetof", "override", "priv", "proc", "pub", "pure",
+    "ref", "return", "self", "sizeof", "static", "struct", "super", "trait", "true", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// the error type for a client, e.g. DynamoDBClient => DynamoDBError
+fn error_type(client_type_name: &str) -> String {
+    format!("{}Error", client_type_name.trim_right_matches("Client"))
+}
+
+#[cfg(test)]
+mod tests {
+    use botocore::Service;
+    use serde_json;
+    use super::{content_type, error_type, prepare_input, signature_supported, snake_case};
+
+    fn service(protocol: &str, json_version: Option<&str>, signature_version: &str) -> Service {
+        let json_version = json_version.map(|version| format!(r#""jsonVersion": "{}","#, version)).unwrap_or(String::new());
+        serde_json::from_str(&format!(r#"{{
+            "metadata": {{
+                "apiVersion": "2014-06-30",
+                "endpointPrefix": "example",
+                {}
+                "protocol": "{}",
+                "serviceFullName": "Example Service",
+                "signatureVersion": "{}"
+            }},
+            "operations": {{}},
+            "shapes": {{}}
+        }}"#, json_version, protocol, signature_version)).unwrap()
+    }
+
+    #[test]
+    fn snake_case_botocore_names() {
+        assert_eq!(snake_case("GetQueueUrl"), "get_queue_url");
+        assert_eq!(snake_case("QueueURL"), "queue_url");
+        assert_eq!(snake_case("AWSAccountIds"), "aws_account_ids");
+        assert_eq!(snake_case("Type"), "aws_type");
+        assert_eq!(snake_case("Return"), "aws_return");
+        assert_eq!(snake_case("Override"), "aws_override");
+        assert_eq!(snake_case("in"), "aws_in");
+        assert_eq!(snake_case("clusterArns"), "cluster_arns");
+    }
+
+    #[test]
+    fn error_type_from_client_name() {
+        assert_eq!(error_type("DynamoDBClient"), "DynamoDBError");
+        assert_eq!(error_type("LambdaClient"), "LambdaError");
+    }
+
+    #[test]
+    fn operations_fill_empty_idempotency_tokens() {
+        let service: Service = serde_json::from_str(r#"{
+            "metadata": {
+                "apiVersion": "2014-11-13",
+                "endpointPrefix": "ecs",
+                "protocol": "json",
+                "serviceFullName": "Amazon EC2 Container Service",
+                "signatureVersion": "v4"
+            },
+            "operations": {
+                "CreateService": {
+                    "name": "CreateService",
+                    "http": {"method": "POST", "requestUri": "/"},
+                    "input": {"shape": "CreateServiceRequest"}
+                },
+                "DeleteService": {
+                    "name": "DeleteService",
+                    "http": {"method": "POST", "requestUri": "/"},
+                    "input": {"shape": "DeleteServiceRequest"}
+                }
+            },
+            "shapes": {
+                "CreateServiceRequest": {
+                    "type": "structure",
+                    "members": {
+                        "clientToken": {"shape": "String", "idempotencyToken": true}
+                    }
+                },
+                "DeleteServiceRequest": {
+                    "type": "structure",
+                    "members": {
+                        "service": {"shape": "String"}
+                    }
+                },
+                "String": {"type": "string"}
+            }
+        }"#).unwrap();
+
+        assert_eq!(prepare_input(&service, &service.operations["CreateService"]),
+            "\t\tlet mut input = input.clone();\n\t\tinput.fill_idempotency_tokens();\n\t\ttry!(input.validate());\n");
+        assert_eq!(prepare_input(&service, &service.operations["DeleteService"]),
+            "\t\ttry!(input.validate());\n");
+    }
+
+    #[test]
+    fn content_type_from_protocol() {
+        assert_eq!(content_type(&service("json", Some("1.1"), "v4")), "application/x-amz-json-1.1");
+        assert_eq!(content_type(&service("json", None, "v4")), "application/x-amz-json-1.0");
+        assert_eq!(content_type(&service("rest-json", Some("1.1"), "v4")), "application/json");
+        assert_eq!(content_type(&service("rest-json", None, "v4")), "application/json");
+    }
+
+    #[test]
+    fn only_signature_version_4_is_supported() {
+        assert!(signature_supported(&service("json", None, "v4")));
+        assert!(signature_supported(&service("rest-xml", None, "s3")));
+        assert!(!signature_supported(&service("query", None, "v2")));
+        assert!(!signature_supported(&service("rest-xml", None, "v3https")));
     }
 }
@@ -0,0 +1,49 @@
+//! Generates the services included in Rusoto into a directory, normally `src/generated`, whose
+//! files are used when Rusoto is built without the `regenerate` feature:
+//!
+//! ```bash
+//! cargo run --features serde_codegen -- botocore/botocore/data ../src/generated
+//! ```
+
+extern crate rusoto_codegen;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+
+use rusoto_codegen::{generate, rusoto_services};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        let _ = writeln!(io::stderr(), "Usage: {} BOTOCORE_DATA_DIR OUTPUT_DIR", args[0]);
+        process::exit(1);
+    }
+
+    let botocore_path = Path::new(&args[1]);
+    let output_path = Path::new(&args[2]);
+
+    for service in rusoto_services() {
+        let name = service.name().to_owned();
+        println!("Generating {}", name);
+
+        // files left by an earlier run mustn't pass for this one's output
+        let _ = fs::remove_file(output_path.join(format!("{}.rs", name)));
+        let _ = fs::remove_file(output_path.join(format!("{}_botocore.rs", name)));
+        generate(service, botocore_path, output_path);
+
+        // a service that can't be generated has already been reported
+        if !output_path.join(format!("{}_botocore.rs", name)).exists() {
+            process::exit(1);
+        }
+        if !output_path.join(format!("{}.rs", name)).exists() {
+            let _ = writeln!(io::stderr(), "Nothing was generated for {}: rusoto_codegen needs the serde_codegen feature", name);
+            process::exit(1);
+        }
+
+        // only the code expanded by Serde is kept
+        let _ = fs::remove_file(output_path.join(format!("{}_botocore.rs", name)));
+    }
+}
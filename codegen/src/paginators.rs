@@ -0,0 +1,222 @@
+//! Iterators over paginated operations, generated from botocore's `paginators-1.json`.
+//!
+//! Each paginated operation gets a `*_pages` client method returning an iterator over responses,
+//! which passes each response's output token back as the next request's input token, and an
+//! `*_items` method returning an iterator over the elements of each page's first result key.
+
+use botocore::{Paginator, Paginators, Service, Shape};
+use super::{member_required, result_type, snake_case};
+
+/// `*_pages` and `*_items` methods for the client's impl block.
+pub fn generate_methods(service: &Service, paginators: &Paginators) -> String {
+    let mut src = String::new();
+
+    for (operation_name, paginator) in paginators.pagination.iter() {
+        let operation = match service.operations.get(operation_name) {
+            Some(operation) => operation,
+            None => continue,
+        };
+        let method_name = snake_case(operation_name);
+
+        src.push_str(&format!("\t/// Returns an iterator over each page of {} responses, starting with `input`.\n", operation_name));
+        src.push_str(&format!("\tpub fn {}_pages<'b>(&'b mut self, input: {}) -> {}Pages<'a, 'b> {{\n",
+            method_name, operation.input_shape(), operation_name));
+        src.push_str(&format!("\t\t{}Pages {{ client: self, input: input, done: false }}\n", operation_name));
+        src.push_str("\t}\n");
+
+        if let Some((result_key, _)) = items_key(service, operation.output_shape_or(""), paginator) {
+            src.push_str(&format!("\t/// Returns an iterator over the {} in each page of {} responses, starting with `input`.\n",
+                result_key, operation_name));
+            src.push_str(&format!("\tpub fn {}_items<'b>(&'b mut self, input: {}) -> {}Items<'a, 'b> {{\n",
+                method_name, operation.input_shape(), operation_name));
+            src.push_str(&format!("\t\t{}Items {{ pages: self.{}_pages(input), items: Vec::new().into_iter() }}\n",
+                operation_name, method_name));
+            src.push_str("\t}\n");
+        }
+    }
+
+    src
+}
+
+/// The `*Pages` and `*Items` iterator types returned by the client methods.
+pub fn generate_iterators(service: &Service, paginators: &Paginators, client_type: &str) -> String {
+    let mut src = String::new();
+
+    for (operation_name, paginator) in paginators.pagination.iter() {
+        let operation = match service.operations.get(operation_name) {
+            Some(operation) => operation,
+            None => continue,
+        };
+        let output_type = operation.output_shape_or("()");
+        let output_shape = service.shapes.get(output_type).expect("Shape undefined");
+        let input_shape = service.shapes.get(operation.input_shape()).expect("Shape undefined");
+
+        src.push_str(&format!("/// Iterator over each page of {} responses.\n", operation_name));
+        src.push_str(&format!("pub struct {}Pages<'a: 'b, 'b> {{\n", operation_name));
+        src.push_str(&format!("\tclient: &'b mut {}<'a>,\n", client_type));
+        src.push_str(&format!("\tinput: {},\n", operation.input_shape()));
+        src.push_str("\tdone: bool,\n");
+        src.push_str("}\n");
+
+        src.push_str(&format!("impl<'a, 'b> Iterator for {}Pages<'a, 'b> {{\n", operation_name));
+        src.push_str(&format!("\ttype Item = {};\n", result_type(service, output_type)));
+        src.push_str("\tfn next(&mut self) -> Option<Self::Item> {\n");
+        src.push_str("\t\tif self.done {\n\t\t\treturn None;\n\t\t}\n");
+        src.push_str(&format!("\t\tmatch self.client.{}(&self.input) {{\n", snake_case(operation_name)));
+        src.push_str("\t\t\tOk(output) => {\n");
+        src.push_str(&next_page(service, paginator, input_shape, output_shape));
+        src.push_str("\t\t\t\tSome(Ok(output))\n");
+        src.push_str("\t\t\t},\n");
+        src.push_str("\t\t\tErr(err) => {\n");
+        src.push_str("\t\t\t\tself.done = true;\n");
+        src.push_str("\t\t\t\tSome(Err(err))\n");
+        src.push_str("\t\t\t},\n");
+        src.push_str("\t\t}\n");
+        src.push_str("\t}\n");
+        src.push_str("}\n");
+
+        if let Some((result_key, item_type)) = items_key(service, output_type, paginator) {
+            let page_items = if member_required(service, output_shape, &result_key) {
+                format!("page.{}", snake_case(&result_key))
+            } else {
+                format!("page.{}.unwrap_or(Vec::new())", snake_case(&result_key))
+            };
+
+            src.push_str(&format!("/// Iterator over the {} in each page of {} responses.\n", result_key, operation_name));
+            src.push_str(&format!("pub struct {}Items<'a: 'b, 'b> {{\n", operation_name));
+            src.push_str(&format!("\tpages: {}Pages<'a, 'b>,\n", operation_name));
+            src.push_str(&format!("\titems: ::std::vec::IntoIter<{}>,\n", item_type));
+            src.push_str("}\n");
+
+            src.push_str(&format!("impl<'a, 'b> Iterator for {}Items<'a, 'b> {{\n", operation_name));
+            src.push_str(&format!("\ttype Item = {};\n", result_type(service, &item_type)));
+            src.push_str("\tfn next(&mut self) -> Option<Self::Item> {\n");
+            src.push_str("\t\tloop {\n");
+            src.push_str("\t\t\tif let Some(item) = self.items.next() {\n\t\t\t\treturn Some(Ok(item));\n\t\t\t}\n");
+            src.push_str("\t\t\tmatch self.pages.next() {\n");
+            src.push_str(&format!("\t\t\t\tSome(Ok(page)) => self.items = {}.into_iter(),\n", page_items));
+            src.push_str("\t\t\t\tSome(Err(err)) => return Some(Err(err)),\n");
+            src.push_str("\t\t\t\tNone => return None,\n");
+            src.push_str("\t\t\t}\n");
+            src.push_str("\t\t}\n");
+            src.push_str("\t}\n");
+            src.push_str("}\n");
+        }
+    }
+
+    src
+}
+
+// rust code to set the input tokens from `output`, or mark the iterator done on the last page
+fn next_page(service: &Service, paginator: &Paginator, input_shape: &Shape, output_shape: &Shape) -> String {
+    let mut src = String::new();
+    let output_tokens = paginator.output_tokens();
+
+    for (index, output_token) in output_tokens.iter().enumerate() {
+        src.push_str(&format!("\t\t\t\tlet token{} = {};\n", index, output_token_value(service, output_shape, output_token)));
+    }
+
+    let mut more = (0..output_tokens.len()).map(|index| format!("token{}.is_some()", index)).collect::<Vec<String>>().join(" || ");
+
+    // some services say whether there are more results, rather than leaving out the token
+    if let Some(ref more_results) = paginator.more_results {
+        let truncated = if member_required(service, output_shape, more_results) {
+            format!("output.{}", snake_case(more_results))
+        } else {
+            format!("output.{}.unwrap_or(false)", snake_case(more_results))
+        };
+        more = format!("{} && ({})", truncated, more);
+    }
+
+    src.push_str(&format!("\t\t\t\tif {} {{\n", more));
+
+    for (index, input_token) in paginator.input_tokens().iter().enumerate() {
+        let field = snake_case(input_token);
+
+        if member_required(service, input_shape, input_token) {
+            src.push_str(&format!("\t\t\t\t\tif let Some(token) = token{} {{\n\t\t\t\t\t\tself.input.{} = token;\n\t\t\t\t\t}}\n", index, field));
+        } else {
+            src.push_str(&format!("\t\t\t\t\tself.input.{} = token{};\n", field, index));
+        }
+    }
+
+    src.push_str("\t\t\t\t} else {\n");
+    src.push_str("\t\t\t\t\tself.done = true;\n");
+    src.push_str("\t\t\t\t}\n");
+    src
+}
+
+// rust code for an Option holding an output token, e.g. `NextMarker || Contents[-1].Key`
+fn output_token_value(service: &Service, shape: &Shape, expression: &str) -> String {
+    let mut alternatives = expression.split("||").map(|alternative| token_path_value(service, shape, alternative.trim()));
+    let first = alternatives.next().expect("Empty output token");
+
+    alternatives.fold(first, |value, alternative| format!("{}.or_else(|| {})", value, alternative))
+}
+
+// a member of the output, or a member of the last element of a list in it, e.g. `Contents[-1].Key`
+fn token_path_value(service: &Service, shape: &Shape, path: &str) -> String {
+    let mut parts = path.splitn(2, "[-1].");
+    let member_name = parts.next().unwrap_or(path);
+    let field = snake_case(member_name);
+
+    match parts.next() {
+        // a required token that wasn't in the response is left empty
+        None if member_required(service, shape, member_name) => {
+            format!("(if output.{}.is_empty() {{ None }} else {{ Some(output.{}.clone()) }})", field, field)
+        },
+        None => format!("output.{}.clone()", field),
+        Some(item_member) => {
+            let member = shape.members.get(member_name).expect("Token member undefined");
+            let list_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+            let item_shape = service.shapes.get(list_shape.member()).expect("Shape undefined");
+
+            let last = if member_required(service, shape, member_name) {
+                format!("output.{}.last()", field)
+            } else {
+                format!("output.{}.as_ref().and_then(|items| items.last())", field)
+            };
+
+            if member_required(service, item_shape, item_member) {
+                format!("{}.map(|item| item.{}.clone())", last, snake_case(item_member))
+            } else {
+                format!("{}.and_then(|item| item.{}.clone())", last, snake_case(item_member))
+            }
+        },
+    }
+}
+
+// the first result key and the type of its elements, if it's a list
+fn items_key(service: &Service, output_type: &str, paginator: &Paginator) -> Option<(String, String)> {
+    let output_shape = match service.shapes.get(output_type) {
+        Some(shape) => shape,
+        None => return None,
+    };
+
+    paginator.result_keys().into_iter().next().and_then(|result_key| {
+        output_shape.members.get(&result_key)
+            .and_then(|member| service.shapes.get(&member.shape))
+            .and_then(|shape| if shape.shape_type == "list" { Some(shape.member().to_owned()) } else { None })
+            .map(|item_type| (result_key.clone(), item_type))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use botocore::Paginator;
+    use serde_json;
+
+    #[test]
+    fn paginator_tokens_are_names_or_lists() {
+        let paginator: Paginator = serde_json::from_str(r#"{
+            "input_token": ["KeyMarker", "UploadIdMarker"],
+            "output_token": ["NextKeyMarker", "NextUploadIdMarker"],
+            "more_results": "IsTruncated",
+            "result_key": "Uploads"
+        }"#).unwrap();
+
+        assert_eq!(paginator.input_tokens(), vec!["KeyMarker", "UploadIdMarker"]);
+        assert_eq!(paginator.output_tokens(), vec!["NextKeyMarker", "NextUploadIdMarker"]);
+        assert_eq!(paginator.result_keys(), vec!["Uploads"]);
+    }
+}
@@ -0,0 +1,368 @@
+//! Code generation for AWS services with protocol type "query", such as SQS.
+//!
+//! Requests are encoded as form parameters and responses are XML, so along with a Rust type
+//! each shape gets a `*Parser` that reads it from XML and a `*Writer` that encodes it as params.
+
+use botocore::{Member, Operation, Service, Shape};
+use doc::rustdoc;
+use enums;
+use super::{prepare_input, signing_metadata, snake_case};
+
+pub fn generate_prelude(_service: &Service) -> String {
+    "use std::collections::HashMap;\n".to_owned()
+}
+
+pub fn generate_shapes(service: &Service) -> String {
+    let mut src = String::new();
+
+    for (name, shape) in service.shapes.iter() {
+        src.push_str(&rust_type(name, shape));
+        src.push_str(&type_parser(service, name, shape));
+        src.push_str(&param_writer(service, name, shape));
+    }
+
+    src
+}
+
+pub fn generate_operations(service: &Service) -> String {
+    let mut src = String::new();
+
+    for operation in service.operations.values() {
+        src.push_str(&request_method(service, operation));
+    }
+
+    src
+}
+
+fn rust_type(name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+
+    if let Some(ref docs) = shape.documentation {
+        src.push_str(&rustdoc(docs, ""));
+    }
+
+    if shape.shape_type == "structure" {
+        src.push_str(&struct_type(name, shape));
+    } else if enums::is_enum(shape) {
+        src.push_str(&enums::enum_type(name, shape));
+    } else {
+        let rust_type = match &shape.shape_type[..] {
+            "map" => format!("HashMap<{},{}>", shape.key(), shape.value()),
+            "list" => format!("Vec<{}>", shape.member()),
+            primitive => primitive_type(primitive).to_owned(),
+        };
+
+        // a String is already a String in Rust
+        if name != "String" {
+            src.push_str(&format!("pub type {} = {};\n", name, rust_type));
+        }
+    }
+
+    src
+}
+
+fn struct_type(name: &str, shape: &Shape) -> String {
+    let mut src = "#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]\n".to_owned();
+
+    if shape.members.is_empty() {
+        src.push_str(&format!("pub struct {};\n\n", name));
+        return src;
+    }
+
+    src.push_str(&format!("pub struct {} {{\n", name));
+
+    for (member_name, member) in shape.members.iter() {
+        if let Some(ref docs) = member.documentation {
+            src.push_str(&rustdoc(docs, "\t"));
+        }
+
+        src.push_str(&format!("\t#[serde(rename=\"{}\")]\n", member_name));
+        if is_required(shape, member_name) {
+            src.push_str(&format!("\tpub {}: {},\n", snake_case(member_name), member.shape));
+        } else {
+            src.push_str(&format!("\tpub {}: Option<{}>,\n", snake_case(member_name), member.shape));
+        }
+    }
+
+    src.push_str("}\n\n");
+    src
+}
+
+// generate rust code to parse a botocore shape from XML
+fn type_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Parse {} from XML\nstruct {}Parser;\nimpl {}Parser {{\n", name, name, name);
+    src.push_str(&format!("\tfn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<{}, XmlParseError> {{\n", name));
+
+    match &shape.shape_type[..] {
+        "map" => src.push_str(&map_parser(shape)),
+        "list" => src.push_str(&list_parser(shape)),
+        shape_type => {
+            src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+            if shape_type == "structure" {
+                src.push_str(&struct_parser(service, name, shape));
+            } else if enums::is_enum(shape) {
+                src.push_str(&format!("\t\tlet obj = {}::from(&try!(characters(stack))[..]);\n", name));
+            } else {
+                src.push_str(&format!("\t\tlet obj = {};\n", primitive_parser(shape_type)));
+            }
+            src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+        }
+    }
+
+    src.push_str("\t\tOk(obj)\n\t}\n}\n");
+    src
+}
+
+// guts of the XML parser for struct shapes
+fn struct_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("\t\tlet mut obj = {}::default();\n", name);
+
+    if shape.members.is_empty() {
+        return src;
+    }
+
+    src.push_str("\t\tloop {\n");
+    src.push_str("\t\t\tlet current_name = try!(peek_at_name(stack));\n");
+
+    for (member_name, member) in shape.members.iter() {
+        let tag_name = location_name(service, member_name, member);
+        let mut parse_stmt = format!("try!({}Parser::parse_xml(\"{}\", stack))", member.shape, tag_name);
+
+        if !is_required(shape, member_name) {
+            parse_stmt = format!("Some({})", parse_stmt);
+        }
+
+        src.push_str(&format!("\t\t\tif current_name == \"{}\" {{\n", tag_name));
+        src.push_str(&format!("\t\t\t\tobj.{} = {};\n", snake_case(member_name), parse_stmt));
+        src.push_str("\t\t\t\tcontinue;\n");
+        src.push_str("\t\t\t}\n");
+    }
+
+    src.push_str("\t\t\tbreak;\n\t\t}\n");
+    src
+}
+
+// guts of the XML parser for map shapes
+fn map_parser(shape: &Shape) -> String {
+    let key = shape.key.as_ref().expect("Key shape undefined");
+    let value = shape.value.as_ref().expect("Value shape undefined");
+
+    let mut src = "\t\tlet mut obj = HashMap::new();\n".to_owned();
+    src.push_str("\t\twhile try!(peek_at_name(stack)) == tag_name {\n");
+    src.push_str("\t\t\ttry!(start_element(tag_name, stack));\n");
+    src.push_str(&format!("\t\t\tlet key = try!({}Parser::parse_xml(\"{}\", stack));\n", key.shape, shape_name(key)));
+    src.push_str(&format!("\t\t\tlet value = try!({}Parser::parse_xml(\"{}\", stack));\n", value.shape, shape_name(value)));
+    src.push_str("\t\t\tobj.insert(key, value);\n");
+    src.push_str("\t\t\ttry!(end_element(tag_name, stack));\n");
+    src.push_str("\t\t}\n");
+    src
+}
+
+// guts of the XML parser for list shapes
+fn list_parser(shape: &Shape) -> String {
+    let member = shape.member.as_ref().expect("Member shape undefined");
+
+    let mut src = "\t\tlet mut obj = Vec::new();\n".to_owned();
+    src.push_str(&format!("\t\twhile try!(peek_at_name(stack)) == \"{}\" {{\n", shape_name(member)));
+    src.push_str(&format!("\t\t\tobj.push(try!({}Parser::parse_xml(\"{}\", stack)));\n", member.shape, shape_name(member)));
+    src.push_str("\t\t}\n");
+    src
+}
+
+// generate rust code to encode a botocore shape into a map of query parameters
+fn param_writer(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Write {} contents to a SignedRequest\nstruct {}Writer;\nimpl {}Writer {{\n", name, name, name);
+    src.push_str(&format!("\tfn write_params(params: &mut Params, name: &str, obj: &{}) {{\n", name));
+
+    match &shape.shape_type[..] {
+        "list" => src.push_str(&list_writer(shape)),
+        "map" => src.push_str(&map_writer(shape)),
+        "structure" => src.push_str(&struct_writer(service, shape)),
+        "string" if enums::is_enum(shape) => src.push_str("\t\tparams.put(name, obj.as_str());\n"),
+        primitive => src.push_str(&format!("\t\tparams.put(name, {});\n", primitive_writer(primitive))),
+    }
+
+    src.push_str("\t}\n}\n");
+    src
+}
+
+// guts of the param_writer for struct shapes
+fn struct_writer(service: &Service, shape: &Shape) -> String {
+    let mut src = "\t\tlet mut prefix = name.to_string();\n".to_owned();
+    src.push_str("\t\tif prefix != \"\" { prefix.push_str(\".\"); }\n");
+
+    for (member_name, member) in shape.members.iter() {
+        let location_name = location_name(service, member_name, member);
+
+        if is_required(shape, member_name) {
+            src.push_str(&format!("\t\t{}Writer::write_params(params, &(prefix.to_string() + \"{}\"), &obj.{});\n",
+                member.shape, location_name, snake_case(member_name)));
+        } else {
+            src.push_str(&format!("\t\tif let Some(ref obj) = obj.{} {{\n", snake_case(member_name)));
+            src.push_str(&format!("\t\t\t{}Writer::write_params(params, &(prefix.to_string() + \"{}\"), obj);\n",
+                member.shape, location_name));
+            src.push_str("\t\t}\n");
+        }
+    }
+
+    src
+}
+
+// guts of the param_writer for list shapes
+fn list_writer(shape: &Shape) -> String {
+    let mut src = "\t\tlet mut index = 1;\n".to_owned();
+    src.push_str("\t\tfor element in obj.iter() {\n");
+    src.push_str("\t\t\tlet key = &format!(\"{}.{}\", name, index);\n");
+    src.push_str(&format!("\t\t\t{}Writer::write_params(params, key, &element);\n", shape.member()));
+    src.push_str("\t\t\tindex += 1;\n");
+    src.push_str("\t\t}\n");
+    src
+}
+
+// guts of the param_writer for map shapes
+fn map_writer(shape: &Shape) -> String {
+    let key = shape.key.as_ref().expect("Key shape undefined");
+    let value = shape.value.as_ref().expect("Value shape undefined");
+
+    let mut src = "\t\tlet mut index = 1;\n".to_owned();
+    src.push_str("\t\tfor (key,value) in obj {\n");
+    src.push_str("\t\t\tlet prefix = &format!(\"{}.{}\", name, index);\n");
+    src.push_str(&format!("\t\t\t{}Writer::write_params(params, &format!(\"{{}}.{{}}\", prefix, \"{}\"), &key);\n", key.shape, shape_name(key)));
+    src.push_str(&format!("\t\t\t{}Writer::write_params(params, &format!(\"{{}}.{{}}\", prefix, \"{}\"), &value);\n", value.shape, shape_name(value)));
+    src.push_str("\t\t\tindex += 1;\n");
+    src.push_str("\t\t}\n");
+    src
+}
+
+fn request_method(service: &Service, operation: &Operation) -> String {
+    let output_type = operation.output_shape_or("()");
+    let mut src = String::new();
+
+    if let Some(ref docs) = operation.documentation {
+        src.push_str(&rustdoc(docs, "\t"));
+    }
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\tpub fn {}(&mut self, input: &{}) -> Result<{}, AWSError> {{\n",
+            snake_case(&operation.name), operation.input_shape(), output_type));
+        src.push_str(&prepare_input(service, operation));
+    } else {
+        src.push_str(&format!("\tpub fn {}(&mut self) -> Result<{}, AWSError> {{\n", snake_case(&operation.name), output_type));
+    }
+
+    src.push_str(&format!("\t\tlet mut request = SignedRequest::new(\"{}\", \"{}\", &self.region, \"{}\");\n",
+        operation.http.method, service.metadata.endpointPrefix, operation.http.requestUri));
+    src.push_str(&signing_metadata(service));
+    src.push_str("\t\tlet mut params = Params::new();\n");
+    src.push_str(&format!("\t\tparams.put(\"Action\", \"{}\");\n", operation.name));
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\t\t{}Writer::write_params(&mut params, \"\", &input);\n", operation.input_shape()));
+    }
+
+    src.push_str("\t\trequest.set_params(params);\n");
+    src.push_str("\t\tlet mut result = request.sign_and_execute(try!(self.creds.get_credentials()));\n");
+    src.push_str("\t\tlet status = result.status.to_u16();\n");
+    src.push_str("\t\tif !result.status.is_success() {\n");
+    src.push_str("\t\t\tlet mut body = String::new();\n");
+    src.push_str("\t\t\ttry!(result.read_to_string(&mut body));\n");
+    src.push_str("\t\t\treturn Err(AWSError::new(format!(\"{}: {}\", status, body)));\n");
+    src.push_str("\t\t}\n");
+
+    match operation.output {
+        Some(ref output) => {
+            src.push_str("\t\tlet mut reader = EventReader::new(result);\n");
+            src.push_str("\t\tlet mut stack = XmlResponseFromAws::new(reader.events().peekable());\n");
+            src.push_str("\t\tstack.next(); // xml start tag\n");
+            src.push_str("\t\tstack.next();\n");
+            // the output is wrapped in an element named for the result, e.g. <ListQueuesResult>
+            let tag_name = output.resultWrapper.as_ref().unwrap_or(&output.shape);
+            src.push_str(&format!("\t\tOk(try!({}Parser::parse_xml(\"{}\", &mut stack)))\n", output.shape, tag_name));
+        },
+        None => src.push_str("\t\tOk(())\n"),
+    }
+
+    src.push_str("\t}\n");
+    src
+}
+
+// Members of shapes without a required list are all treated as required
+pub fn is_required(shape: &Shape, member_name: &str) -> bool {
+    shape.required.is_none() || shape.required(member_name)
+}
+
+// SQS documents a limit of ten entries per batch request that's missing from its model
+pub fn batch_limit(service: &Service, shape_name: &str) -> Option<f64> {
+    if service.metadata.endpointPrefix != "sqs" {
+        return None;
+    }
+
+    match shape_name {
+        "ChangeMessageVisibilityBatchRequestEntryList" |
+        "DeleteMessageBatchRequestEntryList" |
+        "SendMessageBatchRequestEntryList" => Some(10.0),
+        _ => None,
+    }
+}
+
+// The name used for a member's element when encoding/decoding.
+// Lists aren't wrapped in a parent element, so they use their own member's name.
+fn location_name(service: &Service, member_name: &str, member: &Member) -> String {
+    let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+    if member_shape.shape_type == "list" {
+        return shape_name(member_shape.member.as_ref().expect("Member shape undefined"));
+    }
+
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => member_name.to_owned(),
+    }
+}
+
+fn shape_name(member: &Member) -> String {
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => member.shape.to_owned(),
+    }
+}
+
+pub fn primitive_type(shape_type: &str) -> &'static str {
+    match shape_type {
+        "string" => "String",
+        "timestamp" => "::timestamp::Timestamp",
+        "integer" => "i32",
+        "long" => "i64",
+        "float" => "f32",
+        "double" => "f64",
+        "blob" => "::blob::Blob",
+        "boolean" => "bool",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
+
+// rust code to pull primitive types from XML
+fn primitive_parser(shape_type: &str) -> &'static str {
+    match shape_type {
+        "string" => "try!(characters(stack))",
+        "timestamp" => "try!(::timestamp::Timestamp::from_iso8601(&try!(characters(stack))))",
+        "integer" => "try!(i32::from_str(try!(characters(stack)).as_ref()))",
+        "long" => "try!(i64::from_str(try!(characters(stack)).as_ref()))",
+        "float" => "try!(f32::from_str(try!(characters(stack)).as_ref()))",
+        "double" => "try!(f64::from_str(try!(characters(stack)).as_ref()))",
+        "blob" => "::blob::Blob(try!(::blob::decode_base64(&try!(characters(stack)))))",
+        "boolean" => "try!(bool::from_str(try!(characters(stack)).as_ref()))",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
+
+// rust code to write primitive types to a string
+pub fn primitive_writer(shape_type: &str) -> &'static str {
+    match shape_type {
+        "string" => "obj",
+        "timestamp" => "&obj.iso8601()",
+        "integer" | "long" | "float" | "double" | "boolean" => "&obj.to_string()",
+        "blob" => "&::blob::encode_base64(obj)",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
@@ -0,0 +1,230 @@
+//! Request and response bindings shared by the REST protocols, "rest-xml" and "rest-json".
+//!
+//! Members with a `location` aren't part of the request or response body: they're bound to the
+//! request URI, query string or headers, or read back from the response headers and status code.
+
+use botocore::{Member, Operation, Service, Shape};
+use enums;
+use super::snake_case;
+
+/// Helper functions used by the generated bindings, emitted once per service.
+pub fn generate_helpers() -> &'static str {
+    "
+// percent-encode a URI label; greedy labels such as {Key+} keep their slashes
+fn encode_uri_label(value: &str, greedy: bool) -> String {
+\tlet mut encoded = String::new();
+\tfor byte in value.bytes() {
+\t\tmatch byte {
+\t\t\tb'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+\t\t\tb'/' if greedy => encoded.push('/'),
+\t\t\t_ => encoded.push_str(&format!(\"%{:02X}\", byte)),
+\t\t}
+\t}
+\tencoded
+}
+
+fn header_value(headers: &::hyper::header::Headers, name: &str) -> Option<String> {
+\theaders.get_raw(name).and_then(|values| values.first()).map(|value| String::from_utf8_lossy(value).into_owned())
+}
+
+// collect headers sharing a prefix, such as x-amz-meta-, into a map keyed by the rest of the name
+fn prefixed_headers(headers: &::hyper::header::Headers, prefix: &str) -> ::std::collections::HashMap<String, String> {
+\tlet mut values = ::std::collections::HashMap::new();
+\tfor header in headers.iter() {
+\t\tlet name = header.name().to_ascii_lowercase();
+\t\tif name.starts_with(prefix) {
+\t\t\tvalues.insert(name[prefix.len()..].to_owned(), header.value_string());
+\t\t}
+\t}
+\tvalues
+}
+"
+}
+
+/// Rust code declaring `path` and `params` for the operation's request URI, with URI labels
+/// substituted and query string members added.
+pub fn request_uri_writer(service: &Service, operation: &Operation) -> String {
+    // the request URI may carry static query parameters, e.g. /{Bucket}?uploads
+    let mut request_uri = operation.http.requestUri.splitn(2, '?');
+    let path = request_uri.next().unwrap_or("/");
+    let static_params = request_uri.next();
+
+    let mut bindings = String::new();
+    let mut binds_path = false;
+    let mut binds_params = false;
+
+    if let Some(static_params) = static_params {
+        for param in static_params.split('&').filter(|param| !param.is_empty()) {
+            let mut pair = param.splitn(2, '=');
+            let key = pair.next().unwrap_or("");
+            let value = pair.next().unwrap_or("");
+            bindings.push_str(&format!("\t\tparams.put(\"{}\", \"{}\");\n", key, value));
+            binds_params = true;
+        }
+    }
+
+    if let Some(ref input) = operation.input {
+        let shape = service.shapes.get(&input.shape).expect("Shape undefined");
+        bindings.push_str(&uri_member_writer(service, shape, path, &mut binds_path, &mut binds_params));
+    }
+
+    let mut src = format!("\t\tlet {}path = \"{}\".to_owned();\n", if binds_path { "mut " } else { "" }, path);
+    src.push_str(&format!("\t\tlet {}params = Params::new();\n", if binds_params { "mut " } else { "" }));
+    src.push_str(&bindings);
+    src
+}
+
+// substitute the input's URI members into `path` and add its query string members to `params`
+fn uri_member_writer(service: &Service, shape: &Shape, path: &str, binds_path: &mut bool, binds_params: &mut bool) -> String {
+    let mut src = String::new();
+
+    for (member_name, member) in shape.members.iter() {
+        let location = match member.location {
+            Some(ref location) => location,
+            None => continue,
+        };
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+        let location_name = location_name(member_name, member);
+
+        let stmt = match &location[..] {
+            "uri" => {
+                // greedy labels like {Key+} may contain unencoded slashes
+                let greedy_label = format!("{{{}+}}", location_name);
+                let (label, greedy) = if path.contains(&greedy_label) {
+                    (greedy_label, true)
+                } else {
+                    (format!("{{{}}}", location_name), false)
+                };
+                *binds_path = true;
+                format!("path = path.replace(\"{}\", &encode_uri_label(&{}, {}));\n", label, string_value(service, member_shape, "value", "iso8601"), greedy)
+            },
+            "querystring" if member_shape.shape_type == "map" => {
+                *binds_params = true;
+                "for (key, value) in value.iter() {\n\t\t\t\tparams.put(key, value);\n\t\t\t}\n".to_owned()
+            },
+            "querystring" => {
+                *binds_params = true;
+                format!("params.put(\"{}\", &{});\n", location_name, string_value(service, member_shape, "value", "iso8601"))
+            },
+            _ => continue,
+        };
+
+        src.push_str(&member_value(shape, member_name, &stmt));
+    }
+
+    src
+}
+
+/// Rust code adding the input's header members to `request`.
+pub fn header_writer(service: &Service, shape: &Shape) -> String {
+    let mut src = String::new();
+
+    for (member_name, member) in shape.members.iter() {
+        let location_name = location_name(member_name, member);
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+        let stmt = match member.location.as_ref().map(|location| &location[..]) {
+            Some("header") => format!("request.add_header(\"{}\", &{});\n", location_name, string_value(service, member_shape, "value", "rfc822")),
+            Some("headers") => format!("for (header_name, header_value) in value.iter() {{\n\t\t\t\trequest.add_header(&format!(\"{}{{}}\", header_name), header_value);\n\t\t\t}}\n", location_name),
+            _ => continue,
+        };
+
+        src.push_str(&member_value(shape, member_name, &stmt));
+    }
+
+    src
+}
+
+/// Rust code filling `output` members bound to the response `headers` and `status`.
+pub fn output_header_reader(service: &Service, shape: &Shape) -> String {
+    let mut src = String::new();
+
+    for (member_name, member) in shape.members.iter() {
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+        let location_name = location_name(member_name, member);
+        let field = snake_case(member_name);
+
+        match member.location.as_ref().map(|location| &location[..]) {
+            Some("header") => {
+                src.push_str(&format!("\t\tif let Some(value) = header_value(&headers, \"{}\") {{\n", location_name));
+                src.push_str(&format!("\t\t\toutput.{} = {};\n", field, optional(shape, member_name, &header_parser(&member.shape, member_shape))));
+                src.push_str("\t\t}\n");
+            },
+            Some("headers") => {
+                src.push_str(&format!("\t\toutput.{} = {};\n", field, optional(shape, member_name, &format!("prefixed_headers(&headers, \"{}\")", location_name.to_lowercase()))));
+            },
+            Some("statusCode") => {
+                src.push_str(&format!("\t\toutput.{} = {};\n", field, optional(shape, member_name, "status as i32")));
+            },
+            _ => {},
+        }
+    }
+
+    src
+}
+
+/// Whether a member is bound to the URI, query string, headers or status code rather than the body.
+pub fn is_located(member: &Member) -> bool {
+    member.location.is_some()
+}
+
+// wrap a statement that uses `value` so it's only run when the member is present
+fn member_value(shape: &Shape, member_name: &str, stmt: &str) -> String {
+    if shape.required(member_name) {
+        format!("\t\t{{\n\t\t\tlet value = &input.{};\n\t\t\t{}\t\t}}\n", snake_case(member_name), stmt)
+    } else {
+        format!("\t\tif let Some(ref value) = input.{} {{\n\t\t\t{}\t\t}}\n", snake_case(member_name), stmt)
+    }
+}
+
+fn optional(shape: &Shape, member_name: &str, value: &str) -> String {
+    if shape.required(member_name) {
+        value.to_owned()
+    } else {
+        format!("Some({})", value)
+    }
+}
+
+// rust code to render a primitive (or list of primitives) held in `name` as a String.
+// Timestamps use the location's format unless the shape has its own.
+fn string_value(service: &Service, shape: &Shape, name: &str, timestamp_format: &str) -> String {
+    match &shape.shape_type[..] {
+        "timestamp" => match timestamp_format_of(shape, timestamp_format) {
+            "rfc822" => format!("{}.rfc822()", name),
+            "unixTimestamp" => format!("{}.epoch_seconds().to_string()", name),
+            _ => format!("{}.iso8601()", name),
+        },
+        "blob" => format!("::blob::encode_base64({})", name),
+        "list" if enums::is_enum(service.shapes.get(shape.member()).expect("Shape undefined")) => {
+            format!("{}.iter().map(|item| item.as_str()).collect::<Vec<&str>>().join(\",\")", name)
+        },
+        "list" => format!("{}.join(\",\")", name),
+        _ => format!("{}.to_string()", name),
+    }
+}
+
+// rust code to convert a header `value` String to the member's type
+fn header_parser(type_name: &str, shape: &Shape) -> String {
+    match &shape.shape_type[..] {
+        "string" if enums::is_enum(shape) => format!("{}::from(&value[..])", type_name),
+        "string" => "value".to_owned(),
+        "blob" => format!("{}::from(try!(::blob::decode_base64(&value).map_err(|_| AWSError::new(format!(\"Invalid base64 header value {{}}\", value)))))", type_name),
+        "list" => "value.split(',').map(|item| item.trim().to_owned()).collect()".to_owned(),
+        "timestamp" => match timestamp_format_of(shape, "rfc822") {
+            "iso8601" => "try!(::timestamp::Timestamp::from_iso8601(&value).map_err(AWSError::from))".to_owned(),
+            _ => "try!(::timestamp::Timestamp::from_rfc822(&value).map_err(AWSError::from))".to_owned(),
+        },
+        _ => format!("try!({}::from_str(&value).map_err(|_| AWSError::new(format!(\"Invalid header value {{}}\", value))))", type_name),
+    }
+}
+
+fn timestamp_format_of<'a>(shape: &'a Shape, default: &'a str) -> &'a str {
+    shape.timestampFormat.as_ref().map(|format| &format[..]).unwrap_or(default)
+}
+
+fn location_name(member_name: &str, member: &Member) -> String {
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => member_name.to_owned(),
+    }
+}
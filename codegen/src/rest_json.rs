@@ -0,0 +1,241 @@
+//! Code generation for AWS services with protocol type "rest-json", such as Lambda and API Gateway.
+//!
+//! Requests are bound like "rest-xml": members with a `location` go in the request URI, query
+//! string or headers, and the rest are serialized to a JSON body. Shapes derive Serde's traits,
+//! with located members skipped when serializing.
+
+use botocore::{Member, Operation, Service, Shape};
+use doc::rustdoc;
+use enums;
+use rest;
+use super::{content_type, prepare_input, primitive_type, signing_metadata, snake_case};
+
+pub fn generate_prelude(_service: &Service) -> String {
+    let mut src = "use std::ascii::AsciiExt;
+#[allow(unused_imports)]
+use std::str::FromStr;
+
+#[allow(unused_imports)]
+use params::{Params, SQSParams};
+".to_owned();
+
+    src.push_str(rest::generate_helpers());
+    src
+}
+
+pub fn generate_shapes(service: &Service) -> String {
+    let mut src = String::new();
+
+    for (name, shape) in service.shapes.iter() {
+        // String is already a type in Rust
+        if name == "String" {
+            continue;
+        }
+
+        if let Some(ref docs) = shape.documentation {
+            src.push_str(&rustdoc(docs, ""));
+        }
+
+        match &shape.shape_type[..] {
+            "structure" => src.push_str(&struct_type(name, shape)),
+            "string" if enums::is_enum(shape) => src.push_str(&enums::enum_type(name, shape)),
+            "map" => src.push_str(&format!("pub type {} = ::std::collections::HashMap<{}, {}>;\n", name, shape.key(), shape.value())),
+            "list" => src.push_str(&format!("pub type {} = Vec<{}>;\n", name, shape.member())),
+            primitive => src.push_str(&format!("pub type {} = {};\n", name, primitive_type(primitive))),
+        }
+    }
+
+    src
+}
+
+pub fn generate_operations(service: &Service) -> String {
+    let mut src = String::new();
+
+    for operation in service.operations.values() {
+        src.push_str(&request_method(service, operation));
+    }
+
+    src
+}
+
+fn struct_type(name: &str, shape: &Shape) -> String {
+    if shape.members.is_empty() {
+        return format!("#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]\npub struct {};\n", name);
+    }
+
+    let mut src = format!("#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]\npub struct {} {{\n", name);
+
+    for (member_name, member) in shape.members.iter() {
+        if let Some(ref docs) = member.documentation {
+            src.push_str(&rustdoc(docs, "\t"));
+        }
+
+        // members bound to the URI, query string or headers aren't part of the body
+        if rest::is_located(member) {
+            src.push_str(&format!("\t#[serde(rename=\"{}\", default, skip_serializing)]\n", member_name));
+        } else {
+            src.push_str(&format!("\t#[serde(rename=\"{}\")]\n", body_name(member_name, member)));
+        }
+
+        if shape.required(member_name) {
+            src.push_str(&format!("\tpub {}: {},\n", snake_case(member_name), member.shape));
+        } else {
+            src.push_str(&format!("\tpub {}: Option<{}>,\n", snake_case(member_name), member.shape));
+        }
+    }
+
+    src.push_str("}\n");
+    src
+}
+
+fn request_method(service: &Service, operation: &Operation) -> String {
+    let output_type = operation.output_shape_or("()");
+    let mut src = String::new();
+
+    if let Some(ref docs) = operation.documentation {
+        src.push_str(&rustdoc(docs, "\t"));
+    }
+
+    let input_shape = operation.input.as_ref().map(|input| service.shapes.get(&input.shape).expect("Shape undefined"));
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\tpub fn {}(&mut self, input: &{}) -> Result<{}> {{\n",
+            snake_case(&operation.name), operation.input_shape(), output_type));
+        src.push_str(&prepare_input(service, operation));
+    } else {
+        src.push_str(&format!("\tpub fn {}(&mut self) -> Result<{}> {{\n", snake_case(&operation.name), output_type));
+    }
+
+    src.push_str(&rest::request_uri_writer(service, operation));
+
+    if let Some(shape) = input_shape {
+        src.push_str(&payload_writer(service, shape));
+    }
+
+    src.push_str(&format!("\t\tlet mut request = SignedRequest::new(\"{}\", \"{}\", &self.region, &path);\n",
+        operation.http.method, service.metadata.endpointPrefix));
+    src.push_str(&signing_metadata(service));
+    src.push_str(&format!("\t\trequest.set_content_type(\"{}\".to_owned());\n", content_type(service)));
+    src.push_str("\t\trequest.set_params(params);\n");
+
+    if let Some(shape) = input_shape {
+        src.push_str(&rest::header_writer(service, shape));
+        src.push_str("\t\trequest.set_payload(payload);\n");
+    }
+
+    src.push_str("\t\tlet mut result = request.sign_and_execute(try!(self.creds.get_credentials()));\n");
+    src.push_str("\t\tif !result.status.is_success() {\n");
+    src.push_str("\t\t\tlet mut body = String::new();\n");
+    src.push_str("\t\t\ttry!(result.read_to_string(&mut body).map_err(AWSError::from));\n");
+    src.push_str("\t\t\treturn Err(parse_error(result.status.to_u16(), &body));\n");
+    src.push_str("\t\t}\n");
+
+    match operation.output {
+        Some(ref output) => {
+            let output_shape = service.shapes.get(&output.shape).expect("Shape undefined");
+            src.push_str(&output_reader(service, &output.shape, output_shape));
+        },
+        None => src.push_str("\t\tOk(())\n"),
+    }
+
+    src.push_str("\t}\n");
+    src
+}
+
+// build the request body from either the payload member or the input's body members
+fn payload_writer(service: &Service, shape: &Shape) -> String {
+    if let Some(ref payload) = shape.payload {
+        let member = shape.members.get(payload).expect("Payload member undefined");
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+        let field = snake_case(payload);
+        let required = shape.required(payload);
+
+        return match &member_shape.shape_type[..] {
+            "blob" if required => format!("\t\tlet payload = Some(&input.{}[..]);\n", field),
+            "blob" => format!("\t\tlet payload = input.{}.as_ref().map(|value| &value[..]);\n", field),
+            "string" if required => format!("\t\tlet payload = Some(input.{}.as_bytes());\n", field),
+            "string" => format!("\t\tlet payload = input.{}.as_ref().map(|value| value.as_bytes());\n", field),
+            _ if required => format!("\t\tlet encoded = serde_json::to_string(&input.{}).unwrap();\n\t\tlet payload = Some(encoded.as_bytes());\n", field),
+            _ => format!("\t\tlet encoded = input.{}.as_ref().map(|value| serde_json::to_string(value).unwrap());\n\t\tlet payload = encoded.as_ref().map(|value| value.as_bytes());\n", field),
+        };
+    }
+
+    if shape.members.values().any(|member| !rest::is_located(member)) {
+        return "\t\tlet encoded = serde_json::to_string(&input).unwrap();\n\t\tlet payload = Some(encoded.as_bytes());\n".to_owned();
+    }
+
+    "\t\tlet payload = None;\n".to_owned()
+}
+
+// read the operation output from the response body, headers and status
+fn output_reader(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+    let has_located_members = shape.members.values().any(rest::is_located);
+
+    if has_located_members {
+        src.push_str("\t\tlet headers = result.headers.clone();\n");
+    }
+
+    if shape.members.values().any(|member| member.location.as_ref().map(|location| location == "statusCode").unwrap_or(false)) {
+        src.push_str("\t\tlet status = result.status.to_u16();\n");
+    }
+
+    if let Some(ref payload) = shape.payload {
+        let member = shape.members.get(payload).expect("Payload member undefined");
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+        let value = match &member_shape.shape_type[..] {
+            "blob" => {
+                src.push_str("\t\tlet mut body = Vec::new();\n");
+                src.push_str("\t\ttry!(result.read_to_end(&mut body).map_err(AWSError::from));\n");
+                "::blob::Blob(body)".to_owned()
+            },
+            "string" => {
+                src.push_str(&read_body());
+                "body".to_owned()
+            },
+            _ => {
+                src.push_str(&read_body());
+                src.push_str(&decode_body("decoded", &member.shape));
+                "decoded".to_owned()
+            },
+        };
+
+        src.push_str(&format!("\t\tlet mut output = {}::default();\n", name));
+
+        if shape.required(payload) {
+            src.push_str(&format!("\t\toutput.{} = {};\n", snake_case(payload), value));
+        } else {
+            src.push_str(&format!("\t\toutput.{} = Some({});\n", snake_case(payload), value));
+        }
+    } else if shape.members.values().any(|member| !rest::is_located(member)) {
+        src.push_str(&read_body());
+        src.push_str(&decode_body(if has_located_members { "mut output" } else { "output" }, name));
+    } else if has_located_members {
+        src.push_str(&format!("\t\tlet mut output = {}::default();\n", name));
+    } else {
+        src.push_str(&format!("\t\tlet output = {}::default();\n", name));
+    }
+
+    src.push_str(&rest::output_header_reader(service, shape));
+    src.push_str("\t\tOk(output)\n");
+    src
+}
+
+fn read_body() -> String {
+    "\t\tlet mut body = String::new();\n\t\ttry!(result.read_to_string(&mut body).map_err(AWSError::from));\n".to_owned()
+}
+
+// decode `body` as JSON into a new binding, returning an error if it doesn't match the shape
+fn decode_body(binding: &str, type_name: &str) -> String {
+    format!("\t\tlet {}: {} = match serde_json::from_str(&body) {{\n\t\t\tOk(decoded) => decoded,\n\t\t\tErr(_) => return Err(parse_error(result.status.to_u16(), &body)),\n\t\t}};\n",
+        binding, type_name)
+}
+
+fn body_name(member_name: &str, member: &Member) -> String {
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => member_name.to_owned(),
+    }
+}
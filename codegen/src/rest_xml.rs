@@ -0,0 +1,587 @@
+//! Code generation for AWS services with protocol type "rest-xml", such as S3 and Route 53.
+//!
+//! Input members are bound to the request URI, query string, headers or an XML body according to
+//! their `location`, and outputs are read back from the response headers, status code and body.
+//! Each shape gets a Rust type, a `*Parser` that reads it from XML and a `*Serializer` that writes it.
+
+use botocore::{Member, Operation, Service, Shape};
+use doc::rustdoc;
+use enums;
+use rest;
+use super::{prepare_input, signing_metadata, snake_case};
+
+pub fn generate_prelude(_service: &Service) -> String {
+    let mut src = "use std::ascii::AsciiExt;
+use std::collections::HashMap;
+use std::str;
+
+fn xml_escape(value: &str) -> String {
+\tvalue.replace(\"&\", \"&amp;\")
+\t\t.replace(\"<\", \"&lt;\")
+\t\t.replace(\">\", \"&gt;\")
+\t\t.replace(\"\\\"\", \"&quot;\")
+\t\t.replace(\"'\", \"&apos;\")
+}
+".to_owned();
+
+    src.push_str(rest::generate_helpers());
+    src
+}
+
+pub fn generate_shapes(service: &Service) -> String {
+    let mut src = String::new();
+
+    for (name, shape) in service.shapes.iter() {
+        src.push_str(&rust_type(name, shape));
+        src.push_str(&type_parser(service, name, shape));
+        src.push_str(&type_serializer(service, name, shape));
+    }
+
+    src
+}
+
+pub fn generate_operations(service: &Service) -> String {
+    let mut src = String::new();
+
+    for operation in service.operations.values() {
+        src.push_str(&request_method(service, operation));
+    }
+
+    src
+}
+
+fn rust_type(name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+
+    if let Some(ref docs) = shape.documentation {
+        src.push_str(&rustdoc(docs, ""));
+    }
+
+    if shape.shape_type == "structure" {
+        src.push_str(&struct_type(name, shape));
+    } else if enums::is_enum(shape) {
+        src.push_str(&enums::enum_type(name, shape));
+    } else {
+        let rust_type = match &shape.shape_type[..] {
+            "map" => format!("HashMap<{},{}>", shape.key(), shape.value()),
+            "list" => format!("Vec<{}>", shape.member()),
+            primitive => primitive_type(primitive).to_owned(),
+        };
+
+        // a String is already a String in Rust
+        if name != "String" {
+            src.push_str(&format!("pub type {} = {};\n", name, rust_type));
+        }
+    }
+
+    src
+}
+
+fn struct_type(name: &str, shape: &Shape) -> String {
+    let mut src = "#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]\n".to_owned();
+
+    if shape.members.is_empty() {
+        src.push_str(&format!("pub struct {};\n\n", name));
+        return src;
+    }
+
+    src.push_str(&format!("pub struct {} {{\n", name));
+
+    for (member_name, member) in shape.members.iter() {
+        if let Some(ref docs) = member.documentation {
+            src.push_str(&rustdoc(docs, "\t"));
+        }
+
+        src.push_str(&format!("\t#[serde(rename=\"{}\")]\n", member_name));
+        if shape.required(member_name) {
+            src.push_str(&format!("\tpub {}: {},\n", snake_case(member_name), member.shape));
+        } else {
+            src.push_str(&format!("\tpub {}: Option<{}>,\n", snake_case(member_name), member.shape));
+        }
+    }
+
+    src.push_str("}\n\n");
+    src
+}
+
+// generate rust code to parse a botocore shape from XML.
+// List and map parsers read consecutive `tag_name` elements; any wrapping element is handled by the
+// parser of the enclosing structure, as only it knows whether the member is flattened.
+fn type_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Parse {} from XML\nstruct {}Parser;\nimpl {}Parser {{\n", name, name, name);
+    src.push_str(&format!("\tfn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<{}, XmlParseError> {{\n", name));
+
+    match &shape.shape_type[..] {
+        "map" => src.push_str(&map_parser(shape)),
+        "list" => src.push_str(&list_parser(shape)),
+        "structure" => src.push_str(&struct_parser(service, name, shape)),
+        "string" => {
+            let (value, empty) = if enums::is_enum(shape) {
+                (format!("{}::from(&try!(characters(stack))[..])", name), format!("{}::default()", name))
+            } else {
+                ("try!(characters(stack))".to_owned(), "String::new()".to_owned())
+            };
+
+            // S3 sends empty elements such as <Prefix></Prefix>, which have no characters to read
+            src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+            src.push_str(&format!("\t\tlet obj = if at_end_element(stack) {{ {} }} else {{ {} }};\n", empty, value));
+            src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+        },
+        primitive => {
+            src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+            src.push_str(&format!("\t\tlet obj = {};\n", primitive_parser(primitive)));
+            src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+        }
+    }
+
+    src.push_str("\t\tOk(obj)\n\t}\n}\n");
+    src
+}
+
+// guts of the XML parser for struct shapes
+fn struct_parser(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+    let attributes: Vec<(&String, &Member)> = shape.members.iter().filter(|&(_, member)| is_attribute(member)).collect();
+
+    if attributes.is_empty() {
+        src.push_str("\t\ttry!(start_element(tag_name, stack));\n");
+    } else {
+        src.push_str("\t\tlet attributes = try!(start_element(tag_name, stack));\n");
+    }
+
+    let elements: Vec<(&String, &Member)> = shape.members.iter().filter(|&(_, member)| is_body_element(member)).collect();
+
+    if attributes.is_empty() && elements.is_empty() {
+        src.push_str(&format!("\t\tlet obj = {}::default();\n", name));
+    } else {
+        src.push_str(&format!("\t\tlet mut obj = {}::default();\n", name));
+    }
+
+    // xmlutil keys attributes by their local name, so xsi:type is found as "type"
+    for &(member_name, member) in attributes.iter() {
+        let location_name = location_name(member_name, member);
+        let local_name = location_name.rsplit(':').next().unwrap_or(&location_name);
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+        let mut value = if enums::is_enum(member_shape) { format!("{}::from(&value[..])", member.shape) } else { "value.to_owned()".to_owned() };
+        if !shape.required(member_name) {
+            value = format!("Some({})", value);
+        }
+
+        src.push_str(&format!("\t\tif let Some(value) = attributes.get(\"{}\") {{\n", local_name));
+        src.push_str(&format!("\t\t\tobj.{} = {};\n", snake_case(member_name), value));
+        src.push_str("\t\t}\n");
+    }
+
+    if !elements.is_empty() {
+        src.push_str("\t\tloop {\n");
+        src.push_str("\t\t\tlet current_name = try!(peek_at_name(stack));\n");
+
+        for &(member_name, member) in elements.iter() {
+            let tag_name = location_name(member_name, member);
+
+            src.push_str(&format!("\t\t\tif current_name == \"{}\" {{\n", tag_name));
+            src.push_str(&member_parser(service, shape, member_name, member, &tag_name));
+            src.push_str("\t\t\t\tcontinue;\n");
+            src.push_str("\t\t\t}\n");
+        }
+
+        src.push_str("\t\t\tbreak;\n\t\t}\n");
+    }
+
+    src.push_str("\t\ttry!(end_element(tag_name, stack));\n");
+    src
+}
+
+// parse a single struct member, unwrapping non-flattened lists and maps
+fn member_parser(service: &Service, shape: &Shape, member_name: &str, member: &Member, tag_name: &str) -> String {
+    let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+    let mut src = String::new();
+
+    let mut parse_stmt = match wrapped_item_name(member, member_shape) {
+        Some(item_name) => {
+            src.push_str(&format!("\t\t\t\ttry!(start_element(\"{}\", stack));\n", tag_name));
+            src.push_str(&format!("\t\t\t\tlet value = try!({}Parser::parse_xml(\"{}\", stack));\n", member.shape, item_name));
+            src.push_str(&format!("\t\t\t\ttry!(end_element(\"{}\", stack));\n", tag_name));
+            "value".to_owned()
+        },
+        None => format!("try!({}Parser::parse_xml(\"{}\", stack))", member.shape, tag_name),
+    };
+
+    if !shape.required(member_name) {
+        parse_stmt = format!("Some({})", parse_stmt);
+    }
+
+    src.push_str(&format!("\t\t\t\tobj.{} = {};\n", snake_case(member_name), parse_stmt));
+    src
+}
+
+// guts of the XML parser for map shapes
+fn map_parser(shape: &Shape) -> String {
+    let key = shape.key.as_ref().expect("Key shape undefined");
+    let value = shape.value.as_ref().expect("Value shape undefined");
+
+    let mut src = "\t\tlet mut obj = HashMap::new();\n".to_owned();
+    src.push_str("\t\twhile try!(peek_at_name(stack)) == tag_name {\n");
+    src.push_str("\t\t\ttry!(start_element(tag_name, stack));\n");
+    src.push_str(&format!("\t\t\tlet key = try!({}Parser::parse_xml(\"{}\", stack));\n", key.shape, member_location_name(key, "key")));
+    src.push_str(&format!("\t\t\tlet value = try!({}Parser::parse_xml(\"{}\", stack));\n", value.shape, member_location_name(value, "value")));
+    src.push_str("\t\t\tobj.insert(key, value);\n");
+    src.push_str("\t\t\ttry!(end_element(tag_name, stack));\n");
+    src.push_str("\t\t}\n");
+    src
+}
+
+// guts of the XML parser for list shapes
+fn list_parser(shape: &Shape) -> String {
+    let mut src = "\t\tlet mut obj = Vec::new();\n".to_owned();
+    src.push_str("\t\twhile try!(peek_at_name(stack)) == tag_name {\n");
+    src.push_str(&format!("\t\t\tobj.push(try!({}Parser::parse_xml(tag_name, stack)));\n", shape.member()));
+    src.push_str("\t\t}\n");
+    src
+}
+
+// generate rust code to write a botocore shape as XML.
+// As with parsers, list and map serializers write consecutive `name` elements.
+fn type_serializer(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("/// Serialize {} contents to XML\nstruct {}Serializer;\nimpl {}Serializer {{\n", name, name, name);
+    src.push_str(&format!("\tfn serialize(name: &str, obj: &{}) -> String {{\n", name));
+
+    match &shape.shape_type[..] {
+        "map" => src.push_str(&map_serializer(shape)),
+        "list" => src.push_str(&list_serializer(shape)),
+        "structure" => {
+            src.push_str(&struct_attributes(service, shape));
+            src.push_str("\t\tformat!(\"<{}{}>{}</{}>\", name, attributes, Self::serialize_members(obj), name)\n");
+            src.push_str("\t}\n\n");
+            if shape.members.values().any(is_body_element) {
+                src.push_str(&format!("\tfn serialize_members(obj: &{}) -> String {{\n", name));
+                src.push_str(&struct_serializer(service, shape));
+            } else {
+                src.push_str(&format!("\tfn serialize_members(_obj: &{}) -> String {{\n", name));
+                src.push_str("\t\tString::new()\n");
+            }
+        },
+        "string" if enums::is_enum(shape) => src.push_str("\t\tformat!(\"<{}>{}</{}>\", name, xml_escape(obj.as_str()), name)\n"),
+        primitive => src.push_str(&format!("\t\tformat!(\"<{{}}>{{}}</{{}}>\", name, {}, name)\n", primitive_serializer(primitive))),
+    }
+
+    src.push_str("\t}\n}\n");
+    src
+}
+
+// XML attributes, including any namespace declaration they need
+fn struct_attributes(service: &Service, shape: &Shape) -> String {
+    if shape.xmlNamespace.is_none() && !shape.members.values().any(is_attribute) {
+        return "\t\tlet attributes = \"\";\n".to_owned();
+    }
+
+    let mut src = "\t\tlet mut attributes = String::new();\n".to_owned();
+
+    if let Some(ref namespace) = shape.xmlNamespace {
+        match namespace.prefix {
+            Some(ref prefix) => src.push_str(&format!("\t\tattributes.push_str(\" xmlns:{}=\\\"{}\\\"\");\n", prefix, namespace.uri)),
+            None => src.push_str(&format!("\t\tattributes.push_str(\" xmlns=\\\"{}\\\"\");\n", namespace.uri)),
+        }
+    }
+
+    for (member_name, member) in shape.members.iter().filter(|&(_, member)| is_attribute(member)) {
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+        let value = if enums::is_enum(member_shape) { "value.as_str()" } else { "value" };
+        let attribute = format!("attributes.push_str(&format!(\" {}=\\\"{{}}\\\"\", xml_escape({})));\n", location_name(member_name, member), value);
+
+        if shape.required(member_name) {
+            src.push_str(&format!("\t\tlet value = &obj.{};\n\t\t{}", snake_case(member_name), attribute));
+        } else {
+            src.push_str(&format!("\t\tif let Some(ref value) = obj.{} {{\n\t\t\t{}\t\t}}\n", snake_case(member_name), attribute));
+        }
+    }
+
+    src
+}
+
+// guts of the serializer for struct shapes, skipping members that aren't part of the XML body
+fn struct_serializer(service: &Service, shape: &Shape) -> String {
+    let mut src = "\t\tlet mut serialized = String::new();\n".to_owned();
+
+    for (member_name, member) in shape.members.iter().filter(|&(_, member)| is_body_element(member)) {
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+        let tag_name = location_name(member_name, member);
+
+        let serialize_stmt = match wrapped_item_name(member, member_shape) {
+            Some(item_name) => format!("serialized.push_str(&format!(\"<{}>{{}}</{}>\", {}Serializer::serialize(\"{}\", value)));\n",
+                tag_name, tag_name, member.shape, item_name),
+            None => format!("serialized.push_str(&{}Serializer::serialize(\"{}\", value));\n", member.shape, tag_name),
+        };
+
+        if shape.required(member_name) {
+            src.push_str(&format!("\t\tlet value = &obj.{};\n\t\t{}", snake_case(member_name), serialize_stmt));
+        } else {
+            src.push_str(&format!("\t\tif let Some(ref value) = obj.{} {{\n\t\t\t{}\t\t}}\n", snake_case(member_name), serialize_stmt));
+        }
+    }
+
+    src.push_str("\t\tserialized\n");
+    src
+}
+
+// guts of the serializer for list shapes
+fn list_serializer(shape: &Shape) -> String {
+    let mut src = "\t\tlet mut serialized = String::new();\n".to_owned();
+    src.push_str("\t\tfor element in obj.iter() {\n");
+    src.push_str(&format!("\t\t\tserialized.push_str(&{}Serializer::serialize(name, element));\n", shape.member()));
+    src.push_str("\t\t}\n");
+    src.push_str("\t\tserialized\n");
+    src
+}
+
+// guts of the serializer for map shapes
+fn map_serializer(shape: &Shape) -> String {
+    let key = shape.key.as_ref().expect("Key shape undefined");
+    let value = shape.value.as_ref().expect("Value shape undefined");
+
+    let mut src = "\t\tlet mut serialized = String::new();\n".to_owned();
+    src.push_str("\t\tfor (key, value) in obj.iter() {\n");
+    src.push_str(&format!("\t\t\tserialized.push_str(&format!(\"<{{}}>{{}}{{}}</{{}}>\", name, {}Serializer::serialize(\"{}\", key), {}Serializer::serialize(\"{}\", value), name));\n",
+        key.shape, member_location_name(key, "key"), value.shape, member_location_name(value, "value")));
+    src.push_str("\t\t}\n");
+    src.push_str("\t\tserialized\n");
+    src
+}
+
+fn request_method(service: &Service, operation: &Operation) -> String {
+    let output_type = operation.output_shape_or("()");
+    let mut src = String::new();
+
+    if let Some(ref docs) = operation.documentation {
+        src.push_str(&rustdoc(docs, "\t"));
+    }
+
+    let input_shape = operation.input.as_ref().map(|input| service.shapes.get(&input.shape).expect("Shape undefined"));
+
+    if operation.input.is_some() {
+        src.push_str(&format!("\tpub fn {}(&mut self, input: &{}) -> Result<{}, AWSError> {{\n",
+            snake_case(&operation.name), operation.input_shape(), output_type));
+        src.push_str(&prepare_input(service, operation));
+    } else {
+        src.push_str(&format!("\tpub fn {}(&mut self) -> Result<{}, AWSError> {{\n", snake_case(&operation.name), output_type));
+    }
+
+    src.push_str(&rest::request_uri_writer(service, operation));
+
+    if let Some(shape) = input_shape {
+        src.push_str(&payload_writer(service, operation, shape));
+    }
+
+    src.push_str(&format!("\t\tlet mut request = SignedRequest::new(\"{}\", \"{}\", &self.region, &path);\n",
+        operation.http.method, service.metadata.endpointPrefix));
+    src.push_str(&signing_metadata(service));
+    src.push_str("\t\trequest.set_params(params);\n");
+
+    if let Some(shape) = input_shape {
+        src.push_str(&rest::header_writer(service, shape));
+        src.push_str("\t\trequest.set_payload(payload);\n");
+    }
+
+    src.push_str("\t\tlet mut result = request.sign_and_execute(try!(self.creds.get_credentials()));\n");
+    src.push_str("\t\tlet status = result.status.to_u16();\n");
+    src.push_str("\t\tif !result.status.is_success() {\n");
+    src.push_str("\t\t\tlet mut body = String::new();\n");
+    src.push_str("\t\t\ttry!(result.read_to_string(&mut body));\n");
+    src.push_str("\t\t\treturn Err(AWSError::new(format!(\"{}: {}\", status, body)));\n");
+    src.push_str("\t\t}\n");
+
+    match operation.output {
+        Some(ref output) => {
+            let output_shape = service.shapes.get(&output.shape).expect("Shape undefined");
+            src.push_str(&output_reader(service, &output.shape, output_shape));
+        },
+        None => src.push_str("\t\tOk(())\n"),
+    }
+
+    src.push_str("\t}\n");
+    src
+}
+
+// build the request body from either the payload member or the input's body members
+fn payload_writer(service: &Service, operation: &Operation, shape: &Shape) -> String {
+    let input = operation.input.as_ref().expect("Operation has no input");
+
+    if let Some(ref payload) = shape.payload {
+        let member = shape.members.get(payload).expect("Payload member undefined");
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+        let field = snake_case(payload);
+        let required = shape.required(payload);
+
+        return match &member_shape.shape_type[..] {
+            "blob" if required => format!("\t\tlet payload = Some(&input.{}[..]);\n", field),
+            "blob" => format!("\t\tlet payload = input.{}.as_ref().map(|value| &value[..]);\n", field),
+            "string" if required => format!("\t\tlet payload = Some(input.{}.as_bytes());\n", field),
+            "string" => format!("\t\tlet payload = input.{}.as_ref().map(|value| value.as_bytes());\n", field),
+            _ => {
+                let location_name = location_name(payload, member);
+                let serialized = format!("format!(\"<{}{}>{{}}</{}>\", {}Serializer::serialize_members(value)).into_bytes()",
+                    location_name, namespace_attribute(member.xmlNamespace.as_ref().map(|ns| &ns.uri)), location_name, member.shape);
+
+                if required {
+                    format!("\t\tlet value = &input.{};\n\t\tlet payload_bytes = {};\n\t\tlet payload = Some(&payload_bytes[..]);\n", field, serialized)
+                } else {
+                    format!("\t\tlet payload_bytes = input.{}.as_ref().map(|value| {});\n\t\tlet payload = payload_bytes.as_ref().map(|bytes| &bytes[..]);\n", field, serialized)
+                }
+            },
+        };
+    }
+
+    if shape.members.values().any(is_body_element) {
+        let location_name = input.locationName.as_ref().unwrap_or(&input.shape);
+        return format!("\t\tlet payload_bytes = format!(\"<{}{}>{{}}</{}>\", {}Serializer::serialize_members(&input)).into_bytes();\n\t\tlet payload = Some(&payload_bytes[..]);\n",
+            location_name, namespace_attribute(input.xmlNamespace.as_ref().map(|ns| &ns.uri)), location_name, input.shape);
+    }
+
+    "\t\tlet payload = None;\n".to_owned()
+}
+
+// read the operation output from the response headers, status and body
+fn output_reader(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = String::new();
+    let header_reader = rest::output_header_reader(service, shape);
+    let output = if header_reader.is_empty() { "output" } else { "mut output" };
+
+    if shape.members.values().any(is_header) {
+        src.push_str("\t\tlet headers = result.headers.clone();\n");
+    }
+
+    if let Some(ref payload) = shape.payload {
+        let member = shape.members.get(payload).expect("Payload member undefined");
+        let member_shape = service.shapes.get(&member.shape).expect("Shape undefined");
+
+        src.push_str(&format!("\t\tlet mut output = {}::default();\n", name));
+
+        let value = match &member_shape.shape_type[..] {
+            "blob" => {
+                src.push_str("\t\tlet mut body = Vec::new();\n");
+                src.push_str("\t\ttry!(result.read_to_end(&mut body));\n");
+                "::blob::Blob(body)".to_owned()
+            },
+            "string" => {
+                src.push_str("\t\tlet mut body = String::new();\n");
+                src.push_str("\t\ttry!(result.read_to_string(&mut body));\n");
+                "body".to_owned()
+            },
+            _ => {
+                src.push_str(&xml_stack());
+                format!("try!({}Parser::parse_xml(&tag_name, &mut stack))", member.shape)
+            },
+        };
+
+        if shape.required(payload) {
+            src.push_str(&format!("\t\toutput.{} = {};\n", snake_case(payload), value));
+        } else {
+            src.push_str(&format!("\t\toutput.{} = Some({});\n", snake_case(payload), value));
+        }
+    } else if shape.members.values().any(is_body_element) {
+        src.push_str(&xml_stack());
+        src.push_str(&format!("\t\tlet {} = try!({}Parser::parse_xml(&tag_name, &mut stack));\n", output, name));
+    } else {
+        src.push_str(&format!("\t\tlet {} = {}::default();\n", output, name));
+    }
+
+    src.push_str(&header_reader);
+    src.push_str("\t\tOk(output)\n");
+    src
+}
+
+// the response body's root element is named differently by each service, so take whatever is there
+fn xml_stack() -> String {
+    let mut src = "\t\tlet mut reader = EventReader::new(result);\n".to_owned();
+    src.push_str("\t\tlet mut stack = XmlResponseFromAws::new(reader.events().peekable());\n");
+    src.push_str("\t\tstack.next(); // xml start tag\n");
+    src.push_str("\t\tlet tag_name = try!(peek_at_name(&mut stack));\n");
+    src
+}
+
+fn namespace_attribute(uri: Option<&String>) -> String {
+    match uri {
+        Some(uri) => format!(" xmlns=\\\"{}\\\"", uri),
+        None => String::new(),
+    }
+}
+
+// Members bound to the URI, query string, headers or status code aren't part of the XML body
+fn is_body_element(member: &Member) -> bool {
+    member.location.is_none() && !is_attribute(member)
+}
+
+fn is_header(member: &Member) -> bool {
+    match member.location {
+        Some(ref location) => location == "header" || location == "headers",
+        None => false,
+    }
+}
+
+fn is_attribute(member: &Member) -> bool {
+    member.xmlAttribute.unwrap_or(false)
+}
+
+// the element name for a list or map member, when it's wrapped in an element named for the member
+fn wrapped_item_name(member: &Member, member_shape: &Shape) -> Option<String> {
+    if member.flattened.unwrap_or(false) || member_shape.flattened.unwrap_or(false) {
+        return None;
+    }
+
+    match &member_shape.shape_type[..] {
+        "list" => Some(member_location_name(member_shape.member.as_ref().expect("Member shape undefined"), "member")),
+        "map" => Some("entry".to_owned()),
+        _ => None,
+    }
+}
+
+fn location_name(member_name: &str, member: &Member) -> String {
+    member_location_name(member, member_name)
+}
+
+fn member_location_name(member: &Member, default: &str) -> String {
+    match member.locationName {
+        Some(ref location_name) => location_name.to_owned(),
+        None => default.to_owned(),
+    }
+}
+
+fn primitive_type(shape_type: &str) -> &'static str {
+    match shape_type {
+        "string" => "String",
+        "timestamp" => "::timestamp::Timestamp",
+        "integer" => "i32",
+        "long" => "i64",
+        "float" => "f32",
+        "double" => "f64",
+        "blob" => "::blob::Blob",
+        "boolean" => "bool",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
+
+// rust code to pull primitive types from XML
+fn primitive_parser(shape_type: &str) -> &'static str {
+    match shape_type {
+        "integer" => "try!(i32::from_str(try!(characters(stack)).as_ref()))",
+        "long" => "try!(i64::from_str(try!(characters(stack)).as_ref()))",
+        "float" => "try!(f32::from_str(try!(characters(stack)).as_ref()))",
+        "double" => "try!(f64::from_str(try!(characters(stack)).as_ref()))",
+        "blob" => "::blob::Blob(try!(::blob::decode_base64(&try!(characters(stack)))))",
+        "boolean" => "try!(bool::from_str(try!(characters(stack)).as_ref()))",
+        "timestamp" => "try!(::timestamp::Timestamp::from_iso8601(&try!(characters(stack))))",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
+
+// rust code to write primitive types as XML text
+fn primitive_serializer(shape_type: &str) -> &'static str {
+    match shape_type {
+        "string" => "xml_escape(obj)",
+        "timestamp" => "obj.iso8601()",
+        "integer" | "long" | "float" | "double" | "boolean" => "obj.to_string()",
+        "blob" => "::blob::encode_base64(obj)",
+        _ => panic!("Unknown type '{}'", shape_type),
+    }
+}
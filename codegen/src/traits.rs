@@ -0,0 +1,73 @@
+//! A trait per service with a method for each operation.
+//!
+//! The client implements it by calling its own methods, so application code can take any
+//! `T: DynamoDB` and tests can pass an in-memory implementation instead of a `DynamoDBClient`.
+
+use botocore::{Operation, Service};
+use doc::rustdoc;
+use super::{result_type, snake_case};
+
+/// The service trait and its implementation for the client.
+pub fn generate_trait(service: &Service, client_type_name: &str) -> String {
+    let name = trait_name(client_type_name);
+    let mut src = String::new();
+
+    src.push_str(&format!("/// The operations of `{}`, for code that should also work with a substitute client.\n", client_type_name));
+    src.push_str(&format!("pub trait {} {{\n", name));
+    for operation in service.operations.values() {
+        if let Some(ref docs) = operation.documentation {
+            src.push_str(&rustdoc(docs, "\t"));
+        }
+        src.push_str(&format!("\t{};\n", signature(service, operation)));
+    }
+    src.push_str("}\n\n");
+
+    src.push_str(&format!("impl<'a> {} for {}<'a> {{\n", name, client_type_name));
+    for operation in service.operations.values() {
+        let args = if operation.input.is_some() { "self, input" } else { "self" };
+        src.push_str(&format!("\t{} {{\n", signature(service, operation)));
+        src.push_str(&format!("\t\t{}::{}({})\n", client_type_name, snake_case(&operation.name), args));
+        src.push_str("\t}\n");
+    }
+    src.push_str("}\n\n");
+
+    src
+}
+
+fn signature(service: &Service, operation: &Operation) -> String {
+    let output_type = result_type(service, operation.output_shape_or("()"));
+
+    if operation.input.is_some() {
+        format!("fn {}(&mut self, input: &{}) -> {}", snake_case(&operation.name), operation.input_shape(), output_type)
+    } else {
+        format!("fn {}(&mut self) -> {}", snake_case(&operation.name), output_type)
+    }
+}
+
+// the client's name without `Client`, with acronyms capitalized like words, e.g. SQSClient => Sqs
+fn trait_name(client_type_name: &str) -> String {
+    let name = client_type_name.trim_right_matches("Client");
+
+    if name.chars().all(|c| c.is_uppercase() || c.is_numeric()) {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_string() + &chars.as_str().to_lowercase(),
+            None => String::new(),
+        }
+    } else {
+        name.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::trait_name;
+
+    #[test]
+    fn trait_name_from_client_name() {
+        assert_eq!(trait_name("DynamoDBClient"), "DynamoDB");
+        assert_eq!(trait_name("SQSClient"), "Sqs");
+        assert_eq!(trait_name("EC2Client"), "Ec2");
+        assert_eq!(trait_name("S3Client"), "S3");
+    }
+}
@@ -0,0 +1,207 @@
+//! Client-side validation of operation inputs.
+//!
+//! Botocore constrains members with a `required` list, `min` and `max` lengths or values, and a
+//! `pattern`. Every structure that can appear in an operation's input gets a `validate` method
+//! checking them, and operations call it before signing the request, so invalid input is
+//! reported as an `AWSError` without a round trip to AWS.
+
+use std::collections::BTreeSet;
+
+use regex::Regex;
+
+use botocore::{Service, Shape};
+use super::{member_required, snake_case};
+use enums;
+use query;
+
+/// `validate` methods for the structures reachable from operation inputs.
+pub fn generate_validators(service: &Service) -> String {
+    let mut src = String::new();
+
+    for name in input_shapes(service).iter() {
+        let shape = service.shapes.get(name).expect("Shape undefined");
+        if shape.shape_type == "structure" {
+            src.push_str(&validate_method(service, name, shape));
+        }
+    }
+
+    src
+}
+
+/// Names of the shapes that operation inputs are built from.
+pub fn input_shapes(service: &Service) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut pending: Vec<String> = service.operations.values()
+        .filter_map(|operation| operation.input.as_ref().map(|input| input.shape.clone()))
+        .collect();
+
+    while let Some(name) = pending.pop() {
+        if names.contains(&name) {
+            continue;
+        }
+
+        let shape = service.shapes.get(&name).expect("Shape undefined");
+        for member in shape.members.values() {
+            pending.push(member.shape.clone());
+        }
+        for member in shape.member.iter().chain(shape.key.iter()).chain(shape.value.iter()) {
+            pending.push(member.shape.clone());
+        }
+
+        names.insert(name);
+    }
+
+    names
+}
+
+fn validate_method(service: &Service, name: &str, shape: &Shape) -> String {
+    let mut src = format!("impl {} {{\n", name);
+    src.push_str("\t/// Checks the members against the constraints in the service's model.\n");
+    src.push_str("\tpub fn validate(&self) -> ::std::result::Result<(), AWSError> {\n");
+
+    for (member_name, member) in shape.members.iter() {
+        let label = format!("{}.{}", name, member_name);
+        let required = shape.required(member_name);
+        let checks = value_checks(service, &member.shape, "value", &label, required, "\t\t\t");
+
+        if checks.is_empty() {
+            continue;
+        }
+
+        if member_required(service, shape, member_name) {
+            src.push_str("\t\t{\n");
+            src.push_str(&format!("\t\t\tlet value = &self.{};\n", snake_case(member_name)));
+        } else {
+            src.push_str(&format!("\t\tif let Some(ref value) = self.{} {{\n", snake_case(member_name)));
+        }
+        src.push_str(&checks);
+        src.push_str("\t\t}\n");
+    }
+
+    src.push_str("\t\tOk(())\n");
+    src.push_str("\t}\n");
+    src.push_str("}\n\n");
+    src
+}
+
+// checks on the value referenced by `var`, returning early with an error
+fn value_checks(service: &Service, shape_name: &str, var: &str, label: &str, required: bool, indent: &str) -> String {
+    let shape = service.shapes.get(shape_name).expect("Shape undefined");
+    let mut src = String::new();
+
+    match &shape.shape_type[..] {
+        "structure" => {
+            src.push_str(&format!("{}try!({}.validate());\n", indent, var));
+        }
+        "string" if !enums::is_enum(shape) => {
+            if required && shape.min.is_none() {
+                src.push_str(&check(indent, &format!("{}.is_empty()", var), &format!("{} is required", label)));
+            }
+            src.push_str(&length_checks(shape.min, shape.max, &format!("{}.chars().count()", var), label, indent));
+            if let Some(pattern) = shape.pattern.as_ref().and_then(|pattern| anchored(pattern)) {
+                // compiled on first use rather than on every call
+                let regex = format!("{}_PATTERN", var.to_uppercase());
+                src.push_str(&format!("{}lazy_static! {{ static ref {}: ::regex::Regex = ::regex::Regex::new({:?}).unwrap(); }}\n",
+                    indent, regex, pattern));
+                src.push_str(&check(indent, &format!("!{}.is_match({})", regex, var), &format!("{} must match {}", label, pattern)));
+            }
+        }
+        "blob" => {
+            src.push_str(&length_checks(shape.min, shape.max, &format!("{}.len()", var), label, indent));
+        }
+        "list" => {
+            let max = shape.max.or_else(|| missing_max(service, shape_name));
+            src.push_str(&length_checks(shape.min, max, &format!("{}.len()", var), label, indent));
+
+            let item_checks = value_checks(service, shape.member(), "item", label, false, &format!("{}\t", indent));
+            if !item_checks.is_empty() {
+                src.push_str(&format!("{}for item in {}.iter() {{\n", indent, var));
+                src.push_str(&item_checks);
+                src.push_str(&format!("{}}}\n", indent));
+            }
+        }
+        "map" => {
+            src.push_str(&length_checks(shape.min, shape.max, &format!("{}.len()", var), label, indent));
+
+            let entry_indent = format!("{}\t", indent);
+            let key_checks = value_checks(service, shape.key(), "key", label, false, &entry_indent);
+            let entry_value_checks = value_checks(service, shape.value(), "value", label, false, &entry_indent);
+            if !key_checks.is_empty() || !entry_value_checks.is_empty() {
+                let key_var = if key_checks.is_empty() { "_" } else { "key" };
+                let value_var = if entry_value_checks.is_empty() { "_" } else { "value" };
+                src.push_str(&format!("{}for ({}, {}) in {}.iter() {{\n", indent, key_var, value_var, var));
+                src.push_str(&key_checks);
+                src.push_str(&entry_value_checks);
+                src.push_str(&format!("{}}}\n", indent));
+            }
+        }
+        "integer" | "long" => {
+            let (low, high) = if shape.shape_type == "integer" {
+                (::std::i32::MIN as f64, ::std::i32::MAX as f64)
+            } else {
+                (::std::i64::MIN as f64, ::std::i64::MAX as f64)
+            };
+            if let Some(min) = shape.min.iter().cloned().find(|&min| min > low && min <= high) {
+                src.push_str(&check(indent, &format!("*{} < {}", var, min as i64), &format!("{} must be at least {}", label, min as i64)));
+            }
+            if let Some(max) = shape.max.iter().cloned().find(|&max| max >= low && max < high) {
+                src.push_str(&check(indent, &format!("*{} > {}", var, max as i64), &format!("{} must be at most {}", label, max as i64)));
+            }
+        }
+        "float" | "double" => {
+            if let Some(min) = shape.min {
+                src.push_str(&check(indent, &format!("*{} < {:?}", var, min), &format!("{} must be at least {}", label, min)));
+            }
+            if let Some(max) = shape.max {
+                src.push_str(&check(indent, &format!("*{} > {:?}", var, max), &format!("{} must be at most {}", label, max)));
+            }
+        }
+        _ => {}
+    }
+
+    src
+}
+
+// min and max on the length of a string, blob, list or map
+fn length_checks(min: Option<f64>, max: Option<f64>, length: &str, label: &str, indent: &str) -> String {
+    let mut src = String::new();
+
+    if let Some(min) = min.into_iter().find(|&min| min >= 1.0) {
+        src.push_str(&check(indent, &format!("{} < {}", length, min as u64), &format!("{} is shorter than the minimum length of {}", label, min as u64)));
+    }
+    if let Some(max) = max.into_iter().find(|&max| max < ::std::u32::MAX as f64) {
+        src.push_str(&check(indent, &format!("{} > {}", length, max as u64), &format!("{} is longer than the maximum length of {}", label, max as u64)));
+    }
+
+    src
+}
+
+// a documented maximum that the service's model leaves out
+fn missing_max(service: &Service, shape_name: &str) -> Option<f64> {
+    match &service.metadata.protocol[..] {
+        "query" => query::batch_limit(service, shape_name),
+        _ => None,
+    }
+}
+
+fn check(indent: &str, condition: &str, message: &str) -> String {
+    format!("{indent}if {condition} {{\n{indent}\treturn Err(AWSError::new({message:?}));\n{indent}}}\n",
+        indent = indent, condition = condition, message = message)
+}
+
+// botocore patterns must match the whole value; those the regex crate can't compile are skipped
+fn anchored(pattern: &str) -> Option<String> {
+    let anchored = format!("^(?:{})$", pattern);
+    Regex::new(&anchored).ok().map(|_| anchored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::anchored;
+
+    #[test]
+    fn patterns_match_whole_values() {
+        assert_eq!(anchored("[a-zA-Z0-9_.-]+"), Some("^(?:[a-zA-Z0-9_.-]+)$".to_owned()));
+        assert_eq!(anchored("[a-z"), None);
+    }
+}
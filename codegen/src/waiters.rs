@@ -0,0 +1,522 @@
+//! Waiters, generated from botocore's `waiters-2.json`.
+//!
+//! Each waiter gets a `wait_until_*` client method which calls an operation until one of the
+//! waiter's acceptors matches, sleeping between attempts. Acceptors match the error code or HTTP
+//! status of a failed call, or a JMESPath expression over a successful call's output. Only the
+//! subset of JMESPath that botocore's waiters use is supported: sub-expressions, `[]` flattening,
+//! `[?...]` filters, `!`, `&&`, `||`, comparisons and `length()`. Expressions are compiled to
+//! iterator chains over the output's fields, and waiters with other expressions aren't generated.
+
+use botocore::{Acceptor, Service, Shape, Waiter, Waiters};
+use serde_json::Value;
+use super::{member_required, result_type, snake_case};
+
+/// `wait_until_*` methods for the client's impl block.
+pub fn generate_methods(service: &Service, waiters: &Waiters) -> String {
+    let mut src = String::new();
+
+    for (waiter_name, waiter) in waiters.waiters.iter() {
+        let operation = match service.operations.get(&waiter.operation) {
+            Some(operation) => operation,
+            None => continue,
+        };
+        // waiters using JMESPath beyond the supported subset, or matching errors the protocol
+        // doesn't keep, are left out rather than generated to match the wrong responses
+        let arms = match acceptors(service, waiter_name, waiter, operation.output_shape_or("")) {
+            Ok(arms) => arms,
+            Err(_) => continue,
+        };
+        let method_name = format!("wait_until_{}", snake_case(waiter_name));
+        let operation_method = snake_case(&waiter.operation);
+        let return_type = result_type(service, "()");
+
+        let (input_param, input_arg) = if operation.input.is_some() {
+            (format!(", input: &{}", operation.input_shape()), "input")
+        } else {
+            (String::new(), "")
+        };
+
+        src.push_str(&format!("\t/// Waits until {}, calling `{}` every {} seconds, up to {} times.\n",
+            waiter_name, operation_method, waiter.delay, waiter.maxAttempts));
+        src.push_str(&format!("\tpub fn {}(&mut self{}) -> {} {{\n", method_name, input_param, return_type));
+        src.push_str(&format!("\t\tself.{}_with({}{}{}, {})\n", method_name, input_arg,
+            if input_arg.is_empty() { "" } else { ", " }, waiter.delay, waiter.maxAttempts));
+        src.push_str("\t}\n");
+
+        src.push_str(&format!("\t/// Waits until {}, calling `{}` every `delay` seconds, up to `max_attempts` times.\n",
+            waiter_name, operation_method));
+        src.push_str(&format!("\tpub fn {}_with(&mut self{}, delay: u64, max_attempts: u32) -> {} {{\n",
+            method_name, input_param, return_type));
+        src.push_str("\t\tfor attempt in 0..max_attempts {\n");
+        src.push_str("\t\t\tif attempt > 0 {\n");
+        src.push_str("\t\t\t\t::std::thread::sleep(::std::time::Duration::from_secs(delay));\n");
+        src.push_str("\t\t\t}\n");
+        src.push_str(&format!("\t\t\tmatch self.{}({}) {{\n", operation_method, input_arg));
+        src.push_str(&arms);
+        src.push_str("\t\t\t}\n");
+        src.push_str("\t\t}\n");
+        src.push_str(&format!("\t\tErr(From::from(AWSError::new(format!(\"Waiter {} gave up after {{}} attempts\", max_attempts))))\n",
+            waiter_name));
+        src.push_str("\t}\n");
+    }
+
+    src
+}
+
+// match arms for the operation's result, running the first matching acceptor's action, or why the
+// waiter can't be generated
+fn acceptors(service: &Service, waiter_name: &str, waiter: &Waiter, output_type: &str) -> Result<String, String> {
+    let output_shape = service.shapes.get(output_type);
+    let mut output_src = String::new();
+    let mut error_src = String::new();
+    let mut output_done = false;
+    let mut error_done = false;
+
+    for acceptor in waiter.acceptors.iter() {
+        let (condition, on_output) = match &acceptor.matcher[..] {
+            "path" | "pathAll" | "pathAny" => {
+                let shape = output_shape.expect("Waiter path over an operation without output");
+                (Some(try!(path_condition(service, shape, acceptor))), true)
+            },
+            "status" => {
+                let status = acceptor.expected.as_u64().expect("Invalid waiter status");
+                if status >= 200 && status < 300 {
+                    (None, true)
+                } else {
+                    (Some(try!(status_condition(service, status))), false)
+                }
+            },
+            "error" => {
+                let code = match acceptor.expected {
+                    Value::String(ref code) => code,
+                    _ => panic!("Invalid waiter error code"),
+                };
+                (Some(try!(error_condition(service, code))), false)
+            },
+            matcher => panic!("Unknown waiter matcher '{}'", matcher),
+        };
+
+        // acceptors after one that always matches can never be reached
+        let (src, done) = if on_output {
+            (&mut output_src, &mut output_done)
+        } else {
+            (&mut error_src, &mut error_done)
+        };
+        if *done {
+            continue;
+        }
+
+        let action = match &acceptor.state[..] {
+            "success" => "return Ok(());".to_owned(),
+            "retry" => "continue;".to_owned(),
+            "failure" if on_output => format!("return Err(From::from(AWSError::new(\"Waiter {} failed\")));", waiter_name),
+            "failure" => "return Err(err);".to_owned(),
+            state => panic!("Unknown waiter state '{}'", state),
+        };
+
+        match condition {
+            Some(condition) => src.push_str(&format!("\t\t\t\t\tif {} {{\n\t\t\t\t\t\t{}\n\t\t\t\t\t}}\n", condition, action)),
+            None => {
+                src.push_str(&format!("\t\t\t\t\t{}\n", action));
+                *done = true;
+            },
+        }
+    }
+
+    // errors that no acceptor expects are returned straight away
+    if !error_done {
+        error_src.push_str("\t\t\t\t\treturn Err(err);\n");
+    }
+
+    let binding = if output_src.contains("output") { "output" } else { "_" };
+
+    Ok(format!("\t\t\t\tOk({}) => {{\n{}\t\t\t\t}},\n\t\t\t\tErr(err) => {{\n{}\t\t\t\t}},\n", binding, output_src, error_src))
+}
+
+// json services decode the error type, ec2 and rest-xml services keep the response body in the
+// error message
+fn error_condition(service: &Service, code: &str) -> Result<String, String> {
+    match &service.metadata.protocol[..] {
+        "json" | "rest-json" => Ok(format!("err.__type == \"{0}\" || err.__type.ends_with(\"#{0}\")", code)),
+        "ec2" | "rest-xml" => Ok(format!("err.0.contains(\"<Code>{}</Code>\")", code)),
+        protocol => Err(format!("{} errors don't keep their code", protocol)),
+    }
+}
+
+// json errors keep the response status, ec2 and rest-xml ones start their message with it
+fn status_condition(service: &Service, status: u64) -> Result<String, String> {
+    match &service.metadata.protocol[..] {
+        "json" | "rest-json" => Ok(format!("err.status == {}", status)),
+        "ec2" | "rest-xml" => Ok(format!("err.0.starts_with(\"{}:\")", status)),
+        protocol => Err(format!("{} errors don't keep their status", protocol)),
+    }
+}
+
+// rust code for whether the acceptor's expression over `output` matches its expected value
+fn path_condition(service: &Service, shape: &Shape, acceptor: &Acceptor) -> Result<String, String> {
+    let argument = acceptor.argument.as_ref().expect("Waiter path argument undefined");
+    let expression = try!(parse(argument));
+
+    let steps = match expression {
+        Expression::Path(ref steps) => steps,
+        // other expressions evaluate to a boolean
+        _ => {
+            let value = try!(condition(service, shape, &expression, "::std::iter::once(&output)"));
+            return Ok(match acceptor.expected {
+                Value::Bool(false) => format!("!({})", value),
+                _ => value,
+            });
+        },
+    };
+
+    let (values, _, _) = try!(path(service, shape, steps, "::std::iter::once(&output)"));
+    let expected = expected_value(&acceptor.expected);
+
+    Ok(match &acceptor.matcher[..] {
+        "pathAll" => format!("{{ let values: Vec<String> = {}.map(|value| value.to_string()).collect(); !values.is_empty() && values.iter().all(|value| value == {}) }}",
+            values, expected),
+        "pathAny" => format!("{}.any(|value| value.to_string() == {})", values, expected),
+        _ => format!("{}.next().map(|value| value.to_string() == {}).unwrap_or(false)", values, expected),
+    })
+}
+
+// expected values are compared with the output's values rendered as strings
+fn expected_value(expected: &Value) -> String {
+    match *expected {
+        Value::String(ref value) => format!("{:?}", value),
+        ref value => format!("{:?}", ::serde_json::to_string(value).unwrap()),
+    }
+}
+
+// rust code for a boolean expression, with paths relative to the values of `start`
+fn condition(service: &Service, shape: &Shape, expression: &Expression, start: &str) -> Result<String, String> {
+    Ok(match *expression {
+        Expression::Not(ref inner) => format!("!({})", try!(condition(service, shape, inner, start))),
+        Expression::And(ref left, ref right) => format!("({} && {})",
+            try!(condition(service, shape, left, start)), try!(condition(service, shape, right, start))),
+        Expression::Or(ref left, ref right) => format!("({} || {})",
+            try!(condition(service, shape, left, start)), try!(condition(service, shape, right, start))),
+        // lengths are compared as numbers, anything else as strings
+        Expression::Comparison(ref left, ref operator, ref right) => match (&**left, &**right) {
+            (&Expression::Length(_), _) | (_, &Expression::Length(_)) => format!("{} {} {}",
+                try!(number(service, shape, left, start)), operator, try!(number(service, shape, right, start))),
+            _ => format!("{} {} {}", try!(operand(service, shape, left, start)), operator, try!(operand(service, shape, right, start))),
+        },
+        _ => return Err(format!("Unsupported waiter condition {:?}", expression)),
+    })
+}
+
+// rust code for a usize
+fn number(service: &Service, shape: &Shape, expression: &Expression, start: &str) -> Result<String, String> {
+    match *expression {
+        Expression::Length(ref inner) => length(service, shape, inner, start),
+        Expression::Literal(ref value) => Ok(value.to_owned()),
+        _ => Err(format!("Unsupported waiter number {:?}", expression)),
+    }
+}
+
+// rust code for the length of a list projection, or of the value at a path
+fn length(service: &Service, shape: &Shape, expression: &Expression, start: &str) -> Result<String, String> {
+    match *expression {
+        Expression::Path(ref steps) => {
+            let (values, _, projection) = try!(path(service, shape, steps, start));
+            if projection {
+                Ok(format!("{}.count()", values))
+            } else {
+                Ok(format!("{}.next().map(|value| value.len()).unwrap_or(0)", values))
+            }
+        },
+        // a missing value already has no length, e.g. `length(Output || '')`
+        Expression::Or(ref left, ref right) if **right == Expression::Literal(String::new()) => length(service, shape, left, start),
+        _ => Err(format!("Unsupported waiter length {:?}", expression)),
+    }
+}
+
+// rust code for an Option<String>
+fn operand(service: &Service, shape: &Shape, expression: &Expression, start: &str) -> Result<String, String> {
+    match *expression {
+        Expression::Path(ref steps) => {
+            let (values, _, _) = try!(path(service, shape, steps, start));
+            Ok(format!("{}.next().map(|value| value.to_string())", values))
+        },
+        Expression::Literal(ref value) => Ok(format!("Some({:?}.to_owned())", value)),
+        _ => Err(format!("Unsupported waiter operand {:?}", expression)),
+    }
+}
+
+// rust code for an iterator over the values at a path, its shape, and whether it's a projection
+fn path<'a>(service: &'a Service, shape: &'a Shape, steps: &[Step], start: &str) -> Result<(String, &'a Shape, bool), String> {
+    let mut src = start.to_owned();
+    let mut shape = shape;
+    let mut projection = false;
+
+    for step in steps {
+        match *step {
+            Step::Field(ref member_name) => {
+                let member = shape.members.get(member_name).expect(&format!("Waiter path member {} undefined", member_name));
+                let field = snake_case(member_name);
+
+                if member_required(service, shape, member_name) {
+                    src.push_str(&format!(".map(|value| &value.{})", field));
+                } else {
+                    src.push_str(&format!(".flat_map(|value| value.{}.iter())", field));
+                }
+                shape = service.shapes.get(&member.shape).expect("Shape undefined");
+            },
+            Step::Flatten => {
+                src.push_str(".flat_map(|value| value.iter())");
+                shape = service.shapes.get(shape.member()).expect("Shape undefined");
+                projection = true;
+            },
+            Step::Filter(ref filter) => {
+                let item_shape = service.shapes.get(shape.member()).expect("Shape undefined");
+                src.push_str(&format!(".flat_map(|value| value.iter()).filter(|value| {})",
+                    try!(condition(service, item_shape, filter, "::std::iter::once(*value)"))));
+                shape = item_shape;
+                projection = true;
+            },
+        }
+    }
+
+    Ok((src, shape, projection))
+}
+
+#[derive(Debug, PartialEq)]
+enum Expression {
+    Path(Vec<Step>),
+    Literal(String),
+    Length(Box<Expression>),
+    Comparison(Box<Expression>, String, Box<Expression>),
+    Not(Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+}
+
+#[derive(Debug, PartialEq)]
+enum Step {
+    Field(String),
+    Flatten,
+    Filter(Box<Expression>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Identifier(String),
+    Literal(String),
+    Operator(String),
+}
+
+fn parse(expression: &str) -> Result<Expression, String> {
+    let tokens = tokenize(expression);
+    let mut position = 0;
+    let parsed = try!(parse_or(&tokens, &mut position));
+
+    if position != tokens.len() {
+        return Err(format!("Unsupported waiter expression '{}'", expression));
+    }
+
+    Ok(parsed)
+}
+
+fn tokenize(expression: &str) -> Vec<Token> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        if c.is_whitespace() {
+            index += 1;
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = index;
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+            tokens.push(Token::Identifier(chars[start..index].iter().cloned().collect()));
+        } else if c == '`' || c == '\'' {
+            // `json` literals and 'raw strings'
+            let start = index + 1;
+            index = start;
+            while index < chars.len() && chars[index] != c {
+                index += 1;
+            }
+            let literal: String = chars[start..index].iter().cloned().collect();
+            tokens.push(Token::Literal(literal.trim_matches('"').to_owned()));
+            index += 1;
+        } else {
+            let two: String = chars[index..::std::cmp::min(index + 2, chars.len())].iter().cloned().collect();
+            match &two[..] {
+                "[]" | "[?" | "&&" | "||" | "==" | "!=" | "<=" | ">=" => {
+                    tokens.push(Token::Operator(two.clone()));
+                    index += 2;
+                },
+                _ => {
+                    tokens.push(Token::Operator(c.to_string()));
+                    index += 1;
+                },
+            }
+        }
+    }
+
+    tokens
+}
+
+fn next_is(tokens: &[Token], position: usize, operator: &str) -> bool {
+    tokens.get(position) == Some(&Token::Operator(operator.to_owned()))
+}
+
+fn expect(tokens: &[Token], position: &mut usize, operator: &str) -> Result<(), String> {
+    if !next_is(tokens, *position, operator) {
+        return Err(format!("Expected '{}' in waiter expression, found {:?}", operator, tokens.get(*position)));
+    }
+    *position += 1;
+    Ok(())
+}
+
+fn parse_or(tokens: &[Token], position: &mut usize) -> Result<Expression, String> {
+    let mut expression = try!(parse_and(tokens, position));
+
+    while next_is(tokens, *position, "||") {
+        *position += 1;
+        expression = Expression::Or(Box::new(expression), Box::new(try!(parse_and(tokens, position))));
+    }
+
+    Ok(expression)
+}
+
+fn parse_and(tokens: &[Token], position: &mut usize) -> Result<Expression, String> {
+    let mut expression = try!(parse_not(tokens, position));
+
+    while next_is(tokens, *position, "&&") {
+        *position += 1;
+        expression = Expression::And(Box::new(expression), Box::new(try!(parse_not(tokens, position))));
+    }
+
+    Ok(expression)
+}
+
+fn parse_not(tokens: &[Token], position: &mut usize) -> Result<Expression, String> {
+    if next_is(tokens, *position, "!") {
+        *position += 1;
+        return Ok(Expression::Not(Box::new(try!(parse_not(tokens, position)))));
+    }
+
+    let left = try!(parse_primary(tokens, position));
+
+    match tokens.get(*position) {
+        Some(&Token::Operator(ref operator)) if ["==", "!=", "<", "<=", ">", ">="].contains(&&operator[..]) => {
+            *position += 1;
+            Ok(Expression::Comparison(Box::new(left), operator.clone(), Box::new(try!(parse_primary(tokens, position)))))
+        },
+        _ => Ok(left),
+    }
+}
+
+fn parse_primary(tokens: &[Token], position: &mut usize) -> Result<Expression, String> {
+    match tokens.get(*position).cloned() {
+        Some(Token::Literal(value)) => {
+            *position += 1;
+            Ok(Expression::Literal(value))
+        },
+        Some(Token::Operator(ref operator)) if operator == "(" => {
+            *position += 1;
+            let expression = try!(parse_or(tokens, position));
+            try!(expect(tokens, position, ")"));
+            Ok(expression)
+        },
+        Some(Token::Identifier(ref name)) if name == "length" && next_is(tokens, *position + 1, "(") => {
+            *position += 2;
+            let expression = try!(parse_or(tokens, position));
+            try!(expect(tokens, position, ")"));
+            Ok(Expression::Length(Box::new(expression)))
+        },
+        Some(Token::Identifier(_)) => Ok(Expression::Path(try!(parse_path(tokens, position)))),
+        token => Err(format!("Unexpected {:?} in waiter expression", token)),
+    }
+}
+
+fn parse_path(tokens: &[Token], position: &mut usize) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+
+    loop {
+        match tokens.get(*position).cloned() {
+            Some(Token::Identifier(name)) => {
+                *position += 1;
+                steps.push(Step::Field(name));
+            },
+            token => return Err(format!("Expected a member name in waiter expression, found {:?}", token)),
+        }
+
+        loop {
+            if next_is(tokens, *position, "[]") {
+                *position += 1;
+                steps.push(Step::Flatten);
+            } else if next_is(tokens, *position, "[?") {
+                *position += 1;
+                let filter = try!(parse_or(tokens, position));
+                try!(expect(tokens, position, "]"));
+                steps.push(Step::Filter(Box::new(filter)));
+            } else {
+                break;
+            }
+        }
+
+        if !next_is(tokens, *position, ".") {
+            return Ok(steps);
+        }
+        *position += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Expression, Step};
+
+    #[test]
+    fn parse_waiter_expressions() {
+        assert_eq!(parse("Reservations[].Instances[].State.Name"), Ok(Expression::Path(vec![
+            Step::Field("Reservations".to_owned()),
+            Step::Flatten,
+            Step::Field("Instances".to_owned()),
+            Step::Flatten,
+            Step::Field("State".to_owned()),
+            Step::Field("Name".to_owned()),
+        ])));
+
+        assert_eq!(parse("length(Output || '') > `0`"), Ok(Expression::Comparison(
+            Box::new(Expression::Length(Box::new(Expression::Or(
+                Box::new(Expression::Path(vec![Step::Field("Output".to_owned())])),
+                Box::new(Expression::Literal(String::new())),
+            )))),
+            ">".to_owned(),
+            Box::new(Expression::Literal("0".to_owned())),
+        )));
+
+        match parse("length(services[?!(length(deployments) == `1` && runningCount == desiredCount)]) == `0`").unwrap() {
+            Expression::Comparison(left, _, _) => match *left {
+                Expression::Length(ref path) => match **path {
+                    Expression::Path(ref steps) => match steps[1] {
+                        Step::Filter(ref filter) => match **filter {
+                            Expression::Not(ref inner) => match **inner {
+                                Expression::And(_, _) => {},
+                                ref other => panic!("Expected a conjunction, got {:?}", other),
+                            },
+                            ref other => panic!("Expected a negation, got {:?}", other),
+                        },
+                        ref other => panic!("Expected a filter, got {:?}", other),
+                    },
+                    ref other => panic!("Expected a path, got {:?}", other),
+                },
+                ref other => panic!("Expected a length, got {:?}", other),
+            },
+            other => panic!("Expected a comparison, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unsupported_waiter_expressions_are_errors() {
+        assert!(parse("services | [@[?length(deployments)!=`1`], @[?desiredCount!=runningCount]][] | length(@) == `0`").is_err());
+        assert!(parse("Table.TableStatus ==").is_err());
+    }
+}
@@ -0,0 +1,84 @@
+//! Binary data in request and response bodies.
+//!
+//! AWS sends blob members as base64 strings, so generated shapes hold them as a `Blob`, which
+//! derefs to its bytes and implements Serde's traits using base64. XML parsers and serializers
+//! and header bindings use `encode_base64` and `decode_base64`.
+
+use std::ops::{Deref, DerefMut};
+
+use rustc_serialize::base64::{FromBase64, FromBase64Error, ToBase64, STANDARD};
+use serde;
+
+/// Encodes bytes as base64, as blobs are sent.
+pub fn encode_base64(bytes: &[u8]) -> String {
+    bytes.to_base64(STANDARD)
+}
+
+/// Decodes a blob sent as base64.
+pub fn decode_base64(encoded: &str) -> Result<Vec<u8>, FromBase64Error> {
+    encoded.from_base64()
+}
+
+/// Bytes that are serialized as a base64 string.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Blob(pub Vec<u8>);
+
+impl Deref for Blob {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+
+impl DerefMut for Blob {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for Blob {
+    fn from(bytes: Vec<u8>) -> Blob {
+        Blob(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Blob {
+    fn from(bytes: &'a [u8]) -> Blob {
+        Blob(bytes.to_vec())
+    }
+}
+
+impl serde::Serialize for Blob {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+        serde::Serialize::serialize(&self.0.to_base64(STANDARD), serializer)
+    }
+}
+
+impl serde::Deserialize for Blob {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Blob, D::Error> where D: serde::Deserializer {
+        let encoded: String = try!(serde::Deserialize::deserialize(deserializer));
+        encoded.from_base64().map(Blob).map_err(|_| serde::de::Error::syntax("Invalid base64 blob"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::{Blob, decode_base64, encode_base64};
+
+    #[test]
+    fn blobs_are_base64_strings() {
+        let blob = Blob(b"hello".to_vec());
+        assert_eq!(serde_json::to_string(&blob).unwrap(), "\"aGVsbG8=\"");
+        assert_eq!(serde_json::from_str::<Blob>("\"aGVsbG8=\"").unwrap(), blob);
+        assert!(serde_json::from_str::<Blob>("\"not base64!\"").is_err());
+    }
+
+    #[test]
+    fn blobs_round_trip_through_base64() {
+        assert_eq!(encode_base64(b"hello"), "aGVsbG8=");
+        assert_eq!(decode_base64("aGVsbG8=").unwrap(), b"hello".to_vec());
+        assert!(decode_base64("not base64!").is_err());
+    }
+}
@@ -2,12 +2,15 @@
 //!
 //! ## Priority order
 //!
-//! 1. Environment variables: `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`
-//! 2. AWS credentials file.  Usually located at ~/.aws/credentials .
-//! 3. IAM instance profile.  Will only work if running on an EC2 instance with an instance profile/role.
+//! 1. Environment variables: `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and optionally `AWS_SESSION_TOKEN`
+//! 2. AWS credentials file.  Usually located at ~/.aws/credentials , or wherever `AWS_SHARED_CREDENTIALS_FILE` points.
+//!    The profile used is `AWS_PROFILE` if set, otherwise "default".
+//! 3. IAM instance profile.  Will only work if running on an EC2 instance with an instance profile/role,
+//!    or against the metadata service named by `AWS_EC2_METADATA_SERVICE_ENDPOINT`.
 
 use std::env::*;
 use std::env;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::path::Path;
@@ -15,16 +18,27 @@ use std::io::prelude::*;
 use std::io::BufReader;
 use std::ascii::AsciiExt;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
 use hyper::Client;
-use hyper::header::Connection;
+use hyper::header::{Connection, Headers};
+use hyper::method::Method;
 use error::*;
 use regex::Regex;
-use chrono::{Duration, UTC, DateTime};
+use chrono::{Duration, UTC, DateTime, TimeZone};
 use serde_json::{Value, from_str};
 use std::time::Duration as StdDuration;
 
+const METADATA_SERVICE_ENDPOINT: &'static str = "http://169.254.169.254";
+const METADATA_TOKEN_TTL_SECONDS: &'static str = "21600";
+const MIN_REFRESH_INTERVAL_SECONDS: i64 = 10;
+const MAX_METADATA_RETRY_DELAY_SECONDS: u64 = 30;
+const REDACTED: &'static str = "**********";
+
 /// Represents AWS credentials.  Includes access key, secret key, token (for IAM profiles) and expiration timestamp.
-#[derive(Clone, Debug)]
+///
+/// The `Debug` output redacts the secret key and token so they don't end up in logs.
+#[derive(Clone)]
 pub struct AWSCredentials {
     key: String,
     secret: String,
@@ -69,20 +83,71 @@ impl AWSCredentials {
     }
 }
 
+impl fmt::Debug for AWSCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AWSCredentials")
+            .field("key", &self.key)
+            .field("secret", &REDACTED)
+            .field("token", &self.token.as_ref().map(|_| REDACTED))
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
 pub trait AWSCredentialsProvider {
 	fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError>;
+
+	/// Forget any cached credentials so the next `get_credentials()` call fetches new ones.
+	fn invalidate_credentials(&mut self) {}
 }
 
 fn err(message: &str) -> Result<&AWSCredentials, AWSError> {
     Err(AWSError::new(message))
 }
 
+/// Provides a fixed set of credentials, e.g. ones loaded from your own configuration.
+///
+/// The credentials never expire unless created with an expiry through `from_credentials()`.
+#[derive(Clone, Debug)]
+pub struct StaticProvider {
+    credentials: AWSCredentials
+}
+
+impl StaticProvider {
+    pub fn new<K, S>(key: K, secret: S, token: Option<String>) -> StaticProvider where K: Into<String>, S: Into<String> {
+        StaticProvider { credentials: AWSCredentials::new(key, secret, token, never_expires()) }
+    }
+
+    /// Just an access key and secret key, no session token.
+    pub fn new_minimal<K, S>(key: K, secret: S) -> StaticProvider where K: Into<String>, S: Into<String> {
+        StaticProvider::new(key, secret, None)
+    }
+
+    /// Provides `credentials` as they are, including their expiry time.
+    pub fn from_credentials(credentials: AWSCredentials) -> StaticProvider {
+        StaticProvider { credentials: credentials }
+    }
+}
+
+impl AWSCredentialsProvider for StaticProvider {
+    fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
+        if self.credentials.credentials_are_expired() {
+            return err("Static credentials have expired.");
+        }
+        Ok(&self.credentials)
+    }
+}
+
 /// Looks for credentials from environment variables
 pub struct EnvironmentCredentialsProvider {
     credentials: Option<AWSCredentials>
 }
 
 impl AWSCredentialsProvider for EnvironmentCredentialsProvider {
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+    }
+
 	fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
         if self.credentials.is_none() || self.credentials.as_ref().unwrap().credentials_are_expired() {
            self.credentials = Some(try!(get_credentials_from_environment()));
@@ -92,10 +157,9 @@ impl AWSCredentialsProvider for EnvironmentCredentialsProvider {
 }
 
 impl EnvironmentCredentialsProvider {
-    fn new() -> EnvironmentCredentialsProvider {
+    pub fn new() -> EnvironmentCredentialsProvider {
         EnvironmentCredentialsProvider { credentials: None }
     }
-
 }
 
 fn get_credentials_from_environment<'a>() -> Result<AWSCredentials, AWSError> {
@@ -112,7 +176,14 @@ fn get_credentials_from_environment<'a>() -> Result<AWSCredentials, AWSError> {
         return Err(AWSError::new("Couldn't find either AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY or both in environment."));
     }
 
-    Ok(AWSCredentials::new(env_key, env_secret, None, in_ten_minutes()))
+    // temporary credentials (e.g. from STS) also need the session token.
+    // AWS_SECURITY_TOKEN is the older name still used by some tools.
+    let env_token = match var("AWS_SESSION_TOKEN").or(var("AWS_SECURITY_TOKEN")) {
+        Ok(ref val) if !val.is_empty() => Some(val.to_owned()),
+        _ => None,
+    };
+
+    Ok(AWSCredentials::new(env_key, env_secret, env_token, in_ten_minutes()))
 }
 
 /// Looks for AWS credentials in a profile in a credentials file.
@@ -123,17 +194,12 @@ pub struct ProfileCredentialsProvider {
 }
 
 impl ProfileCredentialsProvider {
-   pub fn new() -> AWSResult<ProfileCredentialsProvider> {
-        // Default credentials file location:
-        // ~/.aws/credentials (Linux/Mac)
-        // %USERPROFILE%\.aws\credentials  (Windows)
-        let profile_location;
-        match env::home_dir() {
-            Some(ref p) => profile_location = p.display().to_string() + "/.aws/credentials",
-            None => return Err(AWSError::new("Couldn't get your home dir.")),
-        }
+    /// Uses the profile named by `AWS_PROFILE` (or "default") from the file named by
+    /// `AWS_SHARED_CREDENTIALS_FILE` (or ~/.aws/credentials).
+    pub fn new() -> AWSResult<ProfileCredentialsProvider> {
+        let profile_location = try!(default_credentials_file_location());
 
-        Ok(ProfileCredentialsProvider { credentials: None, profile: "default".to_string(), file_name: profile_location })
+        Ok(ProfileCredentialsProvider { credentials: None, profile: default_profile_name(), file_name: profile_location })
     }
 
     pub fn with_configuration(profile: &str, file_name: &str) -> ProfileCredentialsProvider {
@@ -148,9 +214,17 @@ impl ProfileCredentialsProvider {
     pub fn get_profile(&self) -> &str {
         &self.profile
     }
+
+    pub fn get_file_name(&self) -> &str {
+        &self.file_name
+    }
 }
 
 impl AWSCredentialsProvider for ProfileCredentialsProvider {
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+    }
+
     fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
         if self.credentials.is_none() || self.credentials.as_ref().unwrap().credentials_are_expired() {
             match parse_credentials_file(&self.file_name) {
@@ -161,13 +235,41 @@ impl AWSCredentialsProvider for ProfileCredentialsProvider {
                     }
                     self.credentials = default_profile;
                 },
-                Err(_) => { return err("Parse error"); }
+                Err(why) => { return Err(why); }
             };
        }
        Ok(self.credentials.as_ref().unwrap())
    }
 }
 
+fn default_profile_name() -> String {
+    profile_name(var("AWS_PROFILE").ok())
+}
+
+// the profile named by `AWS_PROFILE`, which is ignored when it's empty
+fn profile_name(aws_profile: Option<String>) -> String {
+    match aws_profile {
+        Some(ref profile) if !profile.is_empty() => profile.to_owned(),
+        _ => "default".to_owned(),
+    }
+}
+
+fn default_credentials_file_location() -> AWSResult<String> {
+    if let Ok(file_name) = var("AWS_SHARED_CREDENTIALS_FILE") {
+        if !file_name.is_empty() {
+            return Ok(file_name);
+        }
+    }
+
+    // Default credentials file location:
+    // ~/.aws/credentials (Linux/Mac)
+    // %USERPROFILE%\.aws\credentials  (Windows)
+    match env::home_dir() {
+        Some(ref p) => Ok(p.display().to_string() + "/.aws/credentials"),
+        None => Err(AWSError::new("Couldn't get your home dir.")),
+    }
+}
+
 fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCredentials>, AWSError> {
     let path = Path::new(&file_with_path);
 
@@ -186,6 +288,7 @@ fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCre
     let mut profiles: HashMap<String, AWSCredentials> = HashMap::new();
     let mut access_key: Option<String> = None;
     let mut secret_key: Option<String> = None;
+    let mut token: Option<String> = None;
     let mut profile_name: Option<String> = None;
 
     let file_lines = BufReader::new(&file);
@@ -202,12 +305,13 @@ fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCre
         if profile_regex.is_match(&unwrapped_line) {
 
             if profile_name.is_some() && access_key.is_some() && secret_key.is_some() {
-                let creds = AWSCredentials::new(access_key.unwrap(), secret_key.unwrap(), None, in_ten_minutes());
+                let creds = AWSCredentials::new(access_key.unwrap(), secret_key.unwrap(), token, in_ten_minutes());
                 profiles.insert(profile_name.unwrap(), creds);
             }
 
             access_key = None;
             secret_key = None;
+            token = None;
 
             let caps = profile_regex.captures(&unwrapped_line).unwrap();
             profile_name = Some(caps.at(1).unwrap().to_string());
@@ -231,6 +335,14 @@ fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCre
                     secret_key = Some(v[1].trim_matches(' ').to_string());
                 }
             }
+        } else if lower_case_line.contains("aws_session_token") || lower_case_line.contains("aws_security_token") {
+            if token.is_none() {
+                // session tokens are base64 and may themselves contain '='
+                let v: Vec<&str> = unwrapped_line.splitn(2, "=").collect();
+                if v.len() > 1 {
+                    token = Some(v[1].trim_matches(' ').to_string());
+                }
+            }
         }
 
         // we could potentially explode here to indicate that the file is invalid
@@ -238,7 +350,7 @@ fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCre
     }
 
     if profile_name.is_some() && access_key.is_some() && secret_key.is_some() {
-        let creds = AWSCredentials::new(access_key.unwrap(), secret_key.unwrap(), None, in_ten_minutes());
+        let creds = AWSCredentials::new(access_key.unwrap(), secret_key.unwrap(), token, in_ten_minutes());
         profiles.insert(profile_name.unwrap(), creds);
     }
 
@@ -250,89 +362,323 @@ fn parse_credentials_file(file_with_path: &str) -> Result<HashMap<String, AWSCre
 }
 
 /// IAM profile source of AWS credentials
+///
+/// Uses the session oriented IMDSv2 flow, falling back to IMDSv1 if the metadata service
+/// won't hand out a session token.
 pub struct IAMRoleCredentialsProvider {
-    credentials: Option<AWSCredentials>
+    credentials: Option<AWSCredentials>,
+    endpoint: String,
+    timeout: StdDuration,
+    max_attempts: u32,
+    retry_delay: StdDuration,
 }
 
 impl IAMRoleCredentialsProvider {
-    fn new() -> IAMRoleCredentialsProvider {
-        IAMRoleCredentialsProvider { credentials: None }
+    /// Uses the EC2 metadata service, or the one named by `AWS_EC2_METADATA_SERVICE_ENDPOINT`.
+    pub fn new() -> IAMRoleCredentialsProvider {
+        let endpoint = match var("AWS_EC2_METADATA_SERVICE_ENDPOINT") {
+            Ok(ref endpoint) if !endpoint.is_empty() => endpoint.to_owned(),
+            _ => METADATA_SERVICE_ENDPOINT.to_owned(),
+        };
+
+        IAMRoleCredentialsProvider::with_endpoint(endpoint)
+    }
+
+    /// Uses the metadata service at `endpoint`, e.g. `http://localhost:8080` for local testing.
+    pub fn with_endpoint<S>(endpoint: S) -> IAMRoleCredentialsProvider where S: Into<String> {
+        IAMRoleCredentialsProvider {
+            credentials: None,
+            endpoint: endpoint.into().trim_right_matches('/').to_owned(),
+            timeout: StdDuration::from_secs(15),
+            max_attempts: 3,
+            retry_delay: StdDuration::from_millis(500),
+        }
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Read and write timeout for each request to the metadata service.
+    pub fn set_timeout(&mut self, timeout: StdDuration) {
+        self.timeout = timeout;
+    }
+
+    pub fn get_timeout(&self) -> StdDuration {
+        self.timeout
+    }
+
+    /// How many times to try fetching credentials before giving up.
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts;
+    }
+
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Delay before the first retry.  It doubles after every failed attempt, up to 30 seconds.
+    pub fn set_retry_delay(&mut self, retry_delay: StdDuration) {
+        self.retry_delay = retry_delay;
+    }
+
+    pub fn get_retry_delay(&self) -> StdDuration {
+        self.retry_delay
+    }
+
+    fn fetch_credentials(&self) -> AWSResult<AWSCredentials> {
+        let mut client = Client::new();
+        client.set_read_timeout(Some(self.timeout));
+        client.set_write_timeout(Some(self.timeout));
+
+        let token = get_metadata_session_token(&client, &self.endpoint);
+
+        let mut address = format!("{}/latest/meta-data/iam/security-credentials/", self.endpoint);
+        let role_list = try!(get_metadata(&client, &address, &token));
+
+        let role_name = match role_list.lines().next() {
+            Some(name) if !name.trim().is_empty() => name.trim().to_owned(),
+            _ => return Err(AWSError::new("Couldn't find an IAM role in metadata service response.")),
+        };
+
+        address.push_str(&role_name);
+        let body = try!(get_metadata(&client, &address, &token));
+
+        parse_instance_credentials(&body)
     }
 }
 
 impl AWSCredentialsProvider for IAMRoleCredentialsProvider {
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+    }
+
     fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
         if self.credentials.is_none() || self.credentials.as_ref().unwrap().credentials_are_expired() {
-            // TODO: backoff and retry on failure.
-
-            // for "real" use: http://169.254.169.254/latest/meta-data/iam/security-credentials/
-            let mut address : String = "http://169.254.169.254/latest/meta-data/iam/security-credentials".to_string();
-            let mut client = Client::new();
-            client.set_read_timeout(Some(StdDuration::from_secs(15)));
-            let mut response;
-            match client.get(&address)
-                .header(Connection::close()).send() {
-                    Err(_) => return err("Couldn't connect to metadata service"), // add Why?
-                    Ok(received_response) => response = received_response
-                };
+            let mut attempt = 0;
+
+            loop {
+                attempt += 1;
+
+                match self.fetch_credentials() {
+                    Ok(creds) => {
+                        self.credentials = Some(creds);
+                        break;
+                    },
+                    Err(why) => {
+                        if attempt >= self.max_attempts {
+                            return Err(why);
+                        }
+                        debug!("Attempt {} to get IAM role credentials failed: {}", attempt, why.0);
+                        thread::sleep(retry_delay(self.retry_delay, attempt));
+                    }
+                }
+            }
+        }
 
-            let mut body = String::new();
-            match response.read_to_string(&mut body) {
-                Err(_) => return err("Didn't get a parsable response body from metadata service"),
-                Ok(_) => (),
-            };
+		Ok(&self.credentials.as_ref().unwrap())
+	}
+}
 
-            address.push_str("/");
-            address.push_str(&body);
-            body = String::new();
-            match client.get(&address)
-                .header(Connection::close()).send() {
-                    Err(_) => return err("Didn't get a parseable response body from instance role details"),
-                    Ok(received_response) => response = received_response
-                };
+/// How long to wait after failed attempt number `attempt`, doubling `first_delay` each time.
+fn retry_delay(first_delay: StdDuration, attempt: u32) -> StdDuration {
+    let max_delay = StdDuration::from_secs(MAX_METADATA_RETRY_DELAY_SECONDS);
 
-            match response.read_to_string(&mut body) {
-                Err(_) => return err("Had issues with reading iam role response: {}"),
-                Ok(_) => (),
-            };
+    match 2u32.checked_pow(attempt - 1).and_then(|factor| first_delay.checked_mul(factor)) {
+        Some(delay) if delay < max_delay => delay,
+        _ => max_delay,
+    }
+}
 
-            let json_object: Value;
-            match from_str(&body) {
-                Err(_) => return err("Couldn't parse metadata response body."),
-                Ok(val) => json_object = val
-            };
+/// Asks the metadata service for an IMDSv2 session token.
+/// Returns None if the service doesn't support (or has disabled) IMDSv2.
+fn get_metadata_session_token(client: &Client, endpoint: &str) -> Option<String> {
+    let mut headers = Headers::new();
+    headers.set(Connection::close());
+    headers.set_raw("X-aws-ec2-metadata-token-ttl-seconds", vec![METADATA_TOKEN_TTL_SECONDS.as_bytes().to_vec()]);
+
+    let address = format!("{}/latest/api/token", endpoint);
+    let mut response = match client.request(Method::Put, &address).headers(headers).body("").send() {
+        Ok(response) => response,
+        Err(_) => return None,
+    };
 
-            let access_key;
-            match json_object.find("AccessKeyId") {
-                None => return err("Couldn't find AccessKeyId in response."),
-                Some(val) => access_key = val.as_string().expect("AccessKeyId value was not a string").to_owned().replace("\"", "")
-            };
+    if !response.status.is_success() {
+        debug!("Metadata service returned {} for session token, falling back to IMDSv1", response.status);
+        return None;
+    }
 
-            let secret_key;
-            match json_object.find("SecretAccessKey") {
-                None => return err("Couldn't find SecretAccessKey in response."),
-                Some(val) => secret_key = val.as_string().expect("SecretAccessKey value was not a string").to_owned().replace("\"", "")
-            };
+    let mut token = String::new();
+    match response.read_to_string(&mut token) {
+        Ok(_) if !token.trim().is_empty() => Some(token.trim().to_owned()),
+        _ => None,
+    }
+}
 
-            let expiration;
-            match json_object.find("Expiration") {
-                None => return err("Couldn't find Expiration in response."),
-                Some(val) => expiration = val.as_string().expect("Expiration value was not a string").to_owned().replace("\"", "")
-            };
+fn get_metadata(client: &Client, address: &str, token: &Option<String>) -> AWSResult<String> {
+    let mut headers = Headers::new();
+    headers.set(Connection::close());
+    if let Some(ref token) = *token {
+        headers.set_raw("X-aws-ec2-metadata-token", vec![token.as_bytes().to_vec()]);
+    }
 
-            let expiration_time = try!(expiration.parse());
+    let mut response = match client.get(address).headers(headers).send() {
+        Ok(response) => response,
+        Err(why) => return Err(AWSError::new(format!("Couldn't connect to metadata service: {}", why))),
+    };
 
-            let token_from_response;
-            match json_object.find("Token") {
-                None => return err("Couldn't find Token in response."),
-                Some(val) => token_from_response = val.as_string().expect("Token value was not a string").to_owned().replace("\"", "")
-            };
+    if !response.status.is_success() {
+        return Err(AWSError::new(format!("Metadata service returned {} for {}", response.status, address)));
+    }
 
-            self.credentials = Some(AWSCredentials::new(access_key, secret_key, Some(token_from_response), expiration_time));
+    let mut body = String::new();
+    match response.read_to_string(&mut body) {
+        Err(_) => return Err(AWSError::new("Didn't get a parsable response body from metadata service")),
+        Ok(_) => (),
+    };
+
+    Ok(body)
+}
+
+fn parse_instance_credentials(body: &str) -> AWSResult<AWSCredentials> {
+    let json_object: Value;
+    match from_str(body) {
+        Err(_) => return Err(AWSError::new("Couldn't parse metadata response body.")),
+        Ok(val) => json_object = val
+    };
+
+    // the metadata service reports failures to assume the role in the body, not the status
+    let code = try!(string_from_metadata(&json_object, "Code"));
+    if code != "Success" {
+        return Err(AWSError::new(format!("Metadata service returned Code {} for instance role credentials.", code)));
+    }
+
+    let access_key = try!(string_from_metadata(&json_object, "AccessKeyId"));
+    let secret_key = try!(string_from_metadata(&json_object, "SecretAccessKey"));
+    let expiration = try!(string_from_metadata(&json_object, "Expiration"));
+    let token_from_response = try!(string_from_metadata(&json_object, "Token"));
+
+    let expiration_time = try!(expiration.parse());
+
+    Ok(AWSCredentials::new(access_key, secret_key, Some(token_from_response), expiration_time))
+}
+
+fn string_from_metadata(json_object: &Value, field: &str) -> AWSResult<String> {
+    match json_object.find(field) {
+        None => Err(AWSError::new(format!("Couldn't find {} in response.", field))),
+        Some(val) => match val.as_string() {
+            Some(string) => Ok(string.replace("\"", "")),
+            None => Err(AWSError::new(format!("{} value was not a string", field))),
         }
+    }
+}
 
-		Ok(&self.credentials.as_ref().unwrap())
-	}
+/// Tries each of its providers in order until one of them comes up with credentials.
+///
+/// If none of them do, the error names every provider that was tried and why it failed.
+///
+/// ```no_run
+/// use rusoto::credentials::{ChainProvider, EnvironmentCredentialsProvider, IAMRoleCredentialsProvider};
+///
+/// let chain = ChainProvider::new()
+///     .with_provider("environment", EnvironmentCredentialsProvider::new())
+///     .with_provider("IAM role", IAMRoleCredentialsProvider::new());
+/// ```
+pub struct ChainProvider {
+    providers: Vec<(String, Box<AWSCredentialsProvider + Send>)>,
+    failures: Vec<(String, AWSError)>,
+    credentials: Option<AWSCredentials>,
+}
+
+impl ChainProvider {
+    pub fn new() -> ChainProvider {
+        ChainProvider {
+            providers: Vec::new(),
+            failures: Vec::new(),
+            credentials: None,
+        }
+    }
+
+    /// Chains `providers` in the order given.  Failures are reported by position in the list.
+    pub fn with_providers(providers: Vec<Box<AWSCredentialsProvider + Send>>) -> ChainProvider {
+        let mut chain = ChainProvider::new();
+        for (index, provider) in providers.into_iter().enumerate() {
+            chain.providers.push((format!("provider {}", index + 1), provider));
+        }
+        chain
+    }
+
+    /// Appends `provider` to the chain.  `name` is used when reporting why it failed.
+    pub fn with_provider<S, P>(mut self, name: S, provider: P) -> ChainProvider
+        where S: Into<String>, P: AWSCredentialsProvider + Send + 'static {
+        self.add_provider(name, provider);
+        self
+    }
+
+    pub fn add_provider<S, P>(&mut self, name: S, provider: P)
+        where S: Into<String>, P: AWSCredentialsProvider + Send + 'static {
+        self.providers.push((name.into(), Box::new(provider)));
+    }
+
+    /// Each provider that failed during the last refresh of credentials, and why.
+    pub fn get_failures(&self) -> &[(String, AWSError)] {
+        &self.failures
+    }
+}
+
+impl AWSCredentialsProvider for ChainProvider {
+    fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
+        if self.credentials.is_none() || self.credentials.as_ref().unwrap().credentials_are_expired() {
+            self.credentials = None;
+            self.failures.clear();
+
+            for &mut (ref name, ref mut provider) in self.providers.iter_mut() {
+                match provider.get_credentials() {
+                    Ok(creds) => {
+                        self.credentials = Some(creds.clone());
+                        break;
+                    },
+                    Err(why) => self.failures.push((name.clone(), why)),
+                }
+            }
+
+            if self.credentials.is_none() {
+                return Err(AWSError::new(chain_failure_message(&self.failures)));
+            }
+        }
+
+        Ok(self.credentials.as_ref().unwrap())
+    }
+
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+        for &mut (_, ref mut provider) in self.providers.iter_mut() {
+            provider.invalidate_credentials();
+        }
+    }
+}
+
+/// Stands in for a provider that couldn't be set up, so that a chain reports why.
+struct UnavailableProvider {
+    why: AWSError,
+}
+
+impl AWSCredentialsProvider for UnavailableProvider {
+    fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
+        Err(AWSError::new(self.why.0.clone()))
+    }
+}
+
+fn chain_failure_message(failures: &[(String, AWSError)]) -> String {
+    if failures.is_empty() {
+        return "No credentials providers to try.".to_owned();
+    }
+
+    let reasons: Vec<String> = failures.iter()
+        .map(|&(ref name, ref why)| format!("{}: {}", name, why.0))
+        .collect();
+
+    format!("Couldn't find AWS credentials. Tried {}", reasons.join("; "))
 }
 
 /// Internally chains AWS credential providers in priority order.
@@ -345,36 +691,35 @@ pub struct DefaultAWSCredentialsProviderChain {
 
 // Chain the providers:
 impl AWSCredentialsProvider for DefaultAWSCredentialsProviderChain {
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+    }
+
     fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
         if self.credentials.is_none() || self.credentials.as_ref().unwrap().credentials_are_expired() {
             // fetch creds in order: env, file, IAM
-
-            if let Ok(creds) = EnvironmentCredentialsProvider::new().get_credentials() {
-                self.credentials = Some(creds.clone());
-
-                return Ok(self.credentials.as_ref().unwrap());
-            }
+            let mut chain = ChainProvider::new().with_provider("environment", EnvironmentCredentialsProvider::new());
 
             let profile_credentials_provider_result = match self.file_path {
                 Some(ref file_path) => Ok(ProfileCredentialsProvider::with_configuration(&self.profile, file_path)),
                 None => ProfileCredentialsProvider::new(),
             };
 
-            if let Ok(mut provider) = profile_credentials_provider_result {
-                if let Ok(creds) =  provider.with_profile(&self.profile).get_credentials() {
-                    self.credentials = Some(creds.clone());
-
-                    return Ok(self.credentials.as_ref().unwrap());
-                }
+            match profile_credentials_provider_result {
+                Ok(mut provider) => {
+                    provider.with_profile(&self.profile);
+                    let name = format!("profile {} in {}", provider.get_profile(), provider.get_file_name());
+                    chain.add_provider(name, provider);
+                },
+                Err(why) => chain.add_provider("ProfileCredentialsProvider", UnavailableProvider { why: why }),
             }
 
-            if let Ok(creds) = IAMRoleCredentialsProvider::new().get_credentials() {
-                self.credentials = Some(creds.clone());
-
-                return Ok(self.credentials.as_ref().unwrap());
-            }
+            // off EC2 the metadata service never answers, so don't keep everyone else waiting on retries
+            let mut iam_role_provider = IAMRoleCredentialsProvider::new();
+            iam_role_provider.set_max_attempts(1);
+            chain.add_provider("IAM role", iam_role_provider);
 
-            return Err(AWSError::new("Couldn't find AWS credentials in environment, default credential file location or IAM role."));
+            self.credentials = Some(try!(chain.get_credentials()).clone());
         }
 
         Ok(self.credentials.as_ref().unwrap())
@@ -382,11 +727,12 @@ impl AWSCredentialsProvider for DefaultAWSCredentialsProviderChain {
 }
 
 impl DefaultAWSCredentialsProviderChain {
+    /// Honours `AWS_PROFILE` and `AWS_SHARED_CREDENTIALS_FILE` when looking up the profile.
     pub fn new() -> DefaultAWSCredentialsProviderChain {
         DefaultAWSCredentialsProviderChain {
             credentials: None,
             file_path: None,
-            profile: "default".to_owned(),
+            profile: default_profile_name(),
         }
     }
 
@@ -418,14 +764,452 @@ impl DefaultAWSCredentialsProviderChain {
     }
 }
 
+/// Caches credentials from another provider so they can be shared between clients and threads.
+///
+/// Clones are cheap and share one cache, so the wrapped provider is only asked for credentials
+/// once no matter how many clients use them.  Credentials are refreshed `refresh_window` before
+/// they expire, on demand or by a background thread.
+///
+/// ```no_run
+/// use rusoto::credentials::{AutoRefreshingProvider, DefaultAWSCredentialsProviderChain};
+///
+/// let provider = AutoRefreshingProvider::new(DefaultAWSCredentialsProviderChain::new());
+/// // hand provider.clone() to each client
+/// ```
+pub struct AutoRefreshingProvider<P> {
+    shared: Arc<AutoRefreshingState<P>>,
+    credentials: Option<AWSCredentials>,
+}
+
+struct AutoRefreshingState<P> {
+    refresh_window: Duration,
+    credentials: RwLock<Option<AWSCredentials>>,
+    provider: Mutex<RefreshingProvider<P>>,
+}
+
+struct RefreshingProvider<P> {
+    provider: P,
+    last_attempt: Option<DateTime<UTC>>,
+}
+
+impl<P: AWSCredentialsProvider + Send + 'static> AutoRefreshingProvider<P> {
+    /// Refreshes credentials five minutes before they expire.
+    pub fn new(provider: P) -> AutoRefreshingProvider<P> {
+        AutoRefreshingProvider::with_refresh_window(provider, Duration::minutes(5))
+    }
+
+    pub fn with_refresh_window(provider: P, refresh_window: Duration) -> AutoRefreshingProvider<P> {
+        AutoRefreshingProvider {
+            shared: Arc::new(AutoRefreshingState {
+                refresh_window: refresh_window,
+                credentials: RwLock::new(None),
+                provider: Mutex::new(RefreshingProvider { provider: provider, last_attempt: None }),
+            }),
+            credentials: None,
+        }
+    }
+
+    /// Also checks every `check_interval` on a background thread whether the credentials are due
+    /// for a refresh, so requests don't have to wait for it.  The thread exits once every clone
+    /// of the provider has been dropped.
+    pub fn with_background_refresh(provider: P, refresh_window: Duration, check_interval: StdDuration) -> AutoRefreshingProvider<P> {
+        let auto_refreshing = AutoRefreshingProvider::with_refresh_window(provider, refresh_window);
+        let state = Arc::downgrade(&auto_refreshing.shared);
+
+        thread::spawn(move || {
+            loop {
+                thread::sleep(check_interval);
+
+                match state.upgrade() {
+                    Some(state) => {
+                        if let Err(why) = state.credentials() {
+                            warn!("Background credentials refresh failed: {}", why.0);
+                        }
+                    },
+                    None => break,
+                }
+            }
+        });
+
+        auto_refreshing
+    }
+
+    pub fn get_refresh_window(&self) -> Duration {
+        self.shared.refresh_window
+    }
+
+    /// Returns the cached credentials, refreshing them first if needed.
+    /// Unlike `get_credentials()` this only needs `&self`, so can be called from any thread.
+    pub fn credentials(&self) -> AWSResult<AWSCredentials> {
+        self.shared.credentials()
+    }
+}
+
+impl<P> Clone for AutoRefreshingProvider<P> {
+    fn clone(&self) -> AutoRefreshingProvider<P> {
+        AutoRefreshingProvider { shared: self.shared.clone(), credentials: self.credentials.clone() }
+    }
+}
+
+impl<P: AWSCredentialsProvider + Send + 'static> AWSCredentialsProvider for AutoRefreshingProvider<P> {
+    fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
+        self.credentials = Some(try!(self.shared.credentials()));
+        Ok(self.credentials.as_ref().unwrap())
+    }
+
+    fn invalidate_credentials(&mut self) {
+        self.credentials = None;
+        *self.shared.credentials.write().unwrap() = None;
+    }
+}
+
+impl<P: AWSCredentialsProvider> AutoRefreshingState<P> {
+    fn credentials(&self) -> AWSResult<AWSCredentials> {
+        if let Some(ref creds) = *self.credentials.read().unwrap() {
+            if !self.in_refresh_window(creds) {
+                return Ok(creds.clone());
+            }
+        }
+
+        self.refresh()
+    }
+
+    fn in_refresh_window(&self, creds: &AWSCredentials) -> bool {
+        *creds.get_expires_at() < UTC::now() + self.refresh_window
+    }
+
+    fn refresh(&self) -> AWSResult<AWSCredentials> {
+        let mut refreshing = self.provider.lock().unwrap();
+        let cached = self.credentials.read().unwrap().clone();
+
+        if let Some(ref creds) = cached {
+            // Another thread may have refreshed them while we waited for the lock, or the provider
+            // may keep handing back the same credentials until they're rotated.  Don't hammer it.
+            let recently_attempted = match refreshing.last_attempt {
+                Some(ref last_attempt) => last_attempt.clone() + Duration::seconds(MIN_REFRESH_INTERVAL_SECONDS) > UTC::now(),
+                None => false,
+            };
+
+            if !self.in_refresh_window(creds) || (recently_attempted && !creds.credentials_are_expired()) {
+                return Ok(creds.clone());
+            }
+        }
+
+        refreshing.last_attempt = Some(UTC::now());
+        refreshing.provider.invalidate_credentials();
+
+        match refreshing.provider.get_credentials() {
+            Ok(creds) => {
+                *self.credentials.write().unwrap() = Some(creds.clone());
+                Ok(creds.clone())
+            },
+            Err(why) => {
+                // keep using credentials that haven't expired yet and try again later
+                match cached {
+                    Some(ref creds) if !creds.credentials_are_expired() => Ok(creds.clone()),
+                    _ => Err(why),
+                }
+            }
+        }
+    }
+}
+
 fn in_ten_minutes() -> DateTime<UTC> {
     UTC::now() + Duration::seconds(600)
 }
 
+fn never_expires() -> DateTime<UTC> {
+    UTC.ymd(9999, 12, 31).and_hms(23, 59, 59)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use error::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration as StdDuration;
+    use chrono::{DateTime, Duration, UTC};
+
+    const INSTANCE_CREDENTIALS: &'static str = r#"{
+        "Code": "Success",
+        "Type": "AWS-HMAC",
+        "AccessKeyId": "iam_access_key",
+        "SecretAccessKey": "iam_secret_key",
+        "Token": "iam_token",
+        "Expiration": "2050-01-01T00:00:00Z"
+    }"#;
+
+    /// Serves each request to a local stand-in for the EC2 metadata service from `handler`,
+    /// which gets the method, path and IMDSv2 token header and returns a status code and body.
+    fn metadata_stand_in<F>(handler: F) -> String
+        where F: Fn(&str, &str, Option<&str>) -> (u16, String) + Send + 'static {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                    match stream.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request).into_owned();
+                let mut request_line = request.lines().next().unwrap_or("").split(' ');
+                let method = request_line.next().unwrap_or("");
+                let path = request_line.next().unwrap_or("");
+                let token = request.lines()
+                    .find(|line| line.to_lowercase().starts_with("x-aws-ec2-metadata-token:"))
+                    .map(|line| line[25..].trim());
+
+                let (status, body) = handler(method, path, token);
+                let _ = write!(stream, "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body);
+            }
+        });
+
+        endpoint
+    }
+
+    #[test]
+    fn iam_role_provider_uses_session_token() {
+        let endpoint = metadata_stand_in(|method, path, token| {
+            match (method, path, token) {
+                ("PUT", "/latest/api/token", _) => (200, "session_token".to_owned()),
+                ("GET", "/latest/meta-data/iam/security-credentials/", Some("session_token")) => (200, "test_role\n".to_owned()),
+                ("GET", "/latest/meta-data/iam/security-credentials/test_role", Some("session_token")) => (200, INSTANCE_CREDENTIALS.to_owned()),
+                _ => (401, String::new()),
+            }
+        });
+
+        let mut provider = IAMRoleCredentialsProvider::with_endpoint(endpoint);
+        let creds = provider.get_credentials().expect("Failed to get credentials from metadata stand-in");
+
+        assert_eq!(creds.get_aws_access_key_id(), "iam_access_key");
+        assert_eq!(creds.get_aws_secret_key(), "iam_secret_key");
+        assert_eq!(creds.get_token(), &Some("iam_token".to_string()));
+    }
+
+    #[test]
+    fn iam_role_provider_falls_back_without_session_token() {
+        let endpoint = metadata_stand_in(|method, path, token| {
+            match (method, path, token) {
+                ("GET", "/latest/meta-data/iam/security-credentials/", None) => (200, "test_role".to_owned()),
+                ("GET", "/latest/meta-data/iam/security-credentials/test_role", None) => (200, INSTANCE_CREDENTIALS.to_owned()),
+                _ => (405, String::new()),
+            }
+        });
+
+        let mut provider = IAMRoleCredentialsProvider::with_endpoint(endpoint);
+        let creds = provider.get_credentials().expect("Failed to get credentials from metadata stand-in");
+
+        assert_eq!(creds.get_aws_access_key_id(), "iam_access_key");
+    }
+
+    #[test]
+    fn iam_role_provider_checks_response_code() {
+        let endpoint = metadata_stand_in(|_, path, _| {
+            match path {
+                "/latest/meta-data/iam/security-credentials/" => (200, "test_role".to_owned()),
+                "/latest/meta-data/iam/security-credentials/test_role" => (200, INSTANCE_CREDENTIALS.replace("Success", "AssumeRoleUnauthorizedAccess")),
+                _ => (404, String::new()),
+            }
+        });
+
+        let mut provider = IAMRoleCredentialsProvider::with_endpoint(endpoint);
+        provider.set_max_attempts(1);
+
+        assert_eq!(
+            provider.get_credentials().err(),
+            Some(AWSError::new("Metadata service returned Code AssumeRoleUnauthorizedAccess for instance role credentials."))
+        );
+    }
+
+    #[test]
+    fn iam_role_provider_retries() {
+        // each attempt asks for a session token and then the role list, so count the latter
+        let role_list_requests = Arc::new(AtomicUsize::new(0));
+        let counter = role_list_requests.clone();
+        let endpoint = metadata_stand_in(move |_, path, _| {
+            if path == "/latest/meta-data/iam/security-credentials/" {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            (500, String::new())
+        });
+
+        let mut provider = IAMRoleCredentialsProvider::with_endpoint(endpoint);
+        provider.set_max_attempts(3);
+        provider.set_retry_delay(StdDuration::from_millis(1));
+
+        assert!(provider.get_credentials().is_err());
+        assert_eq!(role_list_requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn iam_role_provider_retry_delay_is_capped() {
+        let first_delay = StdDuration::from_millis(500);
+
+        assert_eq!(retry_delay(first_delay, 1), first_delay);
+        assert_eq!(retry_delay(first_delay, 3), StdDuration::from_secs(2));
+        assert_eq!(retry_delay(first_delay, 10), StdDuration::from_secs(30));
+        assert_eq!(retry_delay(first_delay, 40), StdDuration::from_secs(30));
+        assert_eq!(retry_delay(StdDuration::from_secs(u64::max_value()), 2), StdDuration::from_secs(30));
+    }
+
+    /// Hands out credentials expiring at each of `expirations` in turn, counting the fetches.
+    struct CountingProvider {
+        expirations: Vec<DateTime<UTC>>,
+        fetches: Arc<AtomicUsize>,
+        credentials: Option<AWSCredentials>,
+    }
+
+    impl AWSCredentialsProvider for CountingProvider {
+        fn get_credentials(&mut self) -> Result<&AWSCredentials, AWSError> {
+            if self.credentials.is_none() {
+                let fetch = self.fetches.fetch_add(1, Ordering::SeqCst);
+                let expires_at = self.expirations[fetch].clone();
+                self.credentials = Some(AWSCredentials::new("counting_key", format!("secret_{}", fetch), None, expires_at));
+            }
+            Ok(self.credentials.as_ref().unwrap())
+        }
+
+        fn invalidate_credentials(&mut self) {
+            self.credentials = None;
+        }
+    }
+
+    fn counting_provider(expirations: Vec<DateTime<UTC>>) -> (CountingProvider, Arc<AtomicUsize>) {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        (CountingProvider { expirations: expirations, fetches: fetches.clone(), credentials: None }, fetches)
+    }
+
+    #[test]
+    fn auto_refreshing_provider_shares_credentials() {
+        let (provider, fetches) = counting_provider(vec![UTC::now() + Duration::hours(1)]);
+        let auto_refreshing = AutoRefreshingProvider::new(provider);
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let mut clone = auto_refreshing.clone();
+            thread::spawn(move || {
+                clone.get_credentials().unwrap().get_aws_secret_key().to_owned()
+            })
+        }).collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "secret_0");
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn auto_refreshing_provider_replaces_expired_credentials() {
+        let (provider, fetches) = counting_provider(vec![
+            UTC::now() - Duration::seconds(1),
+            UTC::now() + Duration::hours(1),
+        ]);
+        let auto_refreshing = AutoRefreshingProvider::with_refresh_window(provider, Duration::minutes(5));
+
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_0");
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_1");
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_1");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn auto_refreshing_provider_refreshes_before_expiry() {
+        let (provider, fetches) = counting_provider(vec![
+            UTC::now() + Duration::minutes(1),
+            UTC::now() + Duration::hours(1),
+        ]);
+        let auto_refreshing = AutoRefreshingProvider::with_refresh_window(provider, Duration::minutes(5));
+
+        // still valid, and the provider was asked less than MIN_REFRESH_INTERVAL_SECONDS ago
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_0");
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_0");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        auto_refreshing.shared.provider.lock().unwrap().last_attempt =
+            Some(UTC::now() - Duration::seconds(MIN_REFRESH_INTERVAL_SECONDS));
+
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_1");
+        assert_eq!(auto_refreshing.credentials().unwrap().get_aws_secret_key(), "secret_1");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn static_provider_never_expires() {
+        let mut provider = StaticProvider::new("static_key", "static_secret", Some("static_token".to_owned()));
+        let creds = provider.get_credentials().unwrap();
+
+        assert_eq!(creds.get_aws_access_key_id(), "static_key");
+        assert_eq!(creds.get_aws_secret_key(), "static_secret");
+        assert_eq!(creds.get_token(), &Some("static_token".to_string()));
+        assert!(*creds.get_expires_at() > UTC::now() + Duration::weeks(52 * 1000));
+    }
+
+    #[test]
+    fn static_provider_expired_credentials() {
+        let expired = AWSCredentials::new("static_key", "static_secret", None, UTC::now() - Duration::seconds(1));
+        let mut provider = StaticProvider::from_credentials(expired);
+
+        assert_eq!(provider.get_credentials().err(), Some(AWSError::new("Static credentials have expired.")));
+    }
+
+    #[test]
+    fn credentials_debug_redacts_secrets() {
+        let creds = AWSCredentials::new("debug_key", "debug_secret", Some("debug_token".to_owned()), UTC::now());
+        let debug_output = format!("{:?}", creds);
+
+        assert!(debug_output.contains("debug_key"));
+        assert!(!debug_output.contains("debug_secret"));
+        assert!(!debug_output.contains("debug_token"));
+    }
+
+    #[test]
+    fn chain_provider_falls_through_to_later_providers() {
+        let expired = AWSCredentials::new("expired_key", "expired_secret", None, UTC::now() - Duration::seconds(1));
+        let mut chain = ChainProvider::new()
+            .with_provider("expired", StaticProvider::from_credentials(expired))
+            .with_provider("static", StaticProvider::new_minimal("static_key", "static_secret"));
+
+        assert_eq!(chain.get_credentials().unwrap().get_aws_access_key_id(), "static_key");
+        assert_eq!(chain.get_failures().len(), 1);
+        assert_eq!(chain.get_failures()[0].0, "expired");
+    }
+
+    #[test]
+    fn chain_provider_explains_failures() {
+        let mut chain = ChainProvider::with_providers(vec![
+            Box::new(ProfileCredentialsProvider::with_configuration("not_a_profile", "tests/sample-data/multiple_profile_credentials")) as Box<AWSCredentialsProvider + Send>,
+            Box::new(ProfileCredentialsProvider::with_configuration("default", "/bad/file/path")),
+        ]);
+
+        assert_eq!(
+            chain.get_credentials().err(),
+            Some(AWSError::new("Couldn't find AWS credentials. Tried provider 1: profile not found; provider 2: Couldn't stat credentials file."))
+        );
+    }
+
+    #[test]
+    fn chain_provider_explains_unavailable_providers() {
+        let mut chain = ChainProvider::new()
+            .with_provider("ProfileCredentialsProvider", UnavailableProvider { why: AWSError::new("Couldn't get your home dir.") });
+
+        assert!(chain.get_credentials().is_err());
+        assert_eq!(chain.get_failures(), &[("ProfileCredentialsProvider".to_owned(), AWSError::new("Couldn't get your home dir."))]);
+    }
 
     #[test]
     fn parse_credentials_file_default_profile() {
@@ -458,6 +1242,23 @@ mod tests {
 
     }
 
+    #[test]
+    fn parse_credentials_file_session_token() {
+        let result = super::parse_credentials_file("tests/sample-data/session_token_credentials");
+        assert!(result.is_ok());
+
+        let profiles = result.ok().unwrap();
+        assert_eq!(profiles.len(), 2);
+
+        let temporary_profile = profiles.get("temporary").unwrap();
+        assert_eq!(temporary_profile.get_aws_access_key_id(), "temporary_access_key");
+        assert_eq!(temporary_profile.get_aws_secret_key(), "temporary_secret_key");
+        assert_eq!(temporary_profile.get_token(), &Some("temporary/session+token==".to_string()));
+
+        let permanent_profile = profiles.get("permanent").unwrap();
+        assert_eq!(permanent_profile.get_token(), &None);
+    }
+
     #[test]
     fn profile_credentials_provider_happy_path() {
         let mut provider = ProfileCredentialsProvider::with_configuration("foo","tests/sample-data/multiple_profile_credentials");
@@ -479,17 +1280,24 @@ mod tests {
         assert_eq!(result.err(), Some(AWSError("profile not found".to_string())));
     }
 
+    #[test]
+    fn profile_name_from_aws_profile() {
+        assert_eq!(profile_name(None), "default");
+        assert_eq!(profile_name(Some("".to_owned())), "default");
+        assert_eq!(profile_name(Some("foo".to_owned())), "foo");
+    }
+
     #[test]
     fn profile_credentials_provider_profile_name() {
        let mut provider = ProfileCredentialsProvider::new().unwrap();
-       assert_eq!("default", provider.get_profile());
+       assert_eq!(default_profile_name(), provider.get_profile());
        assert_eq!("foo", provider.with_profile("foo").get_profile());
     }
 
     #[test]
     fn credential_chain_profile_name() {
        let mut chain = DefaultAWSCredentialsProviderChain::new();
-       assert_eq!("default", chain.get_profile());
+       assert_eq!(default_profile_name(), chain.get_profile());
        chain.set_profile("foo");
        assert_eq!("foo", chain.get_profile());
     }
@@ -1,5 +1,22 @@
 //! The AWS DynamoDB API.
 
-#![allow(non_snake_case)]
-
+#[cfg(feature = "regenerate")]
 include!(concat!(env!("OUT_DIR"), "/dynamodb.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/dynamodb.rs");
+
+#[cfg(test)]
+mod tests {
+	use error::AWSError;
+	use super::DescribeTableInput;
+
+	#[test]
+	fn validate_checks_patterns() {
+		assert_eq!(DescribeTableInput::new("my-table").validate(), Ok(()));
+		assert_eq!(
+			DescribeTableInput::new("my table").validate(),
+			Err(AWSError::new("DescribeTableInput.TableName must match ^(?:[a-zA-Z0-9_.-]+)$"))
+		);
+		assert!(DescribeTableInput::new("my-other-table").validate().is_ok());
+	}
+}
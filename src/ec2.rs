@@ -0,0 +1,87 @@
+//! The AWS EC2 API.
+
+#![allow(unused_variables, unused_mut)]
+
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::EventReader;
+use xml::reader::ParserConfig;
+
+use credentials::AWSCredentialsProvider;
+use error::AWSError;
+use params::{Params, SQSParams};
+use signature::SignedRequest;
+use xmlutil::{Next, Peek, XmlParseError, XmlResponseFromAws};
+use xmlutil::{at_end_element, characters, end_element, peek_at_name, skip_tree, start_element};
+
+// include the code generated from the EC2 botocore templates
+#[cfg(feature = "regenerate")]
+include!(concat!(env!("OUT_DIR"), "/ec2.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/ec2.rs");
+
+#[cfg(test)]
+mod tests {
+	use std::io::BufReader;
+	use std::fs::File;
+	use serde_json;
+	use xml::reader::*;
+	use xmlutil::*;
+	use super::{DescribeInstancesResult, DescribeInstancesResultParser, StringParser};
+
+	fn parse_sample(name: &str) -> Result<DescribeInstancesResult, XmlParseError> {
+		let file = File::open(format!("tests/sample-data/{}", name)).unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser = EventReader::with_config(file, ParserConfig::new().trim_whitespace(true));
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		DescribeInstancesResultParser::parse_xml("DescribeInstancesResponse", &mut reader)
+	}
+
+	fn parse_describe_instances() -> DescribeInstancesResult {
+		parse_sample("ec2_describe_instances.xml").unwrap()
+	}
+
+	#[test]
+	fn describe_instances_happy_path() {
+		let result = parse_describe_instances();
+
+		let reservations = result.reservations.unwrap();
+		assert_eq!(reservations.len(), 1);
+		assert_eq!(reservations[0].groups.as_ref().unwrap().len(), 0);
+
+		let instances = reservations[0].instances.as_ref().unwrap();
+		assert_eq!(instances.len(), 1);
+		assert_eq!(instances[0].instance_id, Some("i-1234567890abcdef0".to_owned()));
+		assert_eq!(instances[0].public_dns_name, Some("".to_owned()));
+		assert_eq!(instances[0].state.as_ref().unwrap().code, Some(16));
+		assert_eq!(instances[0].tags.as_ref().unwrap()[0].value, Some("Server_1".to_owned()));
+	}
+
+	#[test]
+	fn describe_instances_clone_and_serialize() {
+		let result = parse_describe_instances();
+		assert_eq!(result.clone(), result);
+
+		let json = serde_json::to_string(&result).unwrap();
+		assert!(json.contains("\"InstanceId\":\"i-1234567890abcdef0\""));
+	}
+
+	#[test]
+	fn describe_instances_invalid_code() {
+		assert!(parse_sample("ec2_describe_instances_invalid_code.xml").is_err());
+	}
+
+	#[test]
+	fn strings_are_text_or_empty() {
+		let file = File::open("tests/sample-data/ec2_element_in_string.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		assert!(StringParser::parse_xml("instanceId", &mut reader).is_err());
+	}
+}
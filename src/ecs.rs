@@ -1,5 +1,6 @@
 //! The AWS ECS API.
 
-#![allow(non_snake_case)]
-
+#[cfg(feature = "regenerate")]
 include!(concat!(env!("OUT_DIR"), "/ecs.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/ecs.rs");
@@ -0,0 +1,16624 @@
+use std::io::Read;
+use std::result;
+use serde_json;
+use credentials::AWSCredentialsProvider;
+use error::AWSError;
+use signature::SignedRequest;
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct KMSError {
+    pub __type: String,
+    pub message: String,
+    /// The HTTP status of the response, or 0 if the request failed before one was received.
+    pub status: u16,
+}
+impl ::serde::de::Deserialize for KMSError {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KMSError, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "__type" => { Ok(__Field::__field0) }
+                                "message" => { Ok(__Field::__field1) }
+                                "status" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KMSError;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KMSError, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSError{__type: __field0,
+                                    message: __field1,
+                                    status: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KMSError, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "__type" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None => ::std::default::Default::default(),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSError{__type: __field0,
+                                    message: __field1,
+                                    status: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["__type", "message", "status"];
+            deserializer.visit_struct("KMSError", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+pub type Result<T> = result::Result<T, KMSError>;
+impl KMSError {
+    /// Whether sending the request again may succeed, as when a response failed its CRC32 check.
+    pub fn is_retryable(&self) -> bool { self.__type == "CRC32CheckFailed" }
+}
+impl From<AWSError> for KMSError {
+    fn from(err: AWSError) -> Self {
+        let AWSError(message) = err;
+        KMSError{__type: "Unknown".to_string(),
+                 message: message.to_string(),
+                 status: 0,}
+    }
+}
+fn parse_error(status: u16, body: &str) -> KMSError {
+    let mut error =
+        if let Ok(decoded) = serde_json::from_str::<KMSError>(&body) {
+            decoded
+        } else {
+            KMSError{__type: "DecodeError".to_string(),
+                     message: body.to_string(),
+                     status: 0,}
+        };
+    error.status = status;
+    error
+}
+pub type AWSAccountIdType = String;
+pub type AliasList = Vec<AliasListEntry>;
+/// Contains information about an alias.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct AliasListEntry {
+    /// String that contains the key ARN.
+    pub alias_arn: Option<ArnType>,
+    /// String that contains the alias.
+    pub alias_name: Option<AliasNameType>,
+    /// String that contains the key identifier pointed to by the alias.
+    pub target_key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for AliasListEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<AliasListEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AliasArn" => { Ok(__Field::__field0) }
+                                "AliasName" => { Ok(__Field::__field1) }
+                                "TargetKeyId" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                AliasListEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AliasListEntry, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AliasListEntry{alias_arn: __field0,
+                                          alias_name: __field1,
+                                          target_key_id: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AliasListEntry, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AliasArn" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "AliasName" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "TargetKeyId"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AliasListEntry{alias_arn: __field0,
+                                          alias_name: __field1,
+                                          target_key_id: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["alias_arn", "alias_name", "target_key_id"];
+            deserializer.visit_struct("AliasListEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for AliasListEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a AliasListEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a AliasListEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AliasArn" ,
+                                                    &self.value.alias_arn ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AliasName" ,
+                                                    &self.value.alias_name ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "TargetKeyId" ,
+                                                    &self.value.target_key_id
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("AliasListEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&AliasListEntry>,})
+        }
+    }
+}
+pub type AliasNameType = String;
+/// The request was rejected because it attempted to create a resource that already exists.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct AlreadyExistsException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for AlreadyExistsException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<AlreadyExistsException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                AlreadyExistsException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AlreadyExistsException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AlreadyExistsException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AlreadyExistsException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AlreadyExistsException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("AlreadyExistsException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for AlreadyExistsException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a AlreadyExistsException,
+                _structure_ty: ::std::marker::PhantomData<&'__a AlreadyExistsException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("AlreadyExistsException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&AlreadyExistsException>,})
+        }
+    }
+}
+pub type ArnType = String;
+pub type BooleanType = bool;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CancelKeyDeletionRequest {
+    /// The unique identifier for the customer master key (CMK) for which to cancel deletion.
+    ///
+    /// To specify this value, use the unique key ID or the Amazon Resource Name (ARN) of the CMK. Examples:
+    ///
+    /// * Unique key ID: 1234abcd-12ab-34cd-56ef-1234567890ab
+    /// * Key ARN: arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab
+    ///
+    /// To obtain the unique key ID and key ARN for a given CMK, use ListKeys or DescribeKey.
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for CancelKeyDeletionRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CancelKeyDeletionRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CancelKeyDeletionRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<CancelKeyDeletionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CancelKeyDeletionRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<CancelKeyDeletionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CancelKeyDeletionRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("CancelKeyDeletionRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CancelKeyDeletionRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CancelKeyDeletionRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a CancelKeyDeletionRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("CancelKeyDeletionRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CancelKeyDeletionRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CancelKeyDeletionResponse {
+    /// The unique identifier of the master key for which deletion is canceled.
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for CancelKeyDeletionResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CancelKeyDeletionResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CancelKeyDeletionResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<CancelKeyDeletionResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CancelKeyDeletionResponse{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<CancelKeyDeletionResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CancelKeyDeletionResponse{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("CancelKeyDeletionResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CancelKeyDeletionResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CancelKeyDeletionResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a CancelKeyDeletionResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("CancelKeyDeletionResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CancelKeyDeletionResponse>,})
+        }
+    }
+}
+pub type CiphertextType = ::blob::Blob;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CreateAliasRequest {
+    /// String that contains the display name. The name must start with the word "alias" followed by a forward slash (alias/). Aliases that begin with "alias/AWS" are reserved.
+    pub alias_name: AliasNameType,
+    /// An identifier of the key for which you are creating the alias. This value cannot be another alias but can be a globally unique identifier or a fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub target_key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for CreateAliasRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateAliasRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AliasName" => { Ok(__Field::__field0) }
+                                "TargetKeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateAliasRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateAliasRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateAliasRequest{alias_name: __field0,
+                                              target_key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateAliasRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AliasName" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "TargetKeyId"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateAliasRequest{alias_name: __field0,
+                                              target_key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["alias_name", "target_key_id"];
+            deserializer.visit_struct("CreateAliasRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateAliasRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateAliasRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateAliasRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AliasName" ,
+                                                    &self.value.alias_name ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "TargetKeyId" ,
+                                                    &self.value.target_key_id
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("CreateAliasRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateAliasRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CreateGrantRequest {
+    /// The conditions under which the operations permitted by the grant are allowed.
+    ///
+    /// You can use this value to allow the operations permitted by the grant only when a specified encryption context is present. For more information, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html) in the *AWS Key Management Service Developer Guide*.
+    pub constraints: Option<GrantConstraints>,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// The principal that is given permission to perform the operations that the grant permits.
+    ///
+    /// To specify the principal, use the [Amazon Resource Name (ARN)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html) of an AWS principal. Valid AWS principals include AWS accounts (root), IAM users, federated users, and assumed role users. For examples of the ARN syntax to use for specifying a principal, see [AWS Identity and Access Management (IAM)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-iam) in the Example ARNs section of the *AWS General Reference*.
+    pub grantee_principal: PrincipalIdType,
+    /// The unique identifier for the customer master key (CMK) that the grant applies to.
+    ///
+    /// To specify this value, use the globally unique key ID or the Amazon Resource Name (ARN) of the key. Examples:
+    ///
+    /// * Globally unique key ID: 12345678-1234-1234-1234-123456789012
+    /// * Key ARN: arn:aws:kms:us-west-2:123456789012:key/12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+    /// A friendly name for identifying the grant. Use this value to prevent unintended creation of duplicate grants when retrying this request.
+    ///
+    /// When this value is absent, all `CreateGrant` requests result in a new grant with a unique `GrantId` even if all the supplied parameters are identical. This can result in unintended duplicates when you retry the `CreateGrant` request.
+    ///
+    /// When this value is present, you can retry a `CreateGrant` request with identical parameters; if the grant already exists, the original `GrantId` is returned without creating a new grant. Note that the returned grant token is unique with every `CreateGrant` request, even when a duplicate `GrantId` is returned. All grant tokens obtained in this way can be used interchangeably.
+    pub name: Option<GrantNameType>,
+    /// A list of operations that the grant permits. The list can contain any combination of one or more of the following values:
+    ///
+    /// * Decrypt
+    /// * Encrypt
+    /// * GenerateDataKey
+    /// * GenerateDataKeyWithoutPlaintext
+    /// * ReEncryptFrom
+    /// * ReEncryptTo
+    /// * CreateGrant
+    /// * RetireGrant
+    pub operations: Option<GrantOperationList>,
+    /// The principal that is given permission to retire the grant by using RetireGrant operation.
+    ///
+    /// To specify the principal, use the [Amazon Resource Name (ARN)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html) of an AWS principal. Valid AWS principals include AWS accounts (root), IAM users, federated users, and assumed role users. For examples of the ARN syntax to use for specifying a principal, see [AWS Identity and Access Management (IAM)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-iam) in the Example ARNs section of the *AWS General Reference*.
+    pub retiring_principal: Option<PrincipalIdType>,
+}
+impl ::serde::de::Deserialize for CreateGrantRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateGrantRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field {
+                __field0,
+                __field1,
+                __field2,
+                __field3,
+                __field4,
+                __field5,
+                __field6,
+            }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                5usize => { Ok(__Field::__field5) }
+                                6usize => { Ok(__Field::__field6) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Constraints" => { Ok(__Field::__field0) }
+                                "GrantTokens" => { Ok(__Field::__field1) }
+                                "GranteePrincipal" => {
+                                    Ok(__Field::__field2)
+                                }
+                                "KeyId" => { Ok(__Field::__field3) }
+                                "Name" => { Ok(__Field::__field4) }
+                                "Operations" => { Ok(__Field::__field5) }
+                                "RetiringPrincipal" => {
+                                    Ok(__Field::__field6)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateGrantRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateGrantRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field5 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field6 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateGrantRequest{constraints: __field0,
+                                              grant_tokens: __field1,
+                                              grantee_principal: __field2,
+                                              key_id: __field3,
+                                              name: __field4,
+                                              operations: __field5,
+                                              retiring_principal: __field6,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateGrantRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        let mut __field5 = None;
+                        let mut __field6 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field5 => {
+                                    __field5 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field6 => {
+                                    __field6 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Constraints"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "GranteePrincipal" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field ( "Name" )),
+                            };
+                        let __field5 =
+                            match __field5 {
+                                Some(__field5) => __field5,
+                                None =>
+                                try!(visitor . missing_field ( "Operations"
+                                     )),
+                            };
+                        let __field6 =
+                            match __field6 {
+                                Some(__field6) => __field6,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "RetiringPrincipal" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateGrantRequest{constraints: __field0,
+                                              grant_tokens: __field1,
+                                              grantee_principal: __field2,
+                                              key_id: __field3,
+                                              name: __field4,
+                                              operations: __field5,
+                                              retiring_principal: __field6,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["constraints", "grant_tokens", "grantee_principal",
+                  "key_id", "name", "operations", "retiring_principal"];
+            deserializer.visit_struct("CreateGrantRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateGrantRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateGrantRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateGrantRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Constraints" ,
+                                                    &self.value.constraints ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GranteePrincipal" ,
+                                                    &self.value.grantee_principal
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Name" , &self.value.name
+                                                    , ))));
+                            }
+                            5usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Operations" ,
+                                                    &self.value.operations ,
+                                                    ))));
+                            }
+                            6usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "RetiringPrincipal" ,
+                                                    &self.value.retiring_principal
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> {
+                    Some(0 + 1 + 1 + 1 + 1 + 1 + 1 + 1)
+                }
+            }
+            serializer.visit_struct("CreateGrantRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateGrantRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CreateGrantResponse {
+    /// The unique identifier for the grant.
+    ///
+    /// You can use the `GrantId` in a subsequent RetireGrant or RevokeGrant operation.
+    pub grant_id: Option<GrantIdType>,
+    /// The grant token.
+    ///
+    /// For more information about using grant tokens, see [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_token: Option<GrantTokenType>,
+}
+impl ::serde::de::Deserialize for CreateGrantResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateGrantResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "GrantId" => { Ok(__Field::__field0) }
+                                "GrantToken" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateGrantResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateGrantResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateGrantResponse{grant_id: __field0,
+                                               grant_token: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateGrantResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "GrantId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantToken"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateGrantResponse{grant_id: __field0,
+                                               grant_token: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["grant_id", "grant_token"];
+            deserializer.visit_struct("CreateGrantResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateGrantResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateGrantResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateGrantResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantId" ,
+                                                    &self.value.grant_id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantToken" ,
+                                                    &self.value.grant_token ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("CreateGrantResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateGrantResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CreateKeyRequest {
+    /// Description of the key. We recommend that you choose a description that helps your customer decide whether the key is appropriate for a task.
+    pub description: Option<DescriptionType>,
+    /// Specifies the intended use of the key. Currently this defaults to ENCRYPT/DECRYPT, and only symmetric encryption and decryption are supported.
+    pub key_usage: Option<KeyUsageType>,
+    /// Policy to attach to the key. This is required and delegates back to the account. The key is the root of trust. The policy size limit is 32 KiB (32768 bytes).
+    pub policy: Option<PolicyType>,
+}
+impl ::serde::de::Deserialize for CreateKeyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateKeyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Description" => { Ok(__Field::__field0) }
+                                "KeyUsage" => { Ok(__Field::__field1) }
+                                "Policy" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateKeyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateKeyRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateKeyRequest{description: __field0,
+                                            key_usage: __field1,
+                                            policy: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateKeyRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Description"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyUsage" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Policy" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateKeyRequest{description: __field0,
+                                            key_usage: __field1,
+                                            policy: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["description", "key_usage", "policy"];
+            deserializer.visit_struct("CreateKeyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateKeyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateKeyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateKeyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Description" ,
+                                                    &self.value.description ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyUsage" ,
+                                                    &self.value.key_usage ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Policy" ,
+                                                    &self.value.policy , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("CreateKeyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateKeyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CreateKeyResponse {
+    /// Metadata associated with the key.
+    pub key_metadata: Option<KeyMetadata>,
+}
+impl ::serde::de::Deserialize for CreateKeyResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateKeyResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyMetadata" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateKeyResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateKeyResponse, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateKeyResponse{key_metadata: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateKeyResponse, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyMetadata"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateKeyResponse{key_metadata: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_metadata"];
+            deserializer.visit_struct("CreateKeyResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateKeyResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateKeyResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateKeyResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyMetadata" ,
+                                                    &self.value.key_metadata ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("CreateKeyResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateKeyResponse>,})
+        }
+    }
+}
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub enum DataKeySpec {
+    Aes256,
+    Aes128,
+
+    /// A value that wasn't known when this client was generated.
+    Unknown(String),
+}
+impl DataKeySpec {
+    /// The value as sent to and from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataKeySpec::Aes256 => "AES_256",
+            DataKeySpec::Aes128 => "AES_128",
+            DataKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+impl Default for DataKeySpec {
+    fn default() -> DataKeySpec { DataKeySpec::Unknown(String::new()) }
+}
+impl <'a> From<&'a str> for DataKeySpec {
+    fn from(value: &'a str) -> DataKeySpec {
+        match value {
+            "AES_256" => DataKeySpec::Aes256,
+            "AES_128" => DataKeySpec::Aes128,
+            _ => DataKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+impl ::std::fmt::Display for DataKeySpec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f , "{}" , self . as_str (  ))
+    }
+}
+impl ::serde::Serialize for DataKeySpec {
+    fn serialize<S>(&self, serializer: &mut S)
+     -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {
+        ::serde::Serialize::serialize(self.as_str(), serializer)
+    }
+}
+impl ::serde::Deserialize for DataKeySpec {
+    fn deserialize<D>(deserializer: &mut D)
+     -> ::std::result::Result<DataKeySpec, D::Error> where
+     D: ::serde::Deserializer {
+        let value: String =
+            try!(:: serde:: Deserialize:: deserialize ( deserializer ));
+        Ok(DataKeySpec::from(&value[..]))
+    }
+}
+pub type DateType = ::timestamp::Timestamp;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DecryptRequest {
+    /// Ciphertext to be decrypted. The blob includes metadata.
+    pub ciphertext_blob: CiphertextType,
+    /// The encryption context. If this was specified in the Encrypt function, it must be specified here or the decryption operation will fail. For more information, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html).
+    pub encryption_context: Option<EncryptionContextType>,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+}
+impl ::serde::de::Deserialize for DecryptRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DecryptRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "EncryptionContext" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "GrantTokens" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DecryptRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DecryptRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DecryptRequest{ciphertext_blob: __field0,
+                                          encryption_context: __field1,
+                                          grant_tokens: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DecryptRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContext" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DecryptRequest{ciphertext_blob: __field0,
+                                          encryption_context: __field1,
+                                          grant_tokens: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "encryption_context", "grant_tokens"];
+            deserializer.visit_struct("DecryptRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DecryptRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DecryptRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DecryptRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContext" ,
+                                                    &self.value.encryption_context
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("DecryptRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DecryptRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DecryptResponse {
+    /// ARN of the key used to perform the decryption. This value is returned if no errors are encountered during the operation.
+    pub key_id: Option<KeyIdType>,
+    /// Decrypted plaintext data. This value may not be returned if the customer master key is not available or if you didn't have permission to use it.
+    pub plaintext: Option<PlaintextType>,
+}
+impl ::serde::de::Deserialize for DecryptResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DecryptResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "Plaintext" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DecryptResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DecryptResponse, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DecryptResponse{key_id: __field0,
+                                           plaintext: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DecryptResponse, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Plaintext" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DecryptResponse{key_id: __field0,
+                                           plaintext: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id", "plaintext"];
+            deserializer.visit_struct("DecryptResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DecryptResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DecryptResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a DecryptResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Plaintext" ,
+                                                    &self.value.plaintext ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DecryptResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DecryptResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DeleteAliasRequest {
+    /// The alias to be deleted. The name must start with the word "alias" followed by a forward slash (alias/). Aliases that begin with "alias/AWS" are reserved.
+    pub alias_name: AliasNameType,
+}
+impl ::serde::de::Deserialize for DeleteAliasRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteAliasRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AliasName" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteAliasRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteAliasRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteAliasRequest{alias_name: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteAliasRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AliasName" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteAliasRequest{alias_name: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["alias_name"];
+            deserializer.visit_struct("DeleteAliasRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteAliasRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteAliasRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteAliasRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AliasName" ,
+                                                    &self.value.alias_name ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DeleteAliasRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteAliasRequest>,})
+        }
+    }
+}
+/// The system timed out while trying to fulfill the request. The request can be retried.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DependencyTimeoutException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for DependencyTimeoutException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DependencyTimeoutException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DependencyTimeoutException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DependencyTimeoutException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DependencyTimeoutException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DependencyTimeoutException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DependencyTimeoutException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("DependencyTimeoutException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DependencyTimeoutException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DependencyTimeoutException,
+                _structure_ty: ::std::marker::PhantomData<&'__a DependencyTimeoutException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DependencyTimeoutException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DependencyTimeoutException>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DescribeKeyRequest {
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for DescribeKeyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DescribeKeyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "GrantTokens" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DescribeKeyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DescribeKeyRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DescribeKeyRequest{grant_tokens: __field0,
+                                              key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DescribeKeyRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DescribeKeyRequest{grant_tokens: __field0,
+                                              key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["grant_tokens", "key_id"];
+            deserializer.visit_struct("DescribeKeyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DescribeKeyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DescribeKeyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DescribeKeyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DescribeKeyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DescribeKeyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DescribeKeyResponse {
+    /// Metadata associated with the key.
+    pub key_metadata: Option<KeyMetadata>,
+}
+impl ::serde::de::Deserialize for DescribeKeyResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DescribeKeyResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyMetadata" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DescribeKeyResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DescribeKeyResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DescribeKeyResponse{key_metadata: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DescribeKeyResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyMetadata"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DescribeKeyResponse{key_metadata: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_metadata"];
+            deserializer.visit_struct("DescribeKeyResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DescribeKeyResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DescribeKeyResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a DescribeKeyResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyMetadata" ,
+                                                    &self.value.key_metadata ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DescribeKeyResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DescribeKeyResponse>,})
+        }
+    }
+}
+pub type DescriptionType = String;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DisableKeyRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for DisableKeyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DisableKeyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DisableKeyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DisableKeyRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisableKeyRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DisableKeyRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisableKeyRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("DisableKeyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DisableKeyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DisableKeyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DisableKeyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DisableKeyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DisableKeyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DisableKeyRotationRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for DisableKeyRotationRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DisableKeyRotationRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DisableKeyRotationRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DisableKeyRotationRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisableKeyRotationRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DisableKeyRotationRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisableKeyRotationRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("DisableKeyRotationRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DisableKeyRotationRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DisableKeyRotationRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DisableKeyRotationRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DisableKeyRotationRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DisableKeyRotationRequest>,})
+        }
+    }
+}
+/// The request was rejected because the specified key was marked as disabled.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DisabledException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for DisabledException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DisabledException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DisabledException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DisabledException, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisabledException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DisabledException, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DisabledException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("DisabledException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DisabledException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DisabledException,
+                _structure_ty: ::std::marker::PhantomData<&'__a DisabledException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DisabledException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DisabledException>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct EnableKeyRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for EnableKeyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<EnableKeyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                EnableKeyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EnableKeyRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EnableKeyRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EnableKeyRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EnableKeyRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("EnableKeyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for EnableKeyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a EnableKeyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a EnableKeyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("EnableKeyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&EnableKeyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct EnableKeyRotationRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for EnableKeyRotationRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<EnableKeyRotationRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                EnableKeyRotationRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<EnableKeyRotationRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EnableKeyRotationRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<EnableKeyRotationRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EnableKeyRotationRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("EnableKeyRotationRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for EnableKeyRotationRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a EnableKeyRotationRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a EnableKeyRotationRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("EnableKeyRotationRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&EnableKeyRotationRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct EncryptRequest {
+    /// Name/value pair that specifies the encryption context to be used for authenticated encryption. If used here, the same value must be supplied to the `Decrypt` API or decryption will fail. For more information, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html).
+    pub encryption_context: Option<EncryptionContextType>,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub key_id: KeyIdType,
+    /// Data to be encrypted.
+    pub plaintext: PlaintextType,
+}
+impl ::serde::de::Deserialize for EncryptRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<EncryptRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "EncryptionContext" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "GrantTokens" => { Ok(__Field::__field1) }
+                                "KeyId" => { Ok(__Field::__field2) }
+                                "Plaintext" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                EncryptRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EncryptRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EncryptRequest{encryption_context: __field0,
+                                          grant_tokens: __field1,
+                                          key_id: __field2,
+                                          plaintext: __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EncryptRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContext" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "Plaintext" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EncryptRequest{encryption_context: __field0,
+                                          grant_tokens: __field1,
+                                          key_id: __field2,
+                                          plaintext: __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["encryption_context", "grant_tokens", "key_id",
+                  "plaintext"];
+            deserializer.visit_struct("EncryptRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for EncryptRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a EncryptRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a EncryptRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContext" ,
+                                                    &self.value.encryption_context
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Plaintext" ,
+                                                    &self.value.plaintext ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("EncryptRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&EncryptRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct EncryptResponse {
+    /// The encrypted plaintext. If you are using the CLI, the value is Base64 encoded. Otherwise, it is not encoded.
+    pub ciphertext_blob: Option<CiphertextType>,
+    /// The ID of the key used during encryption.
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for EncryptResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<EncryptResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                EncryptResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EncryptResponse, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EncryptResponse{ciphertext_blob: __field0,
+                                           key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<EncryptResponse, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(EncryptResponse{ciphertext_blob: __field0,
+                                           key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "key_id"];
+            deserializer.visit_struct("EncryptResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for EncryptResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a EncryptResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a EncryptResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("EncryptResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&EncryptResponse>,})
+        }
+    }
+}
+pub type EncryptionContextKey = String;
+pub type EncryptionContextType =
+    ::std::collections::HashMap<EncryptionContextKey, EncryptionContextValue>;
+pub type EncryptionContextValue = String;
+pub type ErrorMessageType = String;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateDataKeyRequest {
+    /// Name/value pair that contains additional data to be authenticated during the encryption and decryption processes that use the key. This value is logged by AWS CloudTrail to provide context around the data encrypted by the key.
+    pub encryption_context: Option<EncryptionContextType>,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub key_id: KeyIdType,
+    /// Value that identifies the encryption algorithm and key size to generate a data key for. Currently this can be AES_128 or AES_256.
+    pub key_spec: Option<DataKeySpec>,
+    /// Integer that contains the number of bytes to generate. Common values are 128, 256, 512, and 1024. 1024 is the current limit. We recommend that you use the `KeySpec` parameter instead.
+    pub number_of_bytes: Option<NumberOfBytesType>,
+}
+impl ::serde::de::Deserialize for GenerateDataKeyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GenerateDataKeyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, __field4, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "EncryptionContext" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "GrantTokens" => { Ok(__Field::__field1) }
+                                "KeyId" => { Ok(__Field::__field2) }
+                                "KeySpec" => { Ok(__Field::__field3) }
+                                "NumberOfBytes" => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateDataKeyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateDataKeyRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyRequest{encryption_context:
+                                                      __field0,
+                                                  grant_tokens: __field1,
+                                                  key_id: __field2,
+                                                  key_spec: __field3,
+                                                  number_of_bytes: __field4,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateDataKeyRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContext" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "KeySpec" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field ( "NumberOfBytes"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyRequest{encryption_context:
+                                                      __field0,
+                                                  grant_tokens: __field1,
+                                                  key_id: __field2,
+                                                  key_spec: __field3,
+                                                  number_of_bytes: __field4,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["encryption_context", "grant_tokens", "key_id", "key_spec",
+                  "number_of_bytes"];
+            deserializer.visit_struct("GenerateDataKeyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateDataKeyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateDataKeyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateDataKeyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContext" ,
+                                                    &self.value.encryption_context
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeySpec" ,
+                                                    &self.value.key_spec ,
+                                                    ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NumberOfBytes" ,
+                                                    &self.value.number_of_bytes
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("GenerateDataKeyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateDataKeyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateDataKeyResponse {
+    /// Ciphertext that contains the encrypted data key. You must store the blob and enough information to reconstruct the encryption context so that the data encrypted by using the key can later be decrypted. You must provide both the ciphertext blob and the encryption context to the Decrypt API to recover the plaintext data key and decrypt the object.
+    ///
+    /// If you are using the CLI, the value is Base64 encoded. Otherwise, it is not encoded.
+    pub ciphertext_blob: Option<CiphertextType>,
+    /// System generated unique identifier of the key to be used to decrypt the encrypted copy of the data key.
+    pub key_id: Option<KeyIdType>,
+    /// Plaintext that contains the data key. Use this for encryption and decryption and then remove it from memory as soon as possible.
+    pub plaintext: Option<PlaintextType>,
+}
+impl ::serde::de::Deserialize for GenerateDataKeyResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GenerateDataKeyResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                "Plaintext" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateDataKeyResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateDataKeyResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyResponse{ciphertext_blob: __field0,
+                                                   key_id: __field1,
+                                                   plaintext: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateDataKeyResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Plaintext" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyResponse{ciphertext_blob: __field0,
+                                                   key_id: __field1,
+                                                   plaintext: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "key_id", "plaintext"];
+            deserializer.visit_struct("GenerateDataKeyResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateDataKeyResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateDataKeyResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateDataKeyResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Plaintext" ,
+                                                    &self.value.plaintext ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("GenerateDataKeyResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateDataKeyResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateDataKeyWithoutPlaintextRequest {
+    /// Name:value pair that contains additional data to be authenticated during the encryption and decryption processes.
+    pub encryption_context: Option<EncryptionContextType>,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub key_id: KeyIdType,
+    /// Value that identifies the encryption algorithm and key size. Currently this can be AES_128 or AES_256.
+    pub key_spec: Option<DataKeySpec>,
+    /// Integer that contains the number of bytes to generate. Common values are 128, 256, 512, 1024 and so on. We recommend that you use the `KeySpec` parameter instead.
+    pub number_of_bytes: Option<NumberOfBytesType>,
+}
+impl ::serde::de::Deserialize for GenerateDataKeyWithoutPlaintextRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     ->
+         ::std::result::Result<GenerateDataKeyWithoutPlaintextRequest,
+                               __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, __field4, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "EncryptionContext" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "GrantTokens" => { Ok(__Field::__field1) }
+                                "KeyId" => { Ok(__Field::__field2) }
+                                "KeySpec" => { Ok(__Field::__field3) }
+                                "NumberOfBytes" => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateDataKeyWithoutPlaintextRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GenerateDataKeyWithoutPlaintextRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyWithoutPlaintextRequest{encryption_context:
+                                                                      __field0,
+                                                                  grant_tokens:
+                                                                      __field1,
+                                                                  key_id:
+                                                                      __field2,
+                                                                  key_spec:
+                                                                      __field3,
+                                                                  number_of_bytes:
+                                                                      __field4,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GenerateDataKeyWithoutPlaintextRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContext" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "KeySpec" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field ( "NumberOfBytes"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyWithoutPlaintextRequest{encryption_context:
+                                                                      __field0,
+                                                                  grant_tokens:
+                                                                      __field1,
+                                                                  key_id:
+                                                                      __field2,
+                                                                  key_spec:
+                                                                      __field3,
+                                                                  number_of_bytes:
+                                                                      __field4,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["encryption_context", "grant_tokens", "key_id", "key_spec",
+                  "number_of_bytes"];
+            deserializer.visit_struct("GenerateDataKeyWithoutPlaintextRequest",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateDataKeyWithoutPlaintextRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateDataKeyWithoutPlaintextRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateDataKeyWithoutPlaintextRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContext" ,
+                                                    &self.value.encryption_context
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeySpec" ,
+                                                    &self.value.key_spec ,
+                                                    ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NumberOfBytes" ,
+                                                    &self.value.number_of_bytes
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("GenerateDataKeyWithoutPlaintextRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateDataKeyWithoutPlaintextRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateDataKeyWithoutPlaintextResponse {
+    /// Ciphertext that contains the wrapped data key. You must store the blob and encryption context so that the key can be used in a future decrypt operation.
+    ///
+    /// If you are using the CLI, the value is Base64 encoded. Otherwise, it is not encoded.
+    pub ciphertext_blob: Option<CiphertextType>,
+    /// System generated unique identifier of the key to be used to decrypt the encrypted copy of the data key.
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for GenerateDataKeyWithoutPlaintextResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     ->
+         ::std::result::Result<GenerateDataKeyWithoutPlaintextResponse,
+                               __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateDataKeyWithoutPlaintextResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GenerateDataKeyWithoutPlaintextResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyWithoutPlaintextResponse{ciphertext_blob:
+                                                                       __field0,
+                                                                   key_id:
+                                                                       __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GenerateDataKeyWithoutPlaintextResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateDataKeyWithoutPlaintextResponse{ciphertext_blob:
+                                                                       __field0,
+                                                                   key_id:
+                                                                       __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "key_id"];
+            deserializer.visit_struct("GenerateDataKeyWithoutPlaintextResponse",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateDataKeyWithoutPlaintextResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateDataKeyWithoutPlaintextResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateDataKeyWithoutPlaintextResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("GenerateDataKeyWithoutPlaintextResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateDataKeyWithoutPlaintextResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateRandomRequest {
+    /// Integer that contains the number of bytes to generate. Common values are 128, 256, 512, 1024 and so on. The current limit is 1024 bytes.
+    pub number_of_bytes: Option<NumberOfBytesType>,
+}
+impl ::serde::de::Deserialize for GenerateRandomRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GenerateRandomRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "NumberOfBytes" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateRandomRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateRandomRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateRandomRequest{number_of_bytes: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateRandomRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "NumberOfBytes"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateRandomRequest{number_of_bytes: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["number_of_bytes"];
+            deserializer.visit_struct("GenerateRandomRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateRandomRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateRandomRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateRandomRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NumberOfBytes" ,
+                                                    &self.value.number_of_bytes
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GenerateRandomRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateRandomRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GenerateRandomResponse {
+    /// Plaintext that contains the unpredictable byte string.
+    pub plaintext: Option<PlaintextType>,
+}
+impl ::serde::de::Deserialize for GenerateRandomResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GenerateRandomResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Plaintext" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GenerateRandomResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateRandomResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateRandomResponse{plaintext: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GenerateRandomResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Plaintext" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GenerateRandomResponse{plaintext: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["plaintext"];
+            deserializer.visit_struct("GenerateRandomResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GenerateRandomResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GenerateRandomResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a GenerateRandomResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Plaintext" ,
+                                                    &self.value.plaintext ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GenerateRandomResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GenerateRandomResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GetKeyPolicyRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+    /// String that contains the name of the policy. Currently, this must be "default". Policy names can be discovered by calling ListKeyPolicies.
+    pub policy_name: PolicyNameType,
+}
+impl ::serde::de::Deserialize for GetKeyPolicyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetKeyPolicyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "PolicyName" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetKeyPolicyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetKeyPolicyRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyPolicyRequest{key_id: __field0,
+                                               policy_name: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetKeyPolicyRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "PolicyName"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyPolicyRequest{key_id: __field0,
+                                               policy_name: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["key_id", "policy_name"];
+            deserializer.visit_struct("GetKeyPolicyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetKeyPolicyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetKeyPolicyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetKeyPolicyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "PolicyName" ,
+                                                    &self.value.policy_name ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("GetKeyPolicyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetKeyPolicyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GetKeyPolicyResponse {
+    /// A policy document in JSON format.
+    pub policy: Option<PolicyType>,
+}
+impl ::serde::de::Deserialize for GetKeyPolicyResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetKeyPolicyResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Policy" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetKeyPolicyResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetKeyPolicyResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyPolicyResponse{policy: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetKeyPolicyResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Policy" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyPolicyResponse{policy: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["policy"];
+            deserializer.visit_struct("GetKeyPolicyResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetKeyPolicyResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetKeyPolicyResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetKeyPolicyResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Policy" ,
+                                                    &self.value.policy , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GetKeyPolicyResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetKeyPolicyResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GetKeyRotationStatusRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for GetKeyRotationStatusRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetKeyRotationStatusRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetKeyRotationStatusRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetKeyRotationStatusRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyRotationStatusRequest{key_id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetKeyRotationStatusRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyRotationStatusRequest{key_id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_id"];
+            deserializer.visit_struct("GetKeyRotationStatusRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetKeyRotationStatusRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetKeyRotationStatusRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetKeyRotationStatusRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GetKeyRotationStatusRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetKeyRotationStatusRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GetKeyRotationStatusResponse {
+    /// A Boolean value that specifies whether key rotation is enabled.
+    pub key_rotation_enabled: Option<BooleanType>,
+}
+impl ::serde::de::Deserialize for GetKeyRotationStatusResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetKeyRotationStatusResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyRotationEnabled" => {
+                                    Ok(__Field::__field0)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetKeyRotationStatusResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetKeyRotationStatusResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyRotationStatusResponse{key_rotation_enabled:
+                                                            __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetKeyRotationStatusResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "KeyRotationEnabled" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetKeyRotationStatusResponse{key_rotation_enabled:
+                                                            __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_rotation_enabled"];
+            deserializer.visit_struct("GetKeyRotationStatusResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetKeyRotationStatusResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetKeyRotationStatusResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetKeyRotationStatusResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyRotationEnabled" ,
+                                                    &self.value.key_rotation_enabled
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GetKeyRotationStatusResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetKeyRotationStatusResponse>,})
+        }
+    }
+}
+/// A structure for specifying the conditions under which the operations permitted by the grant are allowed.
+///
+/// You can use this structure to allow the operations permitted by the grant only when a specified encryption context is present. For more information about encryption context, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html) in the *AWS Key Management Service Developer Guide*.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GrantConstraints {
+    /// Contains a list of key-value pairs that must be present in the encryption context of a subsequent operation permitted by the grant. When a subsequent operation permitted by the grant includes an encryption context that matches this list, the grant allows the operation. Otherwise, the operation is not allowed.
+    pub encryption_context_equals: Option<EncryptionContextType>,
+    /// Contains a list of key-value pairs, a subset of which must be present in the encryption context of a subsequent operation permitted by the grant. When a subsequent operation permitted by the grant includes an encryption context that matches this list or is a subset of this list, the grant allows the operation. Otherwise, the operation is not allowed.
+    pub encryption_context_subset: Option<EncryptionContextType>,
+}
+impl ::serde::de::Deserialize for GrantConstraints {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GrantConstraints, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "EncryptionContextEquals" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "EncryptionContextSubset" => {
+                                    Ok(__Field::__field1)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GrantConstraints;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GrantConstraints, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GrantConstraints{encryption_context_equals:
+                                                __field0,
+                                            encryption_context_subset:
+                                                __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GrantConstraints, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContextEquals" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "EncryptionContextSubset" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GrantConstraints{encryption_context_equals:
+                                                __field0,
+                                            encryption_context_subset:
+                                                __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["encryption_context_equals", "encryption_context_subset"];
+            deserializer.visit_struct("GrantConstraints", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GrantConstraints {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GrantConstraints,
+                _structure_ty: ::std::marker::PhantomData<&'__a GrantConstraints>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContextEquals"
+                                                    ,
+                                                    &self.value.encryption_context_equals
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "EncryptionContextSubset"
+                                                    ,
+                                                    &self.value.encryption_context_subset
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("GrantConstraints",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GrantConstraints>,})
+        }
+    }
+}
+pub type GrantIdType = String;
+pub type GrantList = Vec<GrantListEntry>;
+/// Contains information about an entry in a list of grants.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct GrantListEntry {
+    /// The conditions under which the grant's operations are allowed.
+    pub constraints: Option<GrantConstraints>,
+    /// The date and time when the grant was created.
+    pub creation_date: Option<DateType>,
+    /// The unique identifier for the grant.
+    pub grant_id: Option<GrantIdType>,
+    /// The principal that receives the grant's permissions.
+    pub grantee_principal: Option<PrincipalIdType>,
+    /// The AWS account under which the grant was issued.
+    pub issuing_account: Option<PrincipalIdType>,
+    /// The unique identifier for the customer master key (CMK) to which the grant applies.
+    pub key_id: Option<KeyIdType>,
+    /// The friendly name that identifies the grant. If a name was provided in the CreateGrant request, that name is returned. Otherwise this value is null.
+    pub name: Option<GrantNameType>,
+    /// The list of operations permitted by the grant.
+    pub operations: Option<GrantOperationList>,
+    /// The principal that can retire the grant.
+    pub retiring_principal: Option<PrincipalIdType>,
+}
+impl ::serde::de::Deserialize for GrantListEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GrantListEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field {
+                __field0,
+                __field1,
+                __field2,
+                __field3,
+                __field4,
+                __field5,
+                __field6,
+                __field7,
+                __field8,
+            }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                5usize => { Ok(__Field::__field5) }
+                                6usize => { Ok(__Field::__field6) }
+                                7usize => { Ok(__Field::__field7) }
+                                8usize => { Ok(__Field::__field8) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Constraints" => { Ok(__Field::__field0) }
+                                "CreationDate" => { Ok(__Field::__field1) }
+                                "GrantId" => { Ok(__Field::__field2) }
+                                "GranteePrincipal" => {
+                                    Ok(__Field::__field3)
+                                }
+                                "IssuingAccount" => { Ok(__Field::__field4) }
+                                "KeyId" => { Ok(__Field::__field5) }
+                                "Name" => { Ok(__Field::__field6) }
+                                "Operations" => { Ok(__Field::__field7) }
+                                "RetiringPrincipal" => {
+                                    Ok(__Field::__field8)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GrantListEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GrantListEntry, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field5 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field6 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field7 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field8 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GrantListEntry{constraints: __field0,
+                                          creation_date: __field1,
+                                          grant_id: __field2,
+                                          grantee_principal: __field3,
+                                          issuing_account: __field4,
+                                          key_id: __field5,
+                                          name: __field6,
+                                          operations: __field7,
+                                          retiring_principal: __field8,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GrantListEntry, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        let mut __field5 = None;
+                        let mut __field6 = None;
+                        let mut __field7 = None;
+                        let mut __field8 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field5 => {
+                                    __field5 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field6 => {
+                                    __field6 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field7 => {
+                                    __field7 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field8 => {
+                                    __field8 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Constraints"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "CreationDate"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "GrantId" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "GranteePrincipal" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "IssuingAccount" )),
+                            };
+                        let __field5 =
+                            match __field5 {
+                                Some(__field5) => __field5,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field6 =
+                            match __field6 {
+                                Some(__field6) => __field6,
+                                None =>
+                                try!(visitor . missing_field ( "Name" )),
+                            };
+                        let __field7 =
+                            match __field7 {
+                                Some(__field7) => __field7,
+                                None =>
+                                try!(visitor . missing_field ( "Operations"
+                                     )),
+                            };
+                        let __field8 =
+                            match __field8 {
+                                Some(__field8) => __field8,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "RetiringPrincipal" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GrantListEntry{constraints: __field0,
+                                          creation_date: __field1,
+                                          grant_id: __field2,
+                                          grantee_principal: __field3,
+                                          issuing_account: __field4,
+                                          key_id: __field5,
+                                          name: __field6,
+                                          operations: __field7,
+                                          retiring_principal: __field8,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["constraints", "creation_date", "grant_id",
+                  "grantee_principal", "issuing_account", "key_id", "name",
+                  "operations", "retiring_principal"];
+            deserializer.visit_struct("GrantListEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GrantListEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GrantListEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a GrantListEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Constraints" ,
+                                                    &self.value.constraints ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CreationDate" ,
+                                                    &self.value.creation_date
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantId" ,
+                                                    &self.value.grant_id ,
+                                                    ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GranteePrincipal" ,
+                                                    &self.value.grantee_principal
+                                                    , ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "IssuingAccount" ,
+                                                    &self.value.issuing_account
+                                                    , ))));
+                            }
+                            5usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            6usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Name" , &self.value.name
+                                                    , ))));
+                            }
+                            7usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Operations" ,
+                                                    &self.value.operations ,
+                                                    ))));
+                            }
+                            8usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "RetiringPrincipal" ,
+                                                    &self.value.retiring_principal
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> {
+                    Some(0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1)
+                }
+            }
+            serializer.visit_struct("GrantListEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GrantListEntry>,})
+        }
+    }
+}
+pub type GrantNameType = String;
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub enum GrantOperation {
+    Decrypt,
+    Encrypt,
+    GenerateDataKey,
+    GenerateDataKeyWithoutPlaintext,
+    ReEncryptFrom,
+    ReEncryptTo,
+    CreateGrant,
+    RetireGrant,
+    DescribeKey,
+
+    /// A value that wasn't known when this client was generated.
+    Unknown(String),
+}
+impl GrantOperation {
+    /// The value as sent to and from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrantOperation::Decrypt => "Decrypt",
+            GrantOperation::Encrypt => "Encrypt",
+            GrantOperation::GenerateDataKey => "GenerateDataKey",
+            GrantOperation::GenerateDataKeyWithoutPlaintext =>
+            "GenerateDataKeyWithoutPlaintext",
+            GrantOperation::ReEncryptFrom => "ReEncryptFrom",
+            GrantOperation::ReEncryptTo => "ReEncryptTo",
+            GrantOperation::CreateGrant => "CreateGrant",
+            GrantOperation::RetireGrant => "RetireGrant",
+            GrantOperation::DescribeKey => "DescribeKey",
+            GrantOperation::Unknown(ref value) => value,
+        }
+    }
+}
+impl Default for GrantOperation {
+    fn default() -> GrantOperation { GrantOperation::Unknown(String::new()) }
+}
+impl <'a> From<&'a str> for GrantOperation {
+    fn from(value: &'a str) -> GrantOperation {
+        match value {
+            "Decrypt" => GrantOperation::Decrypt,
+            "Encrypt" => GrantOperation::Encrypt,
+            "GenerateDataKey" => GrantOperation::GenerateDataKey,
+            "GenerateDataKeyWithoutPlaintext" =>
+            GrantOperation::GenerateDataKeyWithoutPlaintext,
+            "ReEncryptFrom" => GrantOperation::ReEncryptFrom,
+            "ReEncryptTo" => GrantOperation::ReEncryptTo,
+            "CreateGrant" => GrantOperation::CreateGrant,
+            "RetireGrant" => GrantOperation::RetireGrant,
+            "DescribeKey" => GrantOperation::DescribeKey,
+            _ => GrantOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+impl ::std::fmt::Display for GrantOperation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f , "{}" , self . as_str (  ))
+    }
+}
+impl ::serde::Serialize for GrantOperation {
+    fn serialize<S>(&self, serializer: &mut S)
+     -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {
+        ::serde::Serialize::serialize(self.as_str(), serializer)
+    }
+}
+impl ::serde::Deserialize for GrantOperation {
+    fn deserialize<D>(deserializer: &mut D)
+     -> ::std::result::Result<GrantOperation, D::Error> where
+     D: ::serde::Deserializer {
+        let value: String =
+            try!(:: serde:: Deserialize:: deserialize ( deserializer ));
+        Ok(GrantOperation::from(&value[..]))
+    }
+}
+pub type GrantOperationList = Vec<GrantOperation>;
+pub type GrantTokenList = Vec<GrantTokenType>;
+pub type GrantTokenType = String;
+/// The request was rejected because the specified alias name is not valid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidAliasNameException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidAliasNameException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidAliasNameException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidAliasNameException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidAliasNameException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidAliasNameException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidAliasNameException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidAliasNameException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidAliasNameException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidAliasNameException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidAliasNameException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidAliasNameException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidAliasNameException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidAliasNameException>,})
+        }
+    }
+}
+/// The request was rejected because a specified ARN was not valid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidArnException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidArnException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidArnException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidArnException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidArnException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidArnException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidArnException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidArnException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidArnException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidArnException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidArnException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidArnException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidArnException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidArnException>,})
+        }
+    }
+}
+/// The request was rejected because the specified ciphertext has been corrupted or is otherwise invalid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidCiphertextException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidCiphertextException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidCiphertextException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidCiphertextException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidCiphertextException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidCiphertextException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidCiphertextException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidCiphertextException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidCiphertextException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidCiphertextException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidCiphertextException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidCiphertextException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidCiphertextException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidCiphertextException>,})
+        }
+    }
+}
+/// The request was rejected because the specified `GrantId` is not valid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidGrantIdException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidGrantIdException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidGrantIdException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidGrantIdException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidGrantIdException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidGrantIdException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidGrantIdException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidGrantIdException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidGrantIdException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidGrantIdException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidGrantIdException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidGrantIdException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidGrantIdException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidGrantIdException>,})
+        }
+    }
+}
+/// The request was rejected because a grant token provided as part of the request is invalid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidGrantTokenException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidGrantTokenException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidGrantTokenException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidGrantTokenException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidGrantTokenException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidGrantTokenException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidGrantTokenException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidGrantTokenException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidGrantTokenException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidGrantTokenException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidGrantTokenException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidGrantTokenException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidGrantTokenException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidGrantTokenException>,})
+        }
+    }
+}
+/// The request was rejected because the specified KeySpec parameter is not valid. The currently supported value is ENCRYPT/DECRYPT.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidKeyUsageException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidKeyUsageException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidKeyUsageException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidKeyUsageException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidKeyUsageException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidKeyUsageException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<InvalidKeyUsageException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidKeyUsageException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidKeyUsageException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidKeyUsageException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidKeyUsageException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidKeyUsageException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidKeyUsageException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidKeyUsageException>,})
+        }
+    }
+}
+/// The request was rejected because the marker that specifies where pagination should next begin is not valid.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct InvalidMarkerException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for InvalidMarkerException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidMarkerException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                InvalidMarkerException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidMarkerException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidMarkerException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<InvalidMarkerException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(InvalidMarkerException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("InvalidMarkerException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidMarkerException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a InvalidMarkerException,
+                _structure_ty: ::std::marker::PhantomData<&'__a InvalidMarkerException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("InvalidMarkerException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&InvalidMarkerException>,})
+        }
+    }
+}
+/// The request was rejected because an internal exception occurred. The request can be retried.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct KMSInternalException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for KMSInternalException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KMSInternalException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KMSInternalException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KMSInternalException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSInternalException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KMSInternalException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSInternalException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("KMSInternalException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for KMSInternalException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a KMSInternalException,
+                _structure_ty: ::std::marker::PhantomData<&'__a KMSInternalException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("KMSInternalException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&KMSInternalException>,})
+        }
+    }
+}
+/// The request was rejected because the state of the specified resource is not valid for this request.
+///
+/// For more information about how key state affects the use of a customer master key (CMK), go to [How Key State Affects the Use of a Customer Master Key](http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html) in the *AWS Key Management Service Developer Guide*.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct KMSInvalidStateException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for KMSInvalidStateException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KMSInvalidStateException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KMSInvalidStateException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<KMSInvalidStateException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSInvalidStateException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<KMSInvalidStateException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KMSInvalidStateException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("KMSInvalidStateException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for KMSInvalidStateException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a KMSInvalidStateException,
+                _structure_ty: ::std::marker::PhantomData<&'__a KMSInvalidStateException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("KMSInvalidStateException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&KMSInvalidStateException>,})
+        }
+    }
+}
+pub type KeyIdType = String;
+pub type KeyList = Vec<KeyListEntry>;
+/// Contains information about each entry in the key list.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct KeyListEntry {
+    /// ARN of the key.
+    pub key_arn: Option<ArnType>,
+    /// Unique identifier of the key.
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for KeyListEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KeyListEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyArn" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KeyListEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyListEntry, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyListEntry{key_arn: __field0, key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyListEntry, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyArn" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyListEntry{key_arn: __field0, key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["key_arn", "key_id"];
+            deserializer.visit_struct("KeyListEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for KeyListEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a KeyListEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a KeyListEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyArn" ,
+                                                    &self.value.key_arn ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("KeyListEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&KeyListEntry>,})
+        }
+    }
+}
+/// Contains metadata about a customer master key (CMK).
+///
+/// This data type is used as a response element for the CreateKey and DescribeKey operations.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct KeyMetadata {
+    /// The twelve-digit account ID of the AWS account that owns the key.
+    pub aws_account_id: Option<AWSAccountIdType>,
+    /// The Amazon Resource Name (ARN) of the key. For examples, see [AWS Key Management Service (AWS KMS)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kms) in the Example ARNs section of the *AWS General Reference*.
+    pub arn: Option<ArnType>,
+    /// The date and time when the key was created.
+    pub creation_date: Option<DateType>,
+    /// The date and time after which AWS KMS deletes the customer master key (CMK). This value is present only when `KeyState` is `PendingDeletion`, otherwise this value is null.
+    pub deletion_date: Option<DateType>,
+    /// The friendly description of the key.
+    pub description: Option<DescriptionType>,
+    /// Specifies whether the key is enabled. When `KeyState` is `Enabled` this value is true, otherwise it is false.
+    pub enabled: Option<BooleanType>,
+    /// The globally unique identifier for the key.
+    pub key_id: KeyIdType,
+    /// The state of the customer master key (CMK).
+    ///
+    /// For more information about how key state affects the use of a CMK, go to [How Key State Affects the Use of a Customer Master Key](http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html) in the *AWS Key Management Service Developer Guide*.
+    pub key_state: Option<KeyState>,
+    /// The cryptographic operations for which you can use the key. Currently the only allowed value is `ENCRYPT_DECRYPT`, which means you can use the key for the Encrypt and Decrypt operations.
+    pub key_usage: Option<KeyUsageType>,
+}
+impl ::serde::de::Deserialize for KeyMetadata {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KeyMetadata, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field {
+                __field0,
+                __field1,
+                __field2,
+                __field3,
+                __field4,
+                __field5,
+                __field6,
+                __field7,
+                __field8,
+            }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                5usize => { Ok(__Field::__field5) }
+                                6usize => { Ok(__Field::__field6) }
+                                7usize => { Ok(__Field::__field7) }
+                                8usize => { Ok(__Field::__field8) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AWSAccountId" => { Ok(__Field::__field0) }
+                                "Arn" => { Ok(__Field::__field1) }
+                                "CreationDate" => { Ok(__Field::__field2) }
+                                "DeletionDate" => { Ok(__Field::__field3) }
+                                "Description" => { Ok(__Field::__field4) }
+                                "Enabled" => { Ok(__Field::__field5) }
+                                "KeyId" => { Ok(__Field::__field6) }
+                                "KeyState" => { Ok(__Field::__field7) }
+                                "KeyUsage" => { Ok(__Field::__field8) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KeyMetadata;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyMetadata, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field5 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field6 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field7 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field8 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyMetadata{aws_account_id: __field0,
+                                       arn: __field1,
+                                       creation_date: __field2,
+                                       deletion_date: __field3,
+                                       description: __field4,
+                                       enabled: __field5,
+                                       key_id: __field6,
+                                       key_state: __field7,
+                                       key_usage: __field8,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyMetadata, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        let mut __field5 = None;
+                        let mut __field6 = None;
+                        let mut __field7 = None;
+                        let mut __field8 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field5 => {
+                                    __field5 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field6 => {
+                                    __field6 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field7 => {
+                                    __field7 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field8 => {
+                                    __field8 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AWSAccountId"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Arn" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "CreationDate"
+                                     )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "DeletionDate"
+                                     )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field ( "Description"
+                                     )),
+                            };
+                        let __field5 =
+                            match __field5 {
+                                Some(__field5) => __field5,
+                                None =>
+                                try!(visitor . missing_field ( "Enabled" )),
+                            };
+                        let __field6 =
+                            match __field6 {
+                                Some(__field6) => __field6,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field7 =
+                            match __field7 {
+                                Some(__field7) => __field7,
+                                None =>
+                                try!(visitor . missing_field ( "KeyState" )),
+                            };
+                        let __field8 =
+                            match __field8 {
+                                Some(__field8) => __field8,
+                                None =>
+                                try!(visitor . missing_field ( "KeyUsage" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyMetadata{aws_account_id: __field0,
+                                       arn: __field1,
+                                       creation_date: __field2,
+                                       deletion_date: __field3,
+                                       description: __field4,
+                                       enabled: __field5,
+                                       key_id: __field6,
+                                       key_state: __field7,
+                                       key_usage: __field8,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["aws_account_id", "arn", "creation_date", "deletion_date",
+                  "description", "enabled", "key_id", "key_state",
+                  "key_usage"];
+            deserializer.visit_struct("KeyMetadata", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for KeyMetadata {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a KeyMetadata,
+                _structure_ty: ::std::marker::PhantomData<&'__a KeyMetadata>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AWSAccountId" ,
+                                                    &self.value.aws_account_id
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Arn" , &self.value.arn ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CreationDate" ,
+                                                    &self.value.creation_date
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DeletionDate" ,
+                                                    &self.value.deletion_date
+                                                    , ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Description" ,
+                                                    &self.value.description ,
+                                                    ))));
+                            }
+                            5usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Enabled" ,
+                                                    &self.value.enabled ,
+                                                    ))));
+                            }
+                            6usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            7usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyState" ,
+                                                    &self.value.key_state ,
+                                                    ))));
+                            }
+                            8usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyUsage" ,
+                                                    &self.value.key_usage ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> {
+                    Some(0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1)
+                }
+            }
+            serializer.visit_struct("KeyMetadata",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&KeyMetadata>,})
+        }
+    }
+}
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub enum KeyState {
+    Enabled,
+    Disabled,
+    PendingDeletion,
+
+    /// A value that wasn't known when this client was generated.
+    Unknown(String),
+}
+impl KeyState {
+    /// The value as sent to and from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyState::Enabled => "Enabled",
+            KeyState::Disabled => "Disabled",
+            KeyState::PendingDeletion => "PendingDeletion",
+            KeyState::Unknown(ref value) => value,
+        }
+    }
+}
+impl Default for KeyState {
+    fn default() -> KeyState { KeyState::Unknown(String::new()) }
+}
+impl <'a> From<&'a str> for KeyState {
+    fn from(value: &'a str) -> KeyState {
+        match value {
+            "Enabled" => KeyState::Enabled,
+            "Disabled" => KeyState::Disabled,
+            "PendingDeletion" => KeyState::PendingDeletion,
+            _ => KeyState::Unknown(value.to_owned()),
+        }
+    }
+}
+impl ::std::fmt::Display for KeyState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f , "{}" , self . as_str (  ))
+    }
+}
+impl ::serde::Serialize for KeyState {
+    fn serialize<S>(&self, serializer: &mut S)
+     -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {
+        ::serde::Serialize::serialize(self.as_str(), serializer)
+    }
+}
+impl ::serde::Deserialize for KeyState {
+    fn deserialize<D>(deserializer: &mut D)
+     -> ::std::result::Result<KeyState, D::Error> where
+     D: ::serde::Deserializer {
+        let value: String =
+            try!(:: serde:: Deserialize:: deserialize ( deserializer ));
+        Ok(KeyState::from(&value[..]))
+    }
+}
+/// The request was rejected because the key was not available. The request can be retried.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct KeyUnavailableException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for KeyUnavailableException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<KeyUnavailableException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                KeyUnavailableException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyUnavailableException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyUnavailableException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<KeyUnavailableException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(KeyUnavailableException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("KeyUnavailableException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for KeyUnavailableException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a KeyUnavailableException,
+                _structure_ty: ::std::marker::PhantomData<&'__a KeyUnavailableException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("KeyUnavailableException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&KeyUnavailableException>,})
+        }
+    }
+}
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub enum KeyUsageType {
+    EncryptDecrypt,
+
+    /// A value that wasn't known when this client was generated.
+    Unknown(String),
+}
+impl KeyUsageType {
+    /// The value as sent to and from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyUsageType::EncryptDecrypt => "ENCRYPT_DECRYPT",
+            KeyUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+impl Default for KeyUsageType {
+    fn default() -> KeyUsageType { KeyUsageType::Unknown(String::new()) }
+}
+impl <'a> From<&'a str> for KeyUsageType {
+    fn from(value: &'a str) -> KeyUsageType {
+        match value {
+            "ENCRYPT_DECRYPT" => KeyUsageType::EncryptDecrypt,
+            _ => KeyUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+impl ::std::fmt::Display for KeyUsageType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f , "{}" , self . as_str (  ))
+    }
+}
+impl ::serde::Serialize for KeyUsageType {
+    fn serialize<S>(&self, serializer: &mut S)
+     -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {
+        ::serde::Serialize::serialize(self.as_str(), serializer)
+    }
+}
+impl ::serde::Deserialize for KeyUsageType {
+    fn deserialize<D>(deserializer: &mut D)
+     -> ::std::result::Result<KeyUsageType, D::Error> where
+     D: ::serde::Deserializer {
+        let value: String =
+            try!(:: serde:: Deserialize:: deserialize ( deserializer ));
+        Ok(KeyUsageType::from(&value[..]))
+    }
+}
+/// The request was rejected because a limit was exceeded. For more information, see [Limits](http://docs.aws.amazon.com/kms/latest/developerguide/limits.html) in the *AWS Key Management Service Developer Guide*.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct LimitExceededException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for LimitExceededException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<LimitExceededException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                LimitExceededException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<LimitExceededException, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(LimitExceededException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<LimitExceededException, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(LimitExceededException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("LimitExceededException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for LimitExceededException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a LimitExceededException,
+                _structure_ty: ::std::marker::PhantomData<&'__a LimitExceededException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("LimitExceededException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&LimitExceededException>,})
+        }
+    }
+}
+pub type LimitType = i32;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListAliasesRequest {
+    /// When paginating results, specify the maximum number of items to return in the response. If additional items exist beyond the number you specify, the `Truncated` element in the response is set to true.
+    ///
+    /// This value is optional. If you include a value, it must be between 1 and 100, inclusive. If you do not include a value, it defaults to 50.
+    pub limit: Option<LimitType>,
+    /// Use this parameter only when paginating results and only in a subsequent request after you've received a response with truncated results. Set it to the value of `NextMarker` from the response you just received.
+    pub marker: Option<MarkerType>,
+}
+impl ::serde::de::Deserialize for ListAliasesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListAliasesRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Limit" => { Ok(__Field::__field0) }
+                                "Marker" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListAliasesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListAliasesRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListAliasesRequest{limit: __field0,
+                                              marker: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListAliasesRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Limit" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Marker" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListAliasesRequest{limit: __field0,
+                                              marker: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["limit", "marker"];
+            deserializer.visit_struct("ListAliasesRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListAliasesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListAliasesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListAliasesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Limit" ,
+                                                    &self.value.limit , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Marker" ,
+                                                    &self.value.marker , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ListAliasesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListAliasesRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListAliasesResponse {
+    /// A list of key aliases in the user's account.
+    pub aliases: Option<AliasList>,
+    /// When `Truncated` is true, this value is present and contains the value to use for the `Marker` parameter in a subsequent pagination request.
+    pub next_marker: Option<MarkerType>,
+    /// A flag that indicates whether there are more items in the list. If your results were truncated, you can use the `Marker` parameter to make a subsequent pagination request to retrieve more items in the list.
+    pub truncated: Option<BooleanType>,
+}
+impl ::serde::de::Deserialize for ListAliasesResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListAliasesResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Aliases" => { Ok(__Field::__field0) }
+                                "NextMarker" => { Ok(__Field::__field1) }
+                                "Truncated" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListAliasesResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListAliasesResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListAliasesResponse{aliases: __field0,
+                                               next_marker: __field1,
+                                               truncated: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListAliasesResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Aliases" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "NextMarker"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Truncated" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListAliasesResponse{aliases: __field0,
+                                               next_marker: __field1,
+                                               truncated: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["aliases", "next_marker", "truncated"];
+            deserializer.visit_struct("ListAliasesResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListAliasesResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListAliasesResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListAliasesResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Aliases" ,
+                                                    &self.value.aliases ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NextMarker" ,
+                                                    &self.value.next_marker ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Truncated" ,
+                                                    &self.value.truncated ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListAliasesResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListAliasesResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListGrantsRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+    /// When paginating results, specify the maximum number of items to return in the response. If additional items exist beyond the number you specify, the `Truncated` element in the response is set to true.
+    ///
+    /// This value is optional. If you include a value, it must be between 1 and 100, inclusive. If you do not include a value, it defaults to 50.
+    pub limit: Option<LimitType>,
+    /// Use this parameter only when paginating results and only in a subsequent request after you've received a response with truncated results. Set it to the value of `NextMarker` from the response you just received.
+    pub marker: Option<MarkerType>,
+}
+impl ::serde::de::Deserialize for ListGrantsRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListGrantsRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "Limit" => { Ok(__Field::__field1) }
+                                "Marker" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListGrantsRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListGrantsRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListGrantsRequest{key_id: __field0,
+                                             limit: __field1,
+                                             marker: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListGrantsRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Limit" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Marker" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListGrantsRequest{key_id: __field0,
+                                             limit: __field1,
+                                             marker: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["key_id", "limit", "marker"];
+            deserializer.visit_struct("ListGrantsRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListGrantsRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListGrantsRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListGrantsRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Limit" ,
+                                                    &self.value.limit , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Marker" ,
+                                                    &self.value.marker , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListGrantsRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListGrantsRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListGrantsResponse {
+    /// A list of grants.
+    pub grants: Option<GrantList>,
+    /// When `Truncated` is true, this value is present and contains the value to use for the `Marker` parameter in a subsequent pagination request.
+    pub next_marker: Option<MarkerType>,
+    /// A flag that indicates whether there are more items in the list. If your results were truncated, you can use the `Marker` parameter to make a subsequent pagination request to retrieve more items in the list.
+    pub truncated: Option<BooleanType>,
+}
+impl ::serde::de::Deserialize for ListGrantsResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListGrantsResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Grants" => { Ok(__Field::__field0) }
+                                "NextMarker" => { Ok(__Field::__field1) }
+                                "Truncated" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListGrantsResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListGrantsResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListGrantsResponse{grants: __field0,
+                                              next_marker: __field1,
+                                              truncated: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListGrantsResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Grants" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "NextMarker"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Truncated" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListGrantsResponse{grants: __field0,
+                                              next_marker: __field1,
+                                              truncated: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["grants", "next_marker", "truncated"];
+            deserializer.visit_struct("ListGrantsResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListGrantsResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListGrantsResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListGrantsResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Grants" ,
+                                                    &self.value.grants , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NextMarker" ,
+                                                    &self.value.next_marker ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Truncated" ,
+                                                    &self.value.truncated ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListGrantsResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListGrantsResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListKeyPoliciesRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub key_id: KeyIdType,
+    /// When paginating results, specify the maximum number of items to return in the response. If additional items exist beyond the number you specify, the `Truncated` element in the response is set to true.
+    ///
+    /// This value is optional. If you include a value, it must be between 1 and 1000, inclusive. If you do not include a value, it defaults to 100.
+    ///
+    /// Currently only 1 policy can be attached to a key.
+    pub limit: Option<LimitType>,
+    /// Use this parameter only when paginating results and only in a subsequent request after you've received a response with truncated results. Set it to the value of `NextMarker` from the response you just received.
+    pub marker: Option<MarkerType>,
+}
+impl ::serde::de::Deserialize for ListKeyPoliciesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListKeyPoliciesRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "Limit" => { Ok(__Field::__field1) }
+                                "Marker" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListKeyPoliciesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeyPoliciesRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeyPoliciesRequest{key_id: __field0,
+                                                  limit: __field1,
+                                                  marker: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeyPoliciesRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Limit" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Marker" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeyPoliciesRequest{key_id: __field0,
+                                                  limit: __field1,
+                                                  marker: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["key_id", "limit", "marker"];
+            deserializer.visit_struct("ListKeyPoliciesRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListKeyPoliciesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListKeyPoliciesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListKeyPoliciesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Limit" ,
+                                                    &self.value.limit , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Marker" ,
+                                                    &self.value.marker , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListKeyPoliciesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListKeyPoliciesRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListKeyPoliciesResponse {
+    /// When `Truncated` is true, this value is present and contains the value to use for the `Marker` parameter in a subsequent pagination request.
+    pub next_marker: Option<MarkerType>,
+    /// A list of policy names. Currently, there is only one policy and it is named "Default".
+    pub policy_names: Option<PolicyNameList>,
+    /// A flag that indicates whether there are more items in the list. If your results were truncated, you can use the `Marker` parameter to make a subsequent pagination request to retrieve more items in the list.
+    pub truncated: Option<BooleanType>,
+}
+impl ::serde::de::Deserialize for ListKeyPoliciesResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListKeyPoliciesResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "NextMarker" => { Ok(__Field::__field0) }
+                                "PolicyNames" => { Ok(__Field::__field1) }
+                                "Truncated" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListKeyPoliciesResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeyPoliciesResponse, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeyPoliciesResponse{next_marker: __field0,
+                                                   policy_names: __field1,
+                                                   truncated: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeyPoliciesResponse, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "NextMarker"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "PolicyNames"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Truncated" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeyPoliciesResponse{next_marker: __field0,
+                                                   policy_names: __field1,
+                                                   truncated: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["next_marker", "policy_names", "truncated"];
+            deserializer.visit_struct("ListKeyPoliciesResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListKeyPoliciesResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListKeyPoliciesResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListKeyPoliciesResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NextMarker" ,
+                                                    &self.value.next_marker ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "PolicyNames" ,
+                                                    &self.value.policy_names ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Truncated" ,
+                                                    &self.value.truncated ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListKeyPoliciesResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListKeyPoliciesResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListKeysRequest {
+    /// When paginating results, specify the maximum number of items to return in the response. If additional items exist beyond the number you specify, the `Truncated` element in the response is set to true.
+    ///
+    /// This value is optional. If you include a value, it must be between 1 and 1000, inclusive. If you do not include a value, it defaults to 100.
+    pub limit: Option<LimitType>,
+    /// Use this parameter only when paginating results and only in a subsequent request after you've received a response with truncated results. Set it to the value of `NextMarker` from the response you just received.
+    pub marker: Option<MarkerType>,
+}
+impl ::serde::de::Deserialize for ListKeysRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListKeysRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Limit" => { Ok(__Field::__field0) }
+                                "Marker" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListKeysRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeysRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeysRequest{limit: __field0,
+                                           marker: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeysRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Limit" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Marker" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeysRequest{limit: __field0,
+                                           marker: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["limit", "marker"];
+            deserializer.visit_struct("ListKeysRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListKeysRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListKeysRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListKeysRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Limit" ,
+                                                    &self.value.limit , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Marker" ,
+                                                    &self.value.marker , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ListKeysRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListKeysRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListKeysResponse {
+    /// A list of keys.
+    pub keys: Option<KeyList>,
+    /// When `Truncated` is true, this value is present and contains the value to use for the `Marker` parameter in a subsequent pagination request.
+    pub next_marker: Option<MarkerType>,
+    /// A flag that indicates whether there are more items in the list. If your results were truncated, you can use the `Marker` parameter to make a subsequent pagination request to retrieve more items in the list.
+    pub truncated: Option<BooleanType>,
+}
+impl ::serde::de::Deserialize for ListKeysResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListKeysResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Keys" => { Ok(__Field::__field0) }
+                                "NextMarker" => { Ok(__Field::__field1) }
+                                "Truncated" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListKeysResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeysResponse, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeysResponse{keys: __field0,
+                                            next_marker: __field1,
+                                            truncated: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListKeysResponse, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Keys" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "NextMarker"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Truncated" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListKeysResponse{keys: __field0,
+                                            next_marker: __field1,
+                                            truncated: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["keys", "next_marker", "truncated"];
+            deserializer.visit_struct("ListKeysResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListKeysResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListKeysResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListKeysResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Keys" , &self.value.keys
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "NextMarker" ,
+                                                    &self.value.next_marker ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Truncated" ,
+                                                    &self.value.truncated ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListKeysResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListKeysResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ListRetirableGrantsRequest {
+    /// When paginating results, specify the maximum number of items to return in the response. If additional items exist beyond the number you specify, the `Truncated` element in the response is set to true.
+    ///
+    /// This value is optional. If you include a value, it must be between 1 and 100, inclusive. If you do not include a value, it defaults to 50.
+    pub limit: Option<LimitType>,
+    /// Use this parameter only when paginating results and only in a subsequent request after you've received a response with truncated results. Set it to the value of `NextMarker` from the response you just received.
+    pub marker: Option<MarkerType>,
+    /// The retiring principal for which to list grants.
+    ///
+    /// To specify the retiring principal, use the [Amazon Resource Name (ARN)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html) of an AWS principal. Valid AWS principals include AWS accounts (root), IAM users, federated users, and assumed role users. For examples of the ARN syntax for specifying a principal, go to [AWS Identity and Access Management (IAM)](http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-iam) in the Example ARNs section of the *Amazon Web Services General Reference*.
+    pub retiring_principal: PrincipalIdType,
+}
+impl ::serde::de::Deserialize for ListRetirableGrantsRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListRetirableGrantsRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Limit" => { Ok(__Field::__field0) }
+                                "Marker" => { Ok(__Field::__field1) }
+                                "RetiringPrincipal" => {
+                                    Ok(__Field::__field2)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListRetirableGrantsRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListRetirableGrantsRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListRetirableGrantsRequest{limit: __field0,
+                                                      marker: __field1,
+                                                      retiring_principal:
+                                                          __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListRetirableGrantsRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Limit" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Marker" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "RetiringPrincipal" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListRetirableGrantsRequest{limit: __field0,
+                                                      marker: __field1,
+                                                      retiring_principal:
+                                                          __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["limit", "marker", "retiring_principal"];
+            deserializer.visit_struct("ListRetirableGrantsRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListRetirableGrantsRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListRetirableGrantsRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListRetirableGrantsRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Limit" ,
+                                                    &self.value.limit , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Marker" ,
+                                                    &self.value.marker , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "RetiringPrincipal" ,
+                                                    &self.value.retiring_principal
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ListRetirableGrantsRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListRetirableGrantsRequest>,})
+        }
+    }
+}
+/// The request was rejected because the specified policy is not syntactically or semantically correct.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct MalformedPolicyDocumentException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for MalformedPolicyDocumentException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<MalformedPolicyDocumentException, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                MalformedPolicyDocumentException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<MalformedPolicyDocumentException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(MalformedPolicyDocumentException{message:
+                                                                __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<MalformedPolicyDocumentException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(MalformedPolicyDocumentException{message:
+                                                                __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("MalformedPolicyDocumentException",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for MalformedPolicyDocumentException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a MalformedPolicyDocumentException,
+                _structure_ty: ::std::marker::PhantomData<&'__a MalformedPolicyDocumentException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("MalformedPolicyDocumentException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&MalformedPolicyDocumentException>,})
+        }
+    }
+}
+pub type MarkerType = String;
+/// The request was rejected because the specified entity or resource could not be found.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct NotFoundException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for NotFoundException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<NotFoundException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                NotFoundException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<NotFoundException, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(NotFoundException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<NotFoundException, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(NotFoundException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("NotFoundException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for NotFoundException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a NotFoundException,
+                _structure_ty: ::std::marker::PhantomData<&'__a NotFoundException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("NotFoundException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&NotFoundException>,})
+        }
+    }
+}
+pub type NumberOfBytesType = i32;
+pub type PendingWindowInDaysType = i32;
+pub type PlaintextType = ::blob::Blob;
+pub type PolicyNameList = Vec<PolicyNameType>;
+pub type PolicyNameType = String;
+pub type PolicyType = String;
+pub type PrincipalIdType = String;
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct PutKeyPolicyRequest {
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+    /// The policy to attach to the key. This is required and delegates back to the account. The key is the root of trust. The policy size limit is 32 KiB (32768 bytes).
+    pub policy: PolicyType,
+    /// Name of the policy to be attached. Currently, the only supported name is "default".
+    pub policy_name: PolicyNameType,
+}
+impl ::serde::de::Deserialize for PutKeyPolicyRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<PutKeyPolicyRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "Policy" => { Ok(__Field::__field1) }
+                                "PolicyName" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                PutKeyPolicyRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<PutKeyPolicyRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(PutKeyPolicyRequest{key_id: __field0,
+                                               policy: __field1,
+                                               policy_name: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<PutKeyPolicyRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Policy" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "PolicyName"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(PutKeyPolicyRequest{key_id: __field0,
+                                               policy: __field1,
+                                               policy_name: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["key_id", "policy", "policy_name"];
+            deserializer.visit_struct("PutKeyPolicyRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for PutKeyPolicyRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a PutKeyPolicyRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a PutKeyPolicyRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Policy" ,
+                                                    &self.value.policy , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "PolicyName" ,
+                                                    &self.value.policy_name ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("PutKeyPolicyRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&PutKeyPolicyRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ReEncryptRequest {
+    /// Ciphertext of the data to re-encrypt.
+    pub ciphertext_blob: CiphertextType,
+    /// Encryption context to be used when the data is re-encrypted.
+    pub destination_encryption_context: Option<EncryptionContextType>,
+    /// A unique identifier for the customer master key used to re-encrypt the data. This value can be a globally unique identifier, a fully specified ARN to either an alias or a key, or an alias name prefixed by "alias/".
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Alias ARN Example - arn:aws:kms:us-east-1:123456789012:alias/MyAliasName
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    /// * Alias Name Example - alias/MyAliasName
+    pub destination_key_id: KeyIdType,
+    /// A list of grant tokens.
+    ///
+    /// For more information, go to [Grant Tokens](http://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token) in the *AWS Key Management Service Developer Guide*.
+    pub grant_tokens: Option<GrantTokenList>,
+    /// Encryption context used to encrypt and decrypt the data specified in the `CiphertextBlob` parameter.
+    pub source_encryption_context: Option<EncryptionContextType>,
+}
+impl ::serde::de::Deserialize for ReEncryptRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ReEncryptRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, __field4, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "DestinationEncryptionContext" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "DestinationKeyId" => {
+                                    Ok(__Field::__field2)
+                                }
+                                "GrantTokens" => { Ok(__Field::__field3) }
+                                "SourceEncryptionContext" => {
+                                    Ok(__Field::__field4)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ReEncryptRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReEncryptRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReEncryptRequest{ciphertext_blob: __field0,
+                                            destination_encryption_context:
+                                                __field1,
+                                            destination_key_id: __field2,
+                                            grant_tokens: __field3,
+                                            source_encryption_context:
+                                                __field4,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReEncryptRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "DestinationEncryptionContext" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "DestinationKeyId" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "GrantTokens"
+                                     )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "SourceEncryptionContext" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReEncryptRequest{ciphertext_blob: __field0,
+                                            destination_encryption_context:
+                                                __field1,
+                                            destination_key_id: __field2,
+                                            grant_tokens: __field3,
+                                            source_encryption_context:
+                                                __field4,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "destination_encryption_context",
+                  "destination_key_id", "grant_tokens",
+                  "source_encryption_context"];
+            deserializer.visit_struct("ReEncryptRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ReEncryptRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ReEncryptRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ReEncryptRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DestinationEncryptionContext"
+                                                    ,
+                                                    &self.value.destination_encryption_context
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DestinationKeyId" ,
+                                                    &self.value.destination_key_id
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantTokens" ,
+                                                    &self.value.grant_tokens ,
+                                                    ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "SourceEncryptionContext"
+                                                    ,
+                                                    &self.value.source_encryption_context
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ReEncryptRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ReEncryptRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ReEncryptResponse {
+    /// The re-encrypted data. If you are using the CLI, the value is Base64 encoded. Otherwise, it is not encoded.
+    pub ciphertext_blob: Option<CiphertextType>,
+    /// Unique identifier of the key used to re-encrypt the data.
+    pub key_id: Option<KeyIdType>,
+    /// Unique identifier of the key used to originally encrypt the data.
+    pub source_key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for ReEncryptResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ReEncryptResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "CiphertextBlob" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                "SourceKeyId" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ReEncryptResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReEncryptResponse, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReEncryptResponse{ciphertext_blob: __field0,
+                                             key_id: __field1,
+                                             source_key_id: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReEncryptResponse, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "CiphertextBlob" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "SourceKeyId"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReEncryptResponse{ciphertext_blob: __field0,
+                                             key_id: __field1,
+                                             source_key_id: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["ciphertext_blob", "key_id", "source_key_id"];
+            deserializer.visit_struct("ReEncryptResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ReEncryptResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ReEncryptResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ReEncryptResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "CiphertextBlob" ,
+                                                    &self.value.ciphertext_blob
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "SourceKeyId" ,
+                                                    &self.value.source_key_id
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ReEncryptResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ReEncryptResponse>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct RetireGrantRequest {
+    /// Unique identifier of the grant to be retired. The grant ID is returned by the `CreateGrant` function.
+    ///
+    /// * Grant ID Example - 0123456789012345678901234567890123456789012345678901234567890123
+    pub grant_id: Option<GrantIdType>,
+    /// Token that identifies the grant to be retired.
+    pub grant_token: Option<GrantTokenType>,
+    /// A unique identifier for the customer master key associated with the grant. This value can be a globally unique identifier or a fully specified ARN of the key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for RetireGrantRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<RetireGrantRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "GrantId" => { Ok(__Field::__field0) }
+                                "GrantToken" => { Ok(__Field::__field1) }
+                                "KeyId" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                RetireGrantRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RetireGrantRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RetireGrantRequest{grant_id: __field0,
+                                              grant_token: __field1,
+                                              key_id: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RetireGrantRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "GrantId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "GrantToken"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RetireGrantRequest{grant_id: __field0,
+                                              grant_token: __field1,
+                                              key_id: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["grant_id", "grant_token", "key_id"];
+            deserializer.visit_struct("RetireGrantRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for RetireGrantRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a RetireGrantRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a RetireGrantRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantId" ,
+                                                    &self.value.grant_id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantToken" ,
+                                                    &self.value.grant_token ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("RetireGrantRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&RetireGrantRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct RevokeGrantRequest {
+    /// Identifier of the grant to be revoked.
+    pub grant_id: GrantIdType,
+    /// A unique identifier for the customer master key associated with the grant. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for RevokeGrantRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<RevokeGrantRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "GrantId" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                RevokeGrantRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RevokeGrantRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RevokeGrantRequest{grant_id: __field0,
+                                              key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RevokeGrantRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "GrantId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RevokeGrantRequest{grant_id: __field0,
+                                              key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["grant_id", "key_id"];
+            deserializer.visit_struct("RevokeGrantRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for RevokeGrantRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a RevokeGrantRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a RevokeGrantRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "GrantId" ,
+                                                    &self.value.grant_id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("RevokeGrantRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&RevokeGrantRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ScheduleKeyDeletionRequest {
+    /// The unique identifier for the customer master key (CMK) to delete.
+    ///
+    /// To specify this value, use the unique key ID or the Amazon Resource Name (ARN) of the CMK. Examples:
+    ///
+    /// * Unique key ID: 1234abcd-12ab-34cd-56ef-1234567890ab
+    /// * Key ARN: arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab
+    ///
+    /// To obtain the unique key ID and key ARN for a given CMK, use ListKeys or DescribeKey.
+    pub key_id: KeyIdType,
+    /// The waiting period, specified in number of days. After the waiting period ends, AWS KMS deletes the customer master key (CMK).
+    ///
+    /// This value is optional. If you include a value, it must be between 7 and 30, inclusive. If you do not include a value, it defaults to 30.
+    pub pending_window_in_days: Option<PendingWindowInDaysType>,
+}
+impl ::serde::de::Deserialize for ScheduleKeyDeletionRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ScheduleKeyDeletionRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "KeyId" => { Ok(__Field::__field0) }
+                                "PendingWindowInDays" => {
+                                    Ok(__Field::__field1)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ScheduleKeyDeletionRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ScheduleKeyDeletionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ScheduleKeyDeletionRequest{key_id: __field0,
+                                                      pending_window_in_days:
+                                                          __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ScheduleKeyDeletionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "PendingWindowInDays" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ScheduleKeyDeletionRequest{key_id: __field0,
+                                                      pending_window_in_days:
+                                                          __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["key_id", "pending_window_in_days"];
+            deserializer.visit_struct("ScheduleKeyDeletionRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ScheduleKeyDeletionRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ScheduleKeyDeletionRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ScheduleKeyDeletionRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "PendingWindowInDays" ,
+                                                    &self.value.pending_window_in_days
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ScheduleKeyDeletionRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ScheduleKeyDeletionRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct ScheduleKeyDeletionResponse {
+    /// The date and time after which AWS KMS deletes the customer master key (CMK).
+    pub deletion_date: Option<DateType>,
+    /// The unique identifier of the customer master key (CMK) for which deletion is scheduled.
+    pub key_id: Option<KeyIdType>,
+}
+impl ::serde::de::Deserialize for ScheduleKeyDeletionResponse {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ScheduleKeyDeletionResponse, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "DeletionDate" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ScheduleKeyDeletionResponse;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ScheduleKeyDeletionResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ScheduleKeyDeletionResponse{deletion_date:
+                                                           __field0,
+                                                       key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ScheduleKeyDeletionResponse,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "DeletionDate"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ScheduleKeyDeletionResponse{deletion_date:
+                                                           __field0,
+                                                       key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["deletion_date", "key_id"];
+            deserializer.visit_struct("ScheduleKeyDeletionResponse", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ScheduleKeyDeletionResponse {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ScheduleKeyDeletionResponse,
+                _structure_ty: ::std::marker::PhantomData<&'__a ScheduleKeyDeletionResponse>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DeletionDate" ,
+                                                    &self.value.deletion_date
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ScheduleKeyDeletionResponse",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ScheduleKeyDeletionResponse>,})
+        }
+    }
+}
+/// The request was rejected because a specified parameter is not supported.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct UnsupportedOperationException {
+    pub message: Option<ErrorMessageType>,
+}
+impl ::serde::de::Deserialize for UnsupportedOperationException {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<UnsupportedOperationException, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "message" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                UnsupportedOperationException;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<UnsupportedOperationException,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UnsupportedOperationException{message: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<UnsupportedOperationException,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "message" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UnsupportedOperationException{message: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["message"];
+            deserializer.visit_struct("UnsupportedOperationException", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for UnsupportedOperationException {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a UnsupportedOperationException,
+                _structure_ty: ::std::marker::PhantomData<&'__a UnsupportedOperationException>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("UnsupportedOperationException",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&UnsupportedOperationException>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct UpdateAliasRequest {
+    /// String that contains the name of the alias to be modified. The name must start with the word "alias" followed by a forward slash (alias/). Aliases that begin with "alias/aws" are reserved.
+    pub alias_name: AliasNameType,
+    /// Unique identifier of the customer master key to be mapped to the alias. This value can be a globally unique identifier or the fully specified ARN of a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    ///
+    /// You can call ListAliases to verify that the alias is mapped to the correct `TargetKeyId`.
+    pub target_key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for UpdateAliasRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<UpdateAliasRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AliasName" => { Ok(__Field::__field0) }
+                                "TargetKeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                UpdateAliasRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<UpdateAliasRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UpdateAliasRequest{alias_name: __field0,
+                                              target_key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<UpdateAliasRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AliasName" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "TargetKeyId"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UpdateAliasRequest{alias_name: __field0,
+                                              target_key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["alias_name", "target_key_id"];
+            deserializer.visit_struct("UpdateAliasRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for UpdateAliasRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a UpdateAliasRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a UpdateAliasRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AliasName" ,
+                                                    &self.value.alias_name ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "TargetKeyId" ,
+                                                    &self.value.target_key_id
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("UpdateAliasRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&UpdateAliasRequest>,})
+        }
+    }
+}
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct UpdateKeyDescriptionRequest {
+    /// New description for the key.
+    pub description: DescriptionType,
+    /// A unique identifier for the customer master key. This value can be a globally unique identifier or the fully specified ARN to a key.
+    ///
+    /// * Key ARN Example - arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012
+    /// * Globally Unique Key ID Example - 12345678-1234-1234-1234-123456789012
+    pub key_id: KeyIdType,
+}
+impl ::serde::de::Deserialize for UpdateKeyDescriptionRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<UpdateKeyDescriptionRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Description" => { Ok(__Field::__field0) }
+                                "KeyId" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                UpdateKeyDescriptionRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<UpdateKeyDescriptionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UpdateKeyDescriptionRequest{description: __field0,
+                                                       key_id: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<UpdateKeyDescriptionRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Description"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "KeyId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(UpdateKeyDescriptionRequest{description: __field0,
+                                                       key_id: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["description", "key_id"];
+            deserializer.visit_struct("UpdateKeyDescriptionRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for UpdateKeyDescriptionRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a UpdateKeyDescriptionRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a UpdateKeyDescriptionRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Description" ,
+                                                    &self.value.description ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "KeyId" ,
+                                                    &self.value.key_id , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("UpdateKeyDescriptionRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&UpdateKeyDescriptionRequest>,})
+        }
+    }
+}
+impl CancelKeyDeletionRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CancelKeyDeletionRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CancelKeyDeletionRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl CreateAliasRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.alias_name;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateAliasRequest.AliasName is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateAliasRequest.AliasName is longer than the maximum length of 256"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:^[a-zA-Z0-9:/_-]+$)$" ) .
+                         unwrap (  ) ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("CreateAliasRequest.AliasName must match ^(?:^[a-zA-Z0-9:/_-]+$)$"));
+            }
+        }
+        {
+            let value = &self.target_key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateAliasRequest.TargetKeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateAliasRequest.TargetKeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl CreateGrantRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.constraints {
+            try!(value . validate (  ));
+        }
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("CreateGrantRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("CreateGrantRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("CreateGrantRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        {
+            let value = &self.grantee_principal;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateGrantRequest.GranteePrincipal is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateGrantRequest.GranteePrincipal is longer than the maximum length of 256"));
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateGrantRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateGrantRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.name {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateGrantRequest.Name is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateGrantRequest.Name is longer than the maximum length of 256"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:^[a-zA-Z0-9:/_-]+$)$" ) .
+                         unwrap (  ) ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("CreateGrantRequest.Name must match ^(?:^[a-zA-Z0-9:/_-]+$)$"));
+            }
+        }
+        if let Some(ref value) = self.retiring_principal {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateGrantRequest.RetiringPrincipal is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("CreateGrantRequest.RetiringPrincipal is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl CreateKeyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.description {
+            if value.chars().count() > 8192 {
+                return Err(AWSError::new("CreateKeyRequest.Description is longer than the maximum length of 8192"));
+            }
+        }
+        if let Some(ref value) = self.policy {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("CreateKeyRequest.Policy is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 131072 {
+                return Err(AWSError::new("CreateKeyRequest.Policy is longer than the maximum length of 131072"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DecryptRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.ciphertext_blob;
+            if value.len() < 1 {
+                return Err(AWSError::new("DecryptRequest.CiphertextBlob is shorter than the minimum length of 1"));
+            }
+            if value.len() > 6144 {
+                return Err(AWSError::new("DecryptRequest.CiphertextBlob is longer than the maximum length of 6144"));
+            }
+        }
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("DecryptRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("DecryptRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("DecryptRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+impl DeleteAliasRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.alias_name;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("DeleteAliasRequest.AliasName is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("DeleteAliasRequest.AliasName is longer than the maximum length of 256"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:^[a-zA-Z0-9:/_-]+$)$" ) .
+                         unwrap (  ) ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("DeleteAliasRequest.AliasName must match ^(?:^[a-zA-Z0-9:/_-]+$)$"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DescribeKeyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("DescribeKeyRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("DescribeKeyRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("DescribeKeyRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("DescribeKeyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("DescribeKeyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DisableKeyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("DisableKeyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("DisableKeyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DisableKeyRotationRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("DisableKeyRotationRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("DisableKeyRotationRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl EnableKeyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("EnableKeyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("EnableKeyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl EnableKeyRotationRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("EnableKeyRotationRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("EnableKeyRotationRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl EncryptRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("EncryptRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("EncryptRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("EncryptRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("EncryptRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("EncryptRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        {
+            let value = &self.plaintext;
+            if value.len() < 1 {
+                return Err(AWSError::new("EncryptRequest.Plaintext is shorter than the minimum length of 1"));
+            }
+            if value.len() > 4096 {
+                return Err(AWSError::new("EncryptRequest.Plaintext is longer than the maximum length of 4096"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GenerateDataKeyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("GenerateDataKeyRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("GenerateDataKeyRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("GenerateDataKeyRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("GenerateDataKeyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("GenerateDataKeyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.number_of_bytes {
+            if *value < 1 {
+                return Err(AWSError::new("GenerateDataKeyRequest.NumberOfBytes must be at least 1"));
+            }
+            if *value > 1024 {
+                return Err(AWSError::new("GenerateDataKeyRequest.NumberOfBytes must be at most 1024"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GenerateDataKeyWithoutPlaintextRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.number_of_bytes {
+            if *value < 1 {
+                return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.NumberOfBytes must be at least 1"));
+            }
+            if *value > 1024 {
+                return Err(AWSError::new("GenerateDataKeyWithoutPlaintextRequest.NumberOfBytes must be at most 1024"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GenerateRandomRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.number_of_bytes {
+            if *value < 1 {
+                return Err(AWSError::new("GenerateRandomRequest.NumberOfBytes must be at least 1"));
+            }
+            if *value > 1024 {
+                return Err(AWSError::new("GenerateRandomRequest.NumberOfBytes must be at most 1024"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GetKeyPolicyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("GetKeyPolicyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("GetKeyPolicyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        {
+            let value = &self.policy_name;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("GetKeyPolicyRequest.PolicyName is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 128 {
+                return Err(AWSError::new("GetKeyPolicyRequest.PolicyName is longer than the maximum length of 128"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:[\\w]+)$" ) . unwrap (  )
+                         ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("GetKeyPolicyRequest.PolicyName must match ^(?:[\\w]+)$"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GetKeyRotationStatusRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("GetKeyRotationStatusRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("GetKeyRotationStatusRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GrantConstraints {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> { Ok(()) }
+}
+impl ListAliasesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.limit {
+            if *value < 1 {
+                return Err(AWSError::new("ListAliasesRequest.Limit must be at least 1"));
+            }
+            if *value > 1000 {
+                return Err(AWSError::new("ListAliasesRequest.Limit must be at most 1000"));
+            }
+        }
+        if let Some(ref value) = self.marker {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListAliasesRequest.Marker is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 320 {
+                return Err(AWSError::new("ListAliasesRequest.Marker is longer than the maximum length of 320"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListGrantsRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListGrantsRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("ListGrantsRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.limit {
+            if *value < 1 {
+                return Err(AWSError::new("ListGrantsRequest.Limit must be at least 1"));
+            }
+            if *value > 1000 {
+                return Err(AWSError::new("ListGrantsRequest.Limit must be at most 1000"));
+            }
+        }
+        if let Some(ref value) = self.marker {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListGrantsRequest.Marker is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 320 {
+                return Err(AWSError::new("ListGrantsRequest.Marker is longer than the maximum length of 320"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListKeyPoliciesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.limit {
+            if *value < 1 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.Limit must be at least 1"));
+            }
+            if *value > 1000 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.Limit must be at most 1000"));
+            }
+        }
+        if let Some(ref value) = self.marker {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.Marker is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 320 {
+                return Err(AWSError::new("ListKeyPoliciesRequest.Marker is longer than the maximum length of 320"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListKeysRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.limit {
+            if *value < 1 {
+                return Err(AWSError::new("ListKeysRequest.Limit must be at least 1"));
+            }
+            if *value > 1000 {
+                return Err(AWSError::new("ListKeysRequest.Limit must be at most 1000"));
+            }
+        }
+        if let Some(ref value) = self.marker {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListKeysRequest.Marker is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 320 {
+                return Err(AWSError::new("ListKeysRequest.Marker is longer than the maximum length of 320"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListRetirableGrantsRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.limit {
+            if *value < 1 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.Limit must be at least 1"));
+            }
+            if *value > 1000 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.Limit must be at most 1000"));
+            }
+        }
+        if let Some(ref value) = self.marker {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.Marker is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 320 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.Marker is longer than the maximum length of 320"));
+            }
+        }
+        {
+            let value = &self.retiring_principal;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.RetiringPrincipal is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("ListRetirableGrantsRequest.RetiringPrincipal is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl PutKeyPolicyRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("PutKeyPolicyRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("PutKeyPolicyRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        {
+            let value = &self.policy;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("PutKeyPolicyRequest.Policy is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 131072 {
+                return Err(AWSError::new("PutKeyPolicyRequest.Policy is longer than the maximum length of 131072"));
+            }
+        }
+        {
+            let value = &self.policy_name;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("PutKeyPolicyRequest.PolicyName is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 128 {
+                return Err(AWSError::new("PutKeyPolicyRequest.PolicyName is longer than the maximum length of 128"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:[\\w]+)$" ) . unwrap (  )
+                         ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("PutKeyPolicyRequest.PolicyName must match ^(?:[\\w]+)$"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ReEncryptRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.ciphertext_blob;
+            if value.len() < 1 {
+                return Err(AWSError::new("ReEncryptRequest.CiphertextBlob is shorter than the minimum length of 1"));
+            }
+            if value.len() > 6144 {
+                return Err(AWSError::new("ReEncryptRequest.CiphertextBlob is longer than the maximum length of 6144"));
+            }
+        }
+        {
+            let value = &self.destination_key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ReEncryptRequest.DestinationKeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("ReEncryptRequest.DestinationKeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.grant_tokens {
+            if value.len() > 10 {
+                return Err(AWSError::new("ReEncryptRequest.GrantTokens is longer than the maximum length of 10"));
+            }
+            for item in value.iter() {
+                if item.chars().count() < 1 {
+                    return Err(AWSError::new("ReEncryptRequest.GrantTokens is shorter than the minimum length of 1"));
+                }
+                if item.chars().count() > 8192 {
+                    return Err(AWSError::new("ReEncryptRequest.GrantTokens is longer than the maximum length of 8192"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+impl RetireGrantRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.grant_id {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("RetireGrantRequest.GrantId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 128 {
+                return Err(AWSError::new("RetireGrantRequest.GrantId is longer than the maximum length of 128"));
+            }
+        }
+        if let Some(ref value) = self.grant_token {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("RetireGrantRequest.GrantToken is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 8192 {
+                return Err(AWSError::new("RetireGrantRequest.GrantToken is longer than the maximum length of 8192"));
+            }
+        }
+        if let Some(ref value) = self.key_id {
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("RetireGrantRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("RetireGrantRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl RevokeGrantRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.grant_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("RevokeGrantRequest.GrantId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 128 {
+                return Err(AWSError::new("RevokeGrantRequest.GrantId is longer than the maximum length of 128"));
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("RevokeGrantRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("RevokeGrantRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ScheduleKeyDeletionRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("ScheduleKeyDeletionRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("ScheduleKeyDeletionRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        if let Some(ref value) = self.pending_window_in_days {
+            if *value < 1 {
+                return Err(AWSError::new("ScheduleKeyDeletionRequest.PendingWindowInDays must be at least 1"));
+            }
+            if *value > 365 {
+                return Err(AWSError::new("ScheduleKeyDeletionRequest.PendingWindowInDays must be at most 365"));
+            }
+        }
+        Ok(())
+    }
+}
+impl UpdateAliasRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.alias_name;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("UpdateAliasRequest.AliasName is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("UpdateAliasRequest.AliasName is longer than the maximum length of 256"));
+            }
+            lazy_static!{static ref VALUE_PATTERN : :: regex:: Regex = ::
+                         regex:: Regex:: new ( "^(?:^[a-zA-Z0-9:/_-]+$)$" ) .
+                         unwrap (  ) ;
+                        }
+            if !VALUE_PATTERN.is_match(value) {
+                return Err(AWSError::new("UpdateAliasRequest.AliasName must match ^(?:^[a-zA-Z0-9:/_-]+$)$"));
+            }
+        }
+        {
+            let value = &self.target_key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("UpdateAliasRequest.TargetKeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("UpdateAliasRequest.TargetKeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl UpdateKeyDescriptionRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.description;
+            if value.chars().count() > 8192 {
+                return Err(AWSError::new("UpdateKeyDescriptionRequest.Description is longer than the maximum length of 8192"));
+            }
+        }
+        {
+            let value = &self.key_id;
+            if value.chars().count() < 1 {
+                return Err(AWSError::new("UpdateKeyDescriptionRequest.KeyId is shorter than the minimum length of 1"));
+            }
+            if value.chars().count() > 256 {
+                return Err(AWSError::new("UpdateKeyDescriptionRequest.KeyId is longer than the maximum length of 256"));
+            }
+        }
+        Ok(())
+    }
+}
+impl CancelKeyDeletionRequest {
+    /// A `CancelKeyDeletionRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> CancelKeyDeletionRequest {
+        CancelKeyDeletionRequest{key_id: key_id.into(),}
+    }
+}
+impl CreateAliasRequest {
+    /// A `CreateAliasRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<AliasNameType>,
+               V1: Into<KeyIdType>>(alias_name: V0, target_key_id: V1)
+     -> CreateAliasRequest {
+        CreateAliasRequest{alias_name: alias_name.into(),
+                           target_key_id: target_key_id.into(),}
+    }
+}
+impl CreateGrantRequest {
+    /// A `CreateGrantRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<PrincipalIdType>,
+               V1: Into<KeyIdType>>(grantee_principal: V0, key_id: V1)
+     -> CreateGrantRequest {
+        CreateGrantRequest{grantee_principal: grantee_principal.into(),
+                           key_id: key_id.into(), ..Default::default()}
+    }
+    /// Sets `Constraints`.
+    pub fn with_constraints(mut self, value: GrantConstraints)
+     -> CreateGrantRequest {
+        self.constraints = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> CreateGrantRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+    /// Sets `Name`.
+    pub fn with_name<V: Into<GrantNameType>>(mut self, value: V)
+     -> CreateGrantRequest {
+        self.name = Some(value.into());
+        self
+    }
+    /// Sets `Operations`.
+    pub fn with_operations(mut self, value: GrantOperationList)
+     -> CreateGrantRequest {
+        self.operations = Some(value);
+        self
+    }
+    /// Sets `RetiringPrincipal`.
+    pub fn with_retiring_principal<V: Into<PrincipalIdType>>(mut self,
+                                                             value: V)
+     -> CreateGrantRequest {
+        self.retiring_principal = Some(value.into());
+        self
+    }
+}
+impl CreateKeyRequest {
+    /// A `CreateKeyRequest` with the given required members and no optional ones.
+    pub fn new() -> CreateKeyRequest { Default::default() }
+    /// Sets `Description`.
+    pub fn with_description<V: Into<DescriptionType>>(mut self, value: V)
+     -> CreateKeyRequest {
+        self.description = Some(value.into());
+        self
+    }
+    /// Sets `KeyUsage`.
+    pub fn with_key_usage<V: Into<KeyUsageType>>(mut self, value: V)
+     -> CreateKeyRequest {
+        self.key_usage = Some(value.into());
+        self
+    }
+    /// Sets `Policy`.
+    pub fn with_policy<V: Into<PolicyType>>(mut self, value: V)
+     -> CreateKeyRequest {
+        self.policy = Some(value.into());
+        self
+    }
+}
+impl DecryptRequest {
+    /// A `DecryptRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<CiphertextType>>(ciphertext_blob: V0)
+     -> DecryptRequest {
+        DecryptRequest{ciphertext_blob:
+                           ciphertext_blob.into(), ..Default::default()}
+    }
+    /// Sets `EncryptionContext`.
+    pub fn with_encryption_context(mut self, value: EncryptionContextType)
+     -> DecryptRequest {
+        self.encryption_context = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> DecryptRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+}
+impl DeleteAliasRequest {
+    /// A `DeleteAliasRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<AliasNameType>>(alias_name: V0)
+     -> DeleteAliasRequest {
+        DeleteAliasRequest{alias_name: alias_name.into(),}
+    }
+}
+impl DescribeKeyRequest {
+    /// A `DescribeKeyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> DescribeKeyRequest {
+        DescribeKeyRequest{key_id: key_id.into(), ..Default::default()}
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> DescribeKeyRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+}
+impl DisableKeyRequest {
+    /// A `DisableKeyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> DisableKeyRequest {
+        DisableKeyRequest{key_id: key_id.into(),}
+    }
+}
+impl DisableKeyRotationRequest {
+    /// A `DisableKeyRotationRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> DisableKeyRotationRequest {
+        DisableKeyRotationRequest{key_id: key_id.into(),}
+    }
+}
+impl EnableKeyRequest {
+    /// A `EnableKeyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> EnableKeyRequest {
+        EnableKeyRequest{key_id: key_id.into(),}
+    }
+}
+impl EnableKeyRotationRequest {
+    /// A `EnableKeyRotationRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> EnableKeyRotationRequest {
+        EnableKeyRotationRequest{key_id: key_id.into(),}
+    }
+}
+impl EncryptRequest {
+    /// A `EncryptRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>,
+               V1: Into<PlaintextType>>(key_id: V0, plaintext: V1)
+     -> EncryptRequest {
+        EncryptRequest{key_id: key_id.into(),
+                       plaintext: plaintext.into(), ..Default::default()}
+    }
+    /// Sets `EncryptionContext`.
+    pub fn with_encryption_context(mut self, value: EncryptionContextType)
+     -> EncryptRequest {
+        self.encryption_context = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> EncryptRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+}
+impl GenerateDataKeyRequest {
+    /// A `GenerateDataKeyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> GenerateDataKeyRequest {
+        GenerateDataKeyRequest{key_id: key_id.into(), ..Default::default()}
+    }
+    /// Sets `EncryptionContext`.
+    pub fn with_encryption_context(mut self, value: EncryptionContextType)
+     -> GenerateDataKeyRequest {
+        self.encryption_context = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> GenerateDataKeyRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+    /// Sets `KeySpec`.
+    pub fn with_key_spec<V: Into<DataKeySpec>>(mut self, value: V)
+     -> GenerateDataKeyRequest {
+        self.key_spec = Some(value.into());
+        self
+    }
+    /// Sets `NumberOfBytes`.
+    pub fn with_number_of_bytes(mut self, value: NumberOfBytesType)
+     -> GenerateDataKeyRequest {
+        self.number_of_bytes = Some(value);
+        self
+    }
+}
+impl GenerateDataKeyWithoutPlaintextRequest {
+    /// A `GenerateDataKeyWithoutPlaintextRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0)
+     -> GenerateDataKeyWithoutPlaintextRequest {
+        GenerateDataKeyWithoutPlaintextRequest{key_id:
+                                                   key_id.into(),
+                                                                    ..Default::default()}
+    }
+    /// Sets `EncryptionContext`.
+    pub fn with_encryption_context(mut self, value: EncryptionContextType)
+     -> GenerateDataKeyWithoutPlaintextRequest {
+        self.encryption_context = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> GenerateDataKeyWithoutPlaintextRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+    /// Sets `KeySpec`.
+    pub fn with_key_spec<V: Into<DataKeySpec>>(mut self, value: V)
+     -> GenerateDataKeyWithoutPlaintextRequest {
+        self.key_spec = Some(value.into());
+        self
+    }
+    /// Sets `NumberOfBytes`.
+    pub fn with_number_of_bytes(mut self, value: NumberOfBytesType)
+     -> GenerateDataKeyWithoutPlaintextRequest {
+        self.number_of_bytes = Some(value);
+        self
+    }
+}
+impl GenerateRandomRequest {
+    /// A `GenerateRandomRequest` with the given required members and no optional ones.
+    pub fn new() -> GenerateRandomRequest { Default::default() }
+    /// Sets `NumberOfBytes`.
+    pub fn with_number_of_bytes(mut self, value: NumberOfBytesType)
+     -> GenerateRandomRequest {
+        self.number_of_bytes = Some(value);
+        self
+    }
+}
+impl GetKeyPolicyRequest {
+    /// A `GetKeyPolicyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>,
+               V1: Into<PolicyNameType>>(key_id: V0, policy_name: V1)
+     -> GetKeyPolicyRequest {
+        GetKeyPolicyRequest{key_id: key_id.into(),
+                            policy_name: policy_name.into(),}
+    }
+}
+impl GetKeyRotationStatusRequest {
+    /// A `GetKeyRotationStatusRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0)
+     -> GetKeyRotationStatusRequest {
+        GetKeyRotationStatusRequest{key_id: key_id.into(),}
+    }
+}
+impl GrantConstraints {
+    /// A `GrantConstraints` with the given required members and no optional ones.
+    pub fn new() -> GrantConstraints { Default::default() }
+    /// Sets `EncryptionContextEquals`.
+    pub fn with_encryption_context_equals(mut self,
+                                          value: EncryptionContextType)
+     -> GrantConstraints {
+        self.encryption_context_equals = Some(value);
+        self
+    }
+    /// Sets `EncryptionContextSubset`.
+    pub fn with_encryption_context_subset(mut self,
+                                          value: EncryptionContextType)
+     -> GrantConstraints {
+        self.encryption_context_subset = Some(value);
+        self
+    }
+}
+impl ListAliasesRequest {
+    /// A `ListAliasesRequest` with the given required members and no optional ones.
+    pub fn new() -> ListAliasesRequest { Default::default() }
+    /// Sets `Limit`.
+    pub fn with_limit(mut self, value: LimitType) -> ListAliasesRequest {
+        self.limit = Some(value);
+        self
+    }
+    /// Sets `Marker`.
+    pub fn with_marker<V: Into<MarkerType>>(mut self, value: V)
+     -> ListAliasesRequest {
+        self.marker = Some(value.into());
+        self
+    }
+}
+impl ListGrantsRequest {
+    /// A `ListGrantsRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> ListGrantsRequest {
+        ListGrantsRequest{key_id: key_id.into(), ..Default::default()}
+    }
+    /// Sets `Limit`.
+    pub fn with_limit(mut self, value: LimitType) -> ListGrantsRequest {
+        self.limit = Some(value);
+        self
+    }
+    /// Sets `Marker`.
+    pub fn with_marker<V: Into<MarkerType>>(mut self, value: V)
+     -> ListGrantsRequest {
+        self.marker = Some(value.into());
+        self
+    }
+}
+impl ListKeyPoliciesRequest {
+    /// A `ListKeyPoliciesRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0) -> ListKeyPoliciesRequest {
+        ListKeyPoliciesRequest{key_id: key_id.into(), ..Default::default()}
+    }
+    /// Sets `Limit`.
+    pub fn with_limit(mut self, value: LimitType) -> ListKeyPoliciesRequest {
+        self.limit = Some(value);
+        self
+    }
+    /// Sets `Marker`.
+    pub fn with_marker<V: Into<MarkerType>>(mut self, value: V)
+     -> ListKeyPoliciesRequest {
+        self.marker = Some(value.into());
+        self
+    }
+}
+impl ListKeysRequest {
+    /// A `ListKeysRequest` with the given required members and no optional ones.
+    pub fn new() -> ListKeysRequest { Default::default() }
+    /// Sets `Limit`.
+    pub fn with_limit(mut self, value: LimitType) -> ListKeysRequest {
+        self.limit = Some(value);
+        self
+    }
+    /// Sets `Marker`.
+    pub fn with_marker<V: Into<MarkerType>>(mut self, value: V)
+     -> ListKeysRequest {
+        self.marker = Some(value.into());
+        self
+    }
+}
+impl ListRetirableGrantsRequest {
+    /// A `ListRetirableGrantsRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<PrincipalIdType>>(retiring_principal: V0)
+     -> ListRetirableGrantsRequest {
+        ListRetirableGrantsRequest{retiring_principal:
+                                       retiring_principal.into(),
+                                                                    ..Default::default()}
+    }
+    /// Sets `Limit`.
+    pub fn with_limit(mut self, value: LimitType)
+     -> ListRetirableGrantsRequest {
+        self.limit = Some(value);
+        self
+    }
+    /// Sets `Marker`.
+    pub fn with_marker<V: Into<MarkerType>>(mut self, value: V)
+     -> ListRetirableGrantsRequest {
+        self.marker = Some(value.into());
+        self
+    }
+}
+impl PutKeyPolicyRequest {
+    /// A `PutKeyPolicyRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>, V1: Into<PolicyType>,
+               V2: Into<PolicyNameType>>(key_id: V0, policy: V1,
+                                         policy_name: V2)
+     -> PutKeyPolicyRequest {
+        PutKeyPolicyRequest{key_id: key_id.into(),
+                            policy: policy.into(),
+                            policy_name: policy_name.into(),}
+    }
+}
+impl ReEncryptRequest {
+    /// A `ReEncryptRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<CiphertextType>,
+               V1: Into<KeyIdType>>(ciphertext_blob: V0,
+                                    destination_key_id: V1)
+     -> ReEncryptRequest {
+        ReEncryptRequest{ciphertext_blob: ciphertext_blob.into(),
+                         destination_key_id:
+                             destination_key_id.into(), ..Default::default()}
+    }
+    /// Sets `DestinationEncryptionContext`.
+    pub fn with_destination_encryption_context(mut self,
+                                               value: EncryptionContextType)
+     -> ReEncryptRequest {
+        self.destination_encryption_context = Some(value);
+        self
+    }
+    /// Sets `GrantTokens`.
+    pub fn with_grant_tokens(mut self, value: GrantTokenList)
+     -> ReEncryptRequest {
+        self.grant_tokens = Some(value);
+        self
+    }
+    /// Sets `SourceEncryptionContext`.
+    pub fn with_source_encryption_context(mut self,
+                                          value: EncryptionContextType)
+     -> ReEncryptRequest {
+        self.source_encryption_context = Some(value);
+        self
+    }
+}
+impl RetireGrantRequest {
+    /// A `RetireGrantRequest` with the given required members and no optional ones.
+    pub fn new() -> RetireGrantRequest { Default::default() }
+    /// Sets `GrantId`.
+    pub fn with_grant_id<V: Into<GrantIdType>>(mut self, value: V)
+     -> RetireGrantRequest {
+        self.grant_id = Some(value.into());
+        self
+    }
+    /// Sets `GrantToken`.
+    pub fn with_grant_token<V: Into<GrantTokenType>>(mut self, value: V)
+     -> RetireGrantRequest {
+        self.grant_token = Some(value.into());
+        self
+    }
+    /// Sets `KeyId`.
+    pub fn with_key_id<V: Into<KeyIdType>>(mut self, value: V)
+     -> RetireGrantRequest {
+        self.key_id = Some(value.into());
+        self
+    }
+}
+impl RevokeGrantRequest {
+    /// A `RevokeGrantRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<GrantIdType>,
+               V1: Into<KeyIdType>>(grant_id: V0, key_id: V1)
+     -> RevokeGrantRequest {
+        RevokeGrantRequest{grant_id: grant_id.into(), key_id: key_id.into(),}
+    }
+}
+impl ScheduleKeyDeletionRequest {
+    /// A `ScheduleKeyDeletionRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<KeyIdType>>(key_id: V0)
+     -> ScheduleKeyDeletionRequest {
+        ScheduleKeyDeletionRequest{key_id:
+                                       key_id.into(), ..Default::default()}
+    }
+    /// Sets `PendingWindowInDays`.
+    pub fn with_pending_window_in_days(mut self,
+                                       value: PendingWindowInDaysType)
+     -> ScheduleKeyDeletionRequest {
+        self.pending_window_in_days = Some(value);
+        self
+    }
+}
+impl UpdateAliasRequest {
+    /// A `UpdateAliasRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<AliasNameType>,
+               V1: Into<KeyIdType>>(alias_name: V0, target_key_id: V1)
+     -> UpdateAliasRequest {
+        UpdateAliasRequest{alias_name: alias_name.into(),
+                           target_key_id: target_key_id.into(),}
+    }
+}
+impl UpdateKeyDescriptionRequest {
+    /// A `UpdateKeyDescriptionRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<DescriptionType>,
+               V1: Into<KeyIdType>>(description: V0, key_id: V1)
+     -> UpdateKeyDescriptionRequest {
+        UpdateKeyDescriptionRequest{description: description.into(),
+                                    key_id: key_id.into(),}
+    }
+}
+/// AWS Key Management Service
+///
+/// AWS Key Management Service (AWS KMS) is an encryption and key management web service. This guide describes the AWS KMS operations that you can call programmatically. For general information about AWS KMS, see the [AWS Key Management Service Developer Guide](http://docs.aws.amazon.com/kms/latest/developerguide/).
+///
+/// AWS provides SDKs that consist of libraries and sample code for various programming languages and platforms (Java, Ruby, .Net, iOS, Android, etc.). The SDKs provide a convenient way to create programmatic access to AWS KMS and other AWS services. For example, the SDKs take care of tasks such as signing requests (see below), managing errors, and retrying requests automatically. For more information about the AWS SDKs, including how to download and install them, see [Tools for Amazon Web Services](http://aws.amazon.com/tools/).
+///
+/// We recommend that you use the AWS SDKs to make programmatic API calls to AWS KMS.
+///
+/// Clients must support TLS (Transport Layer Security) 1.0. We recommend TLS 1.2. Clients must also support cipher suites with Perfect Forward Secrecy (PFS) such as Ephemeral Diffie-Hellman (DHE) or Elliptic Curve Ephemeral Diffie-Hellman (ECDHE). Most modern systems such as Java 7 and later support these modes.
+///
+/// **Signing Requests**
+///
+/// Requests must be signed by using an access key ID and a secret access key. We strongly recommend that you *do not* use your AWS account access key ID and secret key for everyday work with AWS KMS. Instead, use the access key ID and secret access key for an IAM user, or you can use the AWS Security Token Service to generate temporary security credentials that you can use to sign requests.
+///
+/// All AWS KMS operations require [Signature Version 4](http://docs.aws.amazon.com/general/latest/gr/signature-version-4.html).
+///
+/// **Logging API Requests**
+///
+/// AWS KMS supports AWS CloudTrail, a service that logs AWS API calls and related events for your AWS account and delivers them to an Amazon S3 bucket that you specify. By using the information collected by CloudTrail, you can determine what requests were made to AWS KMS, who made the request, when it was made, and so on. To learn more about CloudTrail, including how to turn it on and find your log files, see the [AWS CloudTrail User Guide](http://docs.aws.amazon.com/awscloudtrail/latest/userguide/).
+///
+/// **Additional Resources**
+///
+/// For more information about credentials and request signing, see the following:
+///
+/// * [AWS Security Credentials](http://docs.aws.amazon.com/general/latest/gr/aws-security-credentials.html) - This topic provides general information about the types of credentials used for accessing AWS.
+/// * [AWS Security Token Service](http://docs.aws.amazon.com/STS/latest/UsingSTS/) - This guide describes how to create and use temporary security credentials.
+/// * [Signing AWS API Requests](http://docs.aws.amazon.com/general/latest/gr/signing_aws_api_requests.html) - This set of topics walks you through the process of signing a request using an access key ID and a secret access key.
+///
+/// **Commonly Used APIs**
+///
+/// Of the APIs discussed in this guide, the following will prove the most useful for most applications. You will likely perform actions other than these, such as creating keys and assigning policies, by using the console.
+///
+/// * Encrypt
+/// * Decrypt
+/// * GenerateDataKey
+/// * GenerateDataKeyWithoutPlaintext
+pub struct KMSClient<'a> {
+    creds: Box<AWSCredentialsProvider+ 'a>,
+    region: &'a ::regions::Region,
+}
+impl <'a> KMSClient<'a> {
+    pub fn new<P: AWSCredentialsProvider +
+               'a>(creds: P, region: &'a ::regions::Region) -> KMSClient<'a> {
+        KMSClient{creds: Box::new(creds), region: region,}
+    }
+    /// Cancels the deletion of a customer master key (CMK). When this operation is successful, the CMK is set to the `Disabled` state. To enable a CMK, use EnableKey.
+    ///
+    /// For more information about scheduling and canceling deletion of a CMK, go to [Deleting Customer Master Keys](http://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html) in the *AWS Key Management Service Developer Guide*.
+    pub fn cancel_key_deletion(&mut self, input: &CancelKeyDeletionRequest)
+     -> Result<CancelKeyDeletionResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: CancelKeyDeletionResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Creates a display name for a customer master key. An alias can be used to identify a key and should be unique. The console enforces a one-to-one mapping between the alias and a key. An alias name can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). An alias must start with the word "alias" followed by a forward slash (alias/). An alias that begins with "aws" after the forward slash (alias/aws...) is reserved by Amazon Web Services (AWS).
+    ///
+    /// The alias and the key it is mapped to must be in the same AWS account and the same region.
+    ///
+    /// To map an alias to a different key, call UpdateAlias.
+    pub fn create_alias(&mut self, input: &CreateAliasRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateAlias");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Adds a grant to a key to specify who can use the key and under what conditions. Grants are alternate permission mechanisms to key policies.
+    ///
+    /// For more information about grants, see [Grants](http://docs.aws.amazon.com/kms/latest/developerguide/grants.html) in the *AWS Key Management Service Developer Guide*.
+    pub fn create_grant(&mut self, input: &CreateGrantRequest)
+     -> Result<CreateGrantResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateGrant");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: CreateGrantResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Creates a customer master key. Customer master keys can be used to encrypt small amounts of data (less than 4K) directly, but they are most commonly used to encrypt or envelope data keys that are then used to encrypt customer data. For more information about data keys, see GenerateDataKey and GenerateDataKeyWithoutPlaintext.
+    pub fn create_key(&mut self, input: &CreateKeyRequest)
+     -> Result<CreateKeyResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateKey");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: CreateKeyResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Decrypts ciphertext. Ciphertext is plaintext that has been previously encrypted by using any of the following functions:
+    ///
+    /// * GenerateDataKey
+    /// * GenerateDataKeyWithoutPlaintext
+    /// * Encrypt
+    ///
+    /// Note that if a caller has been granted access permissions to all keys (through, for example, IAM user policies that grant `Decrypt` permission on all resources), then ciphertext encrypted by using keys in other accounts where the key grants access to the caller can be decrypted. To remedy this, we recommend that you do not grant `Decrypt` access in an IAM user policy. Instead grant `Decrypt` access only in key policies. If you must grant `Decrypt` access in an IAM user policy, you should scope the resource to specific keys or to specific trusted accounts.
+    pub fn decrypt(&mut self, input: &DecryptRequest)
+     -> Result<DecryptResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.Decrypt");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: DecryptResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Deletes the specified alias. To map an alias to a different key, call UpdateAlias.
+    pub fn delete_alias(&mut self, input: &DeleteAliasRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DeleteAlias");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Provides detailed information about the specified customer master key.
+    pub fn describe_key(&mut self, input: &DescribeKeyRequest)
+     -> Result<DescribeKeyResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DescribeKey");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: DescribeKeyResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Sets the state of a master key to disabled, thereby preventing its use for cryptographic operations. For more information about how key state affects the use of a master key, go to [How Key State Affects the Use of a Customer Master Key](http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html) in the *AWS Key Management Service Developer Guide*.
+    pub fn disable_key(&mut self, input: &DisableKeyRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DisableKey");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Disables rotation of the specified key.
+    pub fn disable_key_rotation(&mut self, input: &DisableKeyRotationRequest)
+     -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Marks a key as enabled, thereby permitting its use.
+    pub fn enable_key(&mut self, input: &EnableKeyRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.EnableKey");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Enables rotation of the specified customer master key.
+    pub fn enable_key_rotation(&mut self, input: &EnableKeyRotationRequest)
+     -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Encrypts plaintext into ciphertext by using a customer master key. The `Encrypt` function has two primary use cases:
+    ///
+    /// * You can encrypt up to 4 KB of arbitrary data such as an RSA key, a database password, or other sensitive customer information.
+    /// * If you are moving encrypted data from one region to another, you can use this API to encrypt in the new region the plaintext data key that was used to encrypt the data in the original region. This provides you with an encrypted copy of the data key that can be decrypted in the new region and used there to decrypt the encrypted data.
+    ///
+    /// Unless you are moving encrypted data from one region to another, you don't use this function to encrypt a generated data key within a region. You retrieve data keys already encrypted by calling the GenerateDataKey or GenerateDataKeyWithoutPlaintext function. Data keys don't need to be encrypted again by calling `Encrypt`.
+    ///
+    /// If you want to encrypt data locally in your application, you can use the `GenerateDataKey` function to return a plaintext data encryption key and a copy of the key encrypted under the customer master key (CMK) of your choosing.
+    pub fn encrypt(&mut self, input: &EncryptRequest)
+     -> Result<EncryptResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.Encrypt");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: EncryptResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Generates a data key that you can use in your application to locally encrypt data. This call returns a plaintext version of the key in the `Plaintext` field of the response object and an encrypted copy of the key in the `CiphertextBlob` field. The key is encrypted by using the master key specified by the `KeyId` field. To decrypt the encrypted key, pass it to the `Decrypt` API.
+    ///
+    /// We recommend that you use the following pattern to locally encrypt data: call the `GenerateDataKey` API, use the key returned in the `Plaintext` response field to locally encrypt data, and then erase the plaintext data key from memory. Store the encrypted data key (contained in the `CiphertextBlob` field) alongside of the locally encrypted data.
+    ///
+    /// You should not call the `Encrypt` function to re-encrypt your data keys within a region. `GenerateDataKey` always returns the data key encrypted and tied to the customer master key that will be used to decrypt it. There is no need to decrypt it twice.
+    ///
+    /// If you decide to use the optional `EncryptionContext` parameter, you must also store the context in full or at least store enough information along with the encrypted data to be able to reconstruct the context when submitting the ciphertext to the `Decrypt` API. It is a good practice to choose a context that you can reconstruct on the fly to better secure the ciphertext. For more information about how this parameter is used, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html).
+    ///
+    /// To decrypt data, pass the encrypted data key to the `Decrypt` API. `Decrypt` uses the associated master key to decrypt the encrypted data key and returns it as plaintext. Use the plaintext data key to locally decrypt your data and then erase the key from memory. You must specify the encryption context, if any, that you specified when you generated the key. The encryption context is logged by CloudTrail, and you can use this log to help track the use of particular data.
+    pub fn generate_data_key(&mut self, input: &GenerateDataKeyRequest)
+     -> Result<GenerateDataKeyResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GenerateDataKey");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: GenerateDataKeyResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Returns a data key encrypted by a customer master key without the plaintext copy of that key. Otherwise, this API functions exactly like GenerateDataKey. You can use this API to, for example, satisfy an audit requirement that an encrypted key be made available without exposing the plaintext copy of that key.
+    pub fn generate_data_key_without_plaintext(&mut self,
+                                               input:
+                                                   &GenerateDataKeyWithoutPlaintextRequest)
+     -> Result<GenerateDataKeyWithoutPlaintextResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target",
+                           "TrentService.GenerateDataKeyWithoutPlaintext");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: GenerateDataKeyWithoutPlaintextResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Generates an unpredictable byte string.
+    pub fn generate_random(&mut self, input: &GenerateRandomRequest)
+     -> Result<GenerateRandomResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GenerateRandom");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: GenerateRandomResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Retrieves a policy attached to the specified key.
+    pub fn get_key_policy(&mut self, input: &GetKeyPolicyRequest)
+     -> Result<GetKeyPolicyResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: GetKeyPolicyResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Retrieves a Boolean value that indicates whether key rotation is enabled for the specified key.
+    pub fn get_key_rotation_status(&mut self,
+                                   input: &GetKeyRotationStatusRequest)
+     -> Result<GetKeyRotationStatusResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target",
+                           "TrentService.GetKeyRotationStatus");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: GetKeyRotationStatusResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Lists all of the key aliases in the account.
+    pub fn list_aliases(&mut self, input: &ListAliasesRequest)
+     -> Result<ListAliasesResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListAliases");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ListAliasesResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// List the grants for a specified key.
+    pub fn list_grants(&mut self, input: &ListGrantsRequest)
+     -> Result<ListGrantsResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListGrants");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ListGrantsResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Retrieves a list of policies attached to a key.
+    pub fn list_key_policies(&mut self, input: &ListKeyPoliciesRequest)
+     -> Result<ListKeyPoliciesResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ListKeyPoliciesResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Lists the customer master keys.
+    pub fn list_keys(&mut self, input: &ListKeysRequest)
+     -> Result<ListKeysResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListKeys");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ListKeysResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Returns a list of all grants for which the grant's `RetiringPrincipal` matches the one specified.
+    ///
+    /// A typical use is to list all grants that you are able to retire. To retire a grant, use RetireGrant.
+    pub fn list_retirable_grants(&mut self,
+                                 input: &ListRetirableGrantsRequest)
+     -> Result<ListGrantsResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target",
+                           "TrentService.ListRetirableGrants");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ListGrantsResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Attaches a policy to the specified key.
+    pub fn put_key_policy(&mut self, input: &PutKeyPolicyRequest)
+     -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Encrypts data on the server side with a new customer master key without exposing the plaintext of the data on the client side. The data is first decrypted and then encrypted. This operation can also be used to change the encryption context of a ciphertext.
+    ///
+    /// Unlike other actions, `ReEncrypt` is authorized twice - once as `ReEncryptFrom` on the source key and once as `ReEncryptTo` on the destination key. We therefore recommend that you include the `"action":"kms:ReEncrypt*"` statement in your key policies to permit re-encryption from or to the key. The statement is included automatically when you authorize use of the key through the console but must be included manually when you set a policy by using the PutKeyPolicy function.
+    pub fn re_encrypt(&mut self, input: &ReEncryptRequest)
+     -> Result<ReEncryptResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ReEncrypt");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ReEncryptResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Retires a grant. You can retire a grant when you're done using it to clean up. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:
+    ///
+    /// * The account that created the grant
+    /// * The `RetiringPrincipal`, if present
+    /// * The `GranteePrincipal`, if `RetireGrant` is a grantee operation
+    ///
+    /// The grant to retire must be identified by its grant token or by a combination of the key ARN and the grant ID. A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. Both are returned by the `CreateGrant` function.
+    pub fn retire_grant(&mut self, input: &RetireGrantRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.RetireGrant");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Revokes a grant. You can revoke a grant to actively deny operations that depend on it.
+    pub fn revoke_grant(&mut self, input: &RevokeGrantRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.RevokeGrant");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the state of the CMK changes to `PendingDeletion`. Before the waiting period ends, you can use CancelKeyDeletion to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that point to it.
+    ///
+    /// Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is rendered unrecoverable. To restrict the use of a CMK without deleting it, use DisableKey.
+    ///
+    /// For more information about scheduling a CMK for deletion, go to [Deleting Customer Master Keys](http://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html) in the *AWS Key Management Service Developer Guide*.
+    pub fn schedule_key_deletion(&mut self,
+                                 input: &ScheduleKeyDeletionRequest)
+     -> Result<ScheduleKeyDeletionResponse> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target",
+                           "TrentService.ScheduleKeyDeletion");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => {
+                let decoded: ScheduleKeyDeletionResponse =
+                    serde_json::from_str(&body).unwrap();
+                Ok(decoded)
+            }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Updates an alias to map it to a different key.
+    ///
+    /// An alias is not a property of a key. Therefore, an alias can be mapped to and unmapped from an existing key without changing the properties of the key.
+    ///
+    /// An alias name can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). An alias must start with the word "alias" followed by a forward slash (alias/). An alias that begins with "aws" after the forward slash (alias/aws...) is reserved by Amazon Web Services (AWS).
+    ///
+    /// The alias and the key it is mapped to must be in the same AWS account and the same region.
+    pub fn update_alias(&mut self, input: &UpdateAliasRequest) -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.UpdateAlias");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+    /// Updates the description of a key.
+    pub fn update_key_description(&mut self,
+                                  input: &UpdateKeyDescriptionRequest)
+     -> Result<()> {
+        try!(input . validate (  ));
+        let encoded = serde_json::to_string(&input).unwrap();
+        let mut request =
+            SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target",
+                           "TrentService.UpdateKeyDescription");
+        request.set_payload(Some(encoded.as_bytes()));
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        let mut body = String::new();
+        result.read_to_string(&mut body).unwrap();
+        match status {
+            200 => { let decoded = (); Ok(decoded) }
+            _ => { Err(parse_error(status, &body)) }
+        }
+    }
+}
+/// The operations of `KMSClient`, for code that should also work with a substitute client.
+pub trait Kms {
+    /// Cancels the deletion of a customer master key (CMK). When this operation is successful, the CMK is set to the `Disabled` state. To enable a CMK, use EnableKey.
+    ///
+    /// For more information about scheduling and canceling deletion of a CMK, go to [Deleting Customer Master Keys](http://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html) in the *AWS Key Management Service Developer Guide*.
+    fn cancel_key_deletion(&mut self, input: &CancelKeyDeletionRequest)
+    -> Result<CancelKeyDeletionResponse>;
+    /// Creates a display name for a customer master key. An alias can be used to identify a key and should be unique. The console enforces a one-to-one mapping between the alias and a key. An alias name can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). An alias must start with the word "alias" followed by a forward slash (alias/). An alias that begins with "aws" after the forward slash (alias/aws...) is reserved by Amazon Web Services (AWS).
+    ///
+    /// The alias and the key it is mapped to must be in the same AWS account and the same region.
+    ///
+    /// To map an alias to a different key, call UpdateAlias.
+    fn create_alias(&mut self, input: &CreateAliasRequest)
+    -> Result<()>;
+    /// Adds a grant to a key to specify who can use the key and under what conditions. Grants are alternate permission mechanisms to key policies.
+    ///
+    /// For more information about grants, see [Grants](http://docs.aws.amazon.com/kms/latest/developerguide/grants.html) in the *AWS Key Management Service Developer Guide*.
+    fn create_grant(&mut self, input: &CreateGrantRequest)
+    -> Result<CreateGrantResponse>;
+    /// Creates a customer master key. Customer master keys can be used to encrypt small amounts of data (less than 4K) directly, but they are most commonly used to encrypt or envelope data keys that are then used to encrypt customer data. For more information about data keys, see GenerateDataKey and GenerateDataKeyWithoutPlaintext.
+    fn create_key(&mut self, input: &CreateKeyRequest)
+    -> Result<CreateKeyResponse>;
+    /// Decrypts ciphertext. Ciphertext is plaintext that has been previously encrypted by using any of the following functions:
+    ///
+    /// * GenerateDataKey
+    /// * GenerateDataKeyWithoutPlaintext
+    /// * Encrypt
+    ///
+    /// Note that if a caller has been granted access permissions to all keys (through, for example, IAM user policies that grant `Decrypt` permission on all resources), then ciphertext encrypted by using keys in other accounts where the key grants access to the caller can be decrypted. To remedy this, we recommend that you do not grant `Decrypt` access in an IAM user policy. Instead grant `Decrypt` access only in key policies. If you must grant `Decrypt` access in an IAM user policy, you should scope the resource to specific keys or to specific trusted accounts.
+    fn decrypt(&mut self, input: &DecryptRequest)
+    -> Result<DecryptResponse>;
+    /// Deletes the specified alias. To map an alias to a different key, call UpdateAlias.
+    fn delete_alias(&mut self, input: &DeleteAliasRequest)
+    -> Result<()>;
+    /// Provides detailed information about the specified customer master key.
+    fn describe_key(&mut self, input: &DescribeKeyRequest)
+    -> Result<DescribeKeyResponse>;
+    /// Sets the state of a master key to disabled, thereby preventing its use for cryptographic operations. For more information about how key state affects the use of a master key, go to [How Key State Affects the Use of a Customer Master Key](http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html) in the *AWS Key Management Service Developer Guide*.
+    fn disable_key(&mut self, input: &DisableKeyRequest)
+    -> Result<()>;
+    /// Disables rotation of the specified key.
+    fn disable_key_rotation(&mut self, input: &DisableKeyRotationRequest)
+    -> Result<()>;
+    /// Marks a key as enabled, thereby permitting its use.
+    fn enable_key(&mut self, input: &EnableKeyRequest)
+    -> Result<()>;
+    /// Enables rotation of the specified customer master key.
+    fn enable_key_rotation(&mut self, input: &EnableKeyRotationRequest)
+    -> Result<()>;
+    /// Encrypts plaintext into ciphertext by using a customer master key. The `Encrypt` function has two primary use cases:
+    ///
+    /// * You can encrypt up to 4 KB of arbitrary data such as an RSA key, a database password, or other sensitive customer information.
+    /// * If you are moving encrypted data from one region to another, you can use this API to encrypt in the new region the plaintext data key that was used to encrypt the data in the original region. This provides you with an encrypted copy of the data key that can be decrypted in the new region and used there to decrypt the encrypted data.
+    ///
+    /// Unless you are moving encrypted data from one region to another, you don't use this function to encrypt a generated data key within a region. You retrieve data keys already encrypted by calling the GenerateDataKey or GenerateDataKeyWithoutPlaintext function. Data keys don't need to be encrypted again by calling `Encrypt`.
+    ///
+    /// If you want to encrypt data locally in your application, you can use the `GenerateDataKey` function to return a plaintext data encryption key and a copy of the key encrypted under the customer master key (CMK) of your choosing.
+    fn encrypt(&mut self, input: &EncryptRequest)
+    -> Result<EncryptResponse>;
+    /// Generates a data key that you can use in your application to locally encrypt data. This call returns a plaintext version of the key in the `Plaintext` field of the response object and an encrypted copy of the key in the `CiphertextBlob` field. The key is encrypted by using the master key specified by the `KeyId` field. To decrypt the encrypted key, pass it to the `Decrypt` API.
+    ///
+    /// We recommend that you use the following pattern to locally encrypt data: call the `GenerateDataKey` API, use the key returned in the `Plaintext` response field to locally encrypt data, and then erase the plaintext data key from memory. Store the encrypted data key (contained in the `CiphertextBlob` field) alongside of the locally encrypted data.
+    ///
+    /// You should not call the `Encrypt` function to re-encrypt your data keys within a region. `GenerateDataKey` always returns the data key encrypted and tied to the customer master key that will be used to decrypt it. There is no need to decrypt it twice.
+    ///
+    /// If you decide to use the optional `EncryptionContext` parameter, you must also store the context in full or at least store enough information along with the encrypted data to be able to reconstruct the context when submitting the ciphertext to the `Decrypt` API. It is a good practice to choose a context that you can reconstruct on the fly to better secure the ciphertext. For more information about how this parameter is used, see [Encryption Context](http://docs.aws.amazon.com/kms/latest/developerguide/encrypt-context.html).
+    ///
+    /// To decrypt data, pass the encrypted data key to the `Decrypt` API. `Decrypt` uses the associated master key to decrypt the encrypted data key and returns it as plaintext. Use the plaintext data key to locally decrypt your data and then erase the key from memory. You must specify the encryption context, if any, that you specified when you generated the key. The encryption context is logged by CloudTrail, and you can use this log to help track the use of particular data.
+    fn generate_data_key(&mut self, input: &GenerateDataKeyRequest)
+    -> Result<GenerateDataKeyResponse>;
+    /// Returns a data key encrypted by a customer master key without the plaintext copy of that key. Otherwise, this API functions exactly like GenerateDataKey. You can use this API to, for example, satisfy an audit requirement that an encrypted key be made available without exposing the plaintext copy of that key.
+    fn generate_data_key_without_plaintext(&mut self,
+                                           input:
+                                               &GenerateDataKeyWithoutPlaintextRequest)
+    -> Result<GenerateDataKeyWithoutPlaintextResponse>;
+    /// Generates an unpredictable byte string.
+    fn generate_random(&mut self, input: &GenerateRandomRequest)
+    -> Result<GenerateRandomResponse>;
+    /// Retrieves a policy attached to the specified key.
+    fn get_key_policy(&mut self, input: &GetKeyPolicyRequest)
+    -> Result<GetKeyPolicyResponse>;
+    /// Retrieves a Boolean value that indicates whether key rotation is enabled for the specified key.
+    fn get_key_rotation_status(&mut self, input: &GetKeyRotationStatusRequest)
+    -> Result<GetKeyRotationStatusResponse>;
+    /// Lists all of the key aliases in the account.
+    fn list_aliases(&mut self, input: &ListAliasesRequest)
+    -> Result<ListAliasesResponse>;
+    /// List the grants for a specified key.
+    fn list_grants(&mut self, input: &ListGrantsRequest)
+    -> Result<ListGrantsResponse>;
+    /// Retrieves a list of policies attached to a key.
+    fn list_key_policies(&mut self, input: &ListKeyPoliciesRequest)
+    -> Result<ListKeyPoliciesResponse>;
+    /// Lists the customer master keys.
+    fn list_keys(&mut self, input: &ListKeysRequest)
+    -> Result<ListKeysResponse>;
+    /// Returns a list of all grants for which the grant's `RetiringPrincipal` matches the one specified.
+    ///
+    /// A typical use is to list all grants that you are able to retire. To retire a grant, use RetireGrant.
+    fn list_retirable_grants(&mut self, input: &ListRetirableGrantsRequest)
+    -> Result<ListGrantsResponse>;
+    /// Attaches a policy to the specified key.
+    fn put_key_policy(&mut self, input: &PutKeyPolicyRequest)
+    -> Result<()>;
+    /// Encrypts data on the server side with a new customer master key without exposing the plaintext of the data on the client side. The data is first decrypted and then encrypted. This operation can also be used to change the encryption context of a ciphertext.
+    ///
+    /// Unlike other actions, `ReEncrypt` is authorized twice - once as `ReEncryptFrom` on the source key and once as `ReEncryptTo` on the destination key. We therefore recommend that you include the `"action":"kms:ReEncrypt*"` statement in your key policies to permit re-encryption from or to the key. The statement is included automatically when you authorize use of the key through the console but must be included manually when you set a policy by using the PutKeyPolicy function.
+    fn re_encrypt(&mut self, input: &ReEncryptRequest)
+    -> Result<ReEncryptResponse>;
+    /// Retires a grant. You can retire a grant when you're done using it to clean up. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:
+    ///
+    /// * The account that created the grant
+    /// * The `RetiringPrincipal`, if present
+    /// * The `GranteePrincipal`, if `RetireGrant` is a grantee operation
+    ///
+    /// The grant to retire must be identified by its grant token or by a combination of the key ARN and the grant ID. A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. Both are returned by the `CreateGrant` function.
+    fn retire_grant(&mut self, input: &RetireGrantRequest)
+    -> Result<()>;
+    /// Revokes a grant. You can revoke a grant to actively deny operations that depend on it.
+    fn revoke_grant(&mut self, input: &RevokeGrantRequest)
+    -> Result<()>;
+    /// Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the state of the CMK changes to `PendingDeletion`. Before the waiting period ends, you can use CancelKeyDeletion to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that point to it.
+    ///
+    /// Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is rendered unrecoverable. To restrict the use of a CMK without deleting it, use DisableKey.
+    ///
+    /// For more information about scheduling a CMK for deletion, go to [Deleting Customer Master Keys](http://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html) in the *AWS Key Management Service Developer Guide*.
+    fn schedule_key_deletion(&mut self, input: &ScheduleKeyDeletionRequest)
+    -> Result<ScheduleKeyDeletionResponse>;
+    /// Updates an alias to map it to a different key.
+    ///
+    /// An alias is not a property of a key. Therefore, an alias can be mapped to and unmapped from an existing key without changing the properties of the key.
+    ///
+    /// An alias name can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). An alias must start with the word "alias" followed by a forward slash (alias/). An alias that begins with "aws" after the forward slash (alias/aws...) is reserved by Amazon Web Services (AWS).
+    ///
+    /// The alias and the key it is mapped to must be in the same AWS account and the same region.
+    fn update_alias(&mut self, input: &UpdateAliasRequest)
+    -> Result<()>;
+    /// Updates the description of a key.
+    fn update_key_description(&mut self, input: &UpdateKeyDescriptionRequest)
+    -> Result<()>;
+}
+impl <'a> Kms for KMSClient<'a> {
+    fn cancel_key_deletion(&mut self, input: &CancelKeyDeletionRequest)
+     -> Result<CancelKeyDeletionResponse> {
+        KMSClient::cancel_key_deletion(self, input)
+    }
+    fn create_alias(&mut self, input: &CreateAliasRequest) -> Result<()> {
+        KMSClient::create_alias(self, input)
+    }
+    fn create_grant(&mut self, input: &CreateGrantRequest)
+     -> Result<CreateGrantResponse> {
+        KMSClient::create_grant(self, input)
+    }
+    fn create_key(&mut self, input: &CreateKeyRequest)
+     -> Result<CreateKeyResponse> {
+        KMSClient::create_key(self, input)
+    }
+    fn decrypt(&mut self, input: &DecryptRequest) -> Result<DecryptResponse> {
+        KMSClient::decrypt(self, input)
+    }
+    fn delete_alias(&mut self, input: &DeleteAliasRequest) -> Result<()> {
+        KMSClient::delete_alias(self, input)
+    }
+    fn describe_key(&mut self, input: &DescribeKeyRequest)
+     -> Result<DescribeKeyResponse> {
+        KMSClient::describe_key(self, input)
+    }
+    fn disable_key(&mut self, input: &DisableKeyRequest) -> Result<()> {
+        KMSClient::disable_key(self, input)
+    }
+    fn disable_key_rotation(&mut self, input: &DisableKeyRotationRequest)
+     -> Result<()> {
+        KMSClient::disable_key_rotation(self, input)
+    }
+    fn enable_key(&mut self, input: &EnableKeyRequest) -> Result<()> {
+        KMSClient::enable_key(self, input)
+    }
+    fn enable_key_rotation(&mut self, input: &EnableKeyRotationRequest)
+     -> Result<()> {
+        KMSClient::enable_key_rotation(self, input)
+    }
+    fn encrypt(&mut self, input: &EncryptRequest) -> Result<EncryptResponse> {
+        KMSClient::encrypt(self, input)
+    }
+    fn generate_data_key(&mut self, input: &GenerateDataKeyRequest)
+     -> Result<GenerateDataKeyResponse> {
+        KMSClient::generate_data_key(self, input)
+    }
+    fn generate_data_key_without_plaintext(&mut self,
+                                           input:
+                                               &GenerateDataKeyWithoutPlaintextRequest)
+     -> Result<GenerateDataKeyWithoutPlaintextResponse> {
+        KMSClient::generate_data_key_without_plaintext(self, input)
+    }
+    fn generate_random(&mut self, input: &GenerateRandomRequest)
+     -> Result<GenerateRandomResponse> {
+        KMSClient::generate_random(self, input)
+    }
+    fn get_key_policy(&mut self, input: &GetKeyPolicyRequest)
+     -> Result<GetKeyPolicyResponse> {
+        KMSClient::get_key_policy(self, input)
+    }
+    fn get_key_rotation_status(&mut self, input: &GetKeyRotationStatusRequest)
+     -> Result<GetKeyRotationStatusResponse> {
+        KMSClient::get_key_rotation_status(self, input)
+    }
+    fn list_aliases(&mut self, input: &ListAliasesRequest)
+     -> Result<ListAliasesResponse> {
+        KMSClient::list_aliases(self, input)
+    }
+    fn list_grants(&mut self, input: &ListGrantsRequest)
+     -> Result<ListGrantsResponse> {
+        KMSClient::list_grants(self, input)
+    }
+    fn list_key_policies(&mut self, input: &ListKeyPoliciesRequest)
+     -> Result<ListKeyPoliciesResponse> {
+        KMSClient::list_key_policies(self, input)
+    }
+    fn list_keys(&mut self, input: &ListKeysRequest)
+     -> Result<ListKeysResponse> {
+        KMSClient::list_keys(self, input)
+    }
+    fn list_retirable_grants(&mut self, input: &ListRetirableGrantsRequest)
+     -> Result<ListGrantsResponse> {
+        KMSClient::list_retirable_grants(self, input)
+    }
+    fn put_key_policy(&mut self, input: &PutKeyPolicyRequest) -> Result<()> {
+        KMSClient::put_key_policy(self, input)
+    }
+    fn re_encrypt(&mut self, input: &ReEncryptRequest)
+     -> Result<ReEncryptResponse> {
+        KMSClient::re_encrypt(self, input)
+    }
+    fn retire_grant(&mut self, input: &RetireGrantRequest) -> Result<()> {
+        KMSClient::retire_grant(self, input)
+    }
+    fn revoke_grant(&mut self, input: &RevokeGrantRequest) -> Result<()> {
+        KMSClient::revoke_grant(self, input)
+    }
+    fn schedule_key_deletion(&mut self, input: &ScheduleKeyDeletionRequest)
+     -> Result<ScheduleKeyDeletionResponse> {
+        KMSClient::schedule_key_deletion(self, input)
+    }
+    fn update_alias(&mut self, input: &UpdateAliasRequest) -> Result<()> {
+        KMSClient::update_alias(self, input)
+    }
+    fn update_key_description(&mut self, input: &UpdateKeyDescriptionRequest)
+     -> Result<()> {
+        KMSClient::update_key_description(self, input)
+    }
+}
@@ -0,0 +1,12359 @@
+use std::collections::HashMap;
+pub type AWSAccountIdList = Vec<String>;
+/// Parse AWSAccountIdList from XML
+struct AWSAccountIdListParser;
+impl AWSAccountIdListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<AWSAccountIdList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "AWSAccountId" {
+            obj.push(try!(StringParser:: parse_xml ( "AWSAccountId" , stack
+                          )));
+        }
+        Ok(obj)
+    }
+}
+/// Write AWSAccountIdList contents to a SignedRequest
+struct AWSAccountIdListWriter;
+impl AWSAccountIdListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &AWSAccountIdList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            StringWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+pub type ActionNameList = Vec<String>;
+/// Parse ActionNameList from XML
+struct ActionNameListParser;
+impl ActionNameListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ActionNameList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "ActionName" {
+            obj.push(try!(StringParser:: parse_xml ( "ActionName" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write ActionNameList contents to a SignedRequest
+struct ActionNameListWriter;
+impl ActionNameListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &ActionNameList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            StringWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct AddPermissionRequest {
+    /// The AWS account number of the [principal](http://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P) who will be given permission. The principal must have an AWS account, but does not need to be signed up for Amazon SQS. For information about locating the AWS account identification, see [Your AWS Identifiers](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AWSCredentials.html) in the *Amazon SQS Developer Guide*.
+    pub aws_account_ids: AWSAccountIdList,
+    /// The action the client wants to allow for the specified principal. The following are valid values: `* | SendMessage | ReceiveMessage | DeleteMessage | ChangeMessageVisibility | GetQueueAttributes | GetQueueUrl`. For more information about these actions, see [Understanding Permissions](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/acp-overview.html#PermissionTypes) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Specifying `SendMessage`, `DeleteMessage`, or `ChangeMessageVisibility` for the `ActionName.n` also grants permissions for the corresponding batch versions of those actions: `SendMessageBatch`, `DeleteMessageBatch`, and `ChangeMessageVisibilityBatch`.
+    pub actions: ActionNameList,
+    /// The unique identification of the permission you're setting (e.g., `AliceSendMessage`). Constraints: Maximum 80 characters; alphanumeric characters, hyphens (-), and underscores (_) are allowed.
+    pub label: String,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for AddPermissionRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<AddPermissionRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AWSAccountIds" => { Ok(__Field::__field0) }
+                                "Actions" => { Ok(__Field::__field1) }
+                                "Label" => { Ok(__Field::__field2) }
+                                "QueueUrl" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D:  // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                   // xml start tag
+                  ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                AddPermissionRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AddPermissionRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AddPermissionRequest{aws_account_ids: __field0,
+                                                actions: __field1,
+                                                label: __field2,
+                                                queue_url: __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<AddPermissionRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "AWSAccountIds"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Actions" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Label" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(AddPermissionRequest{aws_account_ids: __field0,
+                                                actions: __field1,
+                                                label: __field2,
+                                                queue_url: __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["aws_account_ids", "actions", "label", "queue_url"];
+            deserializer.visit_struct("AddPermissionRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for AddPermissionRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a AddPermissionRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a AddPermissionRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AWSAccountIds" ,
+                                                    &self.value.aws_account_ids
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Actions" ,
+                                                    &self.value.actions ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Label" ,
+                                                    &self.value.label , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("AddPermissionRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&AddPermissionRequest>,})
+        }
+    }
+}
+/// Parse AddPermissionRequest from XML
+struct AddPermissionRequestParser;
+impl AddPermissionRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<AddPermissionRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = AddPermissionRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "AWSAccountId" {
+                obj.aws_account_ids =
+                    try!(AWSAccountIdListParser:: parse_xml (
+                         "AWSAccountId" , stack ));
+                continue ;
+            }
+            if current_name == "ActionName" {
+                obj.actions =
+                    try!(ActionNameListParser:: parse_xml (
+                         "ActionName" , stack ));
+                continue ;
+            }
+            if current_name == "Label" {
+                obj.label =
+                    try!(StringParser:: parse_xml ( "Label" , stack ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write AddPermissionRequest contents to a SignedRequest
+struct AddPermissionRequestWriter;
+impl AddPermissionRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &AddPermissionRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        AWSAccountIdListWriter::write_params(params,
+                                             &(prefix.to_string() +
+                                                   "AWSAccountId"),
+                                             &obj.aws_account_ids);
+        ActionNameListWriter::write_params(params,
+                                           &(prefix.to_string() +
+                                                 "ActionName"), &obj.actions);
+        StringWriter::write_params(params, &(prefix.to_string() + "Label"),
+                                   &obj.label);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+pub type AttributeMap = HashMap<QueueAttributeName, String>;
+/// Parse AttributeMap from XML
+struct AttributeMapParser;
+impl AttributeMapParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<AttributeMap, XmlParseError> {
+        let mut obj = HashMap::new();
+        while try!(peek_at_name ( stack )) == tag_name {
+            try!(start_element ( tag_name , stack ));
+            let key =
+                try!(QueueAttributeNameParser:: parse_xml ( "Name" , stack ));
+            let value = try!(StringParser:: parse_xml ( "Value" , stack ));
+            obj.insert(key, value);
+            try!(end_element ( tag_name , stack ));
+        }
+        Ok(obj)
+    }
+}
+/// Write AttributeMap contents to a SignedRequest
+struct AttributeMapWriter;
+impl AttributeMapWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &AttributeMap) {
+        let mut index = 1;
+        for (key, value) in obj {
+            let prefix = &format!("{}.{}" , name , index);
+            QueueAttributeNameWriter::write_params(params,
+                                                   &format!("{}.{}" , prefix ,
+                                                            "Name"), &key);
+            StringWriter::write_params(params,
+                                       &format!("{}.{}" , prefix , "Value"),
+                                       &value);
+            index += 1;
+        }
+    }
+}
+pub type AttributeNameList = Vec<QueueAttributeName>;
+/// Parse AttributeNameList from XML
+struct AttributeNameListParser;
+impl AttributeNameListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<AttributeNameList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "AttributeName" {
+            obj.push(try!(QueueAttributeNameParser:: parse_xml (
+                          "AttributeName" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write AttributeNameList contents to a SignedRequest
+struct AttributeNameListWriter;
+impl AttributeNameListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &AttributeNameList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            QueueAttributeNameWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+/// Two or more batch entries have the same `Id` in the request.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct BatchEntryIdsNotDistinct;
+impl ::serde::de::Deserialize for BatchEntryIdsNotDistinct {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<BatchEntryIdsNotDistinct, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                BatchEntryIdsNotDistinct;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<BatchEntryIdsNotDistinct, E> where
+                 E: ::serde::de::Error {
+                    Ok(BatchEntryIdsNotDistinct)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<BatchEntryIdsNotDistinct, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("BatchEntryIdsNotDistinct",
+                                           __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for BatchEntryIdsNotDistinct {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("BatchEntryIdsNotDistinct")
+    }
+}
+/// Parse BatchEntryIdsNotDistinct from XML
+struct BatchEntryIdsNotDistinctParser;
+impl BatchEntryIdsNotDistinctParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<BatchEntryIdsNotDistinct, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = BatchEntryIdsNotDistinct::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write BatchEntryIdsNotDistinct contents to a SignedRequest
+struct BatchEntryIdsNotDistinctWriter;
+impl BatchEntryIdsNotDistinctWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &BatchEntryIdsNotDistinct) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The length of all the messages put together is more than the limit.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct BatchRequestTooLong;
+impl ::serde::de::Deserialize for BatchRequestTooLong {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<BatchRequestTooLong, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                BatchRequestTooLong;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<BatchRequestTooLong, E> where
+                 E: ::serde::de::Error {
+                    Ok(BatchRequestTooLong)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<BatchRequestTooLong, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("BatchRequestTooLong", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for BatchRequestTooLong {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("BatchRequestTooLong")
+    }
+}
+/// Parse BatchRequestTooLong from XML
+struct BatchRequestTooLongParser;
+impl BatchRequestTooLongParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<BatchRequestTooLong, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = BatchRequestTooLong::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write BatchRequestTooLong contents to a SignedRequest
+struct BatchRequestTooLongWriter;
+impl BatchRequestTooLongWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &BatchRequestTooLong) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// This is used in the responses of batch API to give a detailed description of the result of an action on each entry in the request.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct BatchResultErrorEntry {
+    /// An error code representing why the action failed on this entry.
+    pub code: String,
+    /// The id of an entry in a batch request.
+    pub id: String,
+    /// A message explaining why the action failed on this entry.
+    pub message: Option<String>,
+    /// Whether the error happened due to the sender's fault.
+    pub sender_fault: Boolean,
+}
+impl ::serde::de::Deserialize for BatchResultErrorEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<BatchResultErrorEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Code" => { Ok(__Field::__field0) }
+                                "Id" => { Ok(__Field::__field1) }
+                                "Message" => { Ok(__Field::__field2) }
+                                "SenderFault" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                BatchResultErrorEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<BatchResultErrorEntry, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(BatchResultErrorEntry{code: __field0,
+                                                 id: __field1,
+                                                 message: __field2,
+                                                 sender_fault: __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<BatchResultErrorEntry, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Code" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "Message" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "SenderFault"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(BatchResultErrorEntry{code: __field0,
+                                                 id: __field1,
+                                                 message: __field2,
+                                                 sender_fault: __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["code", "id", "message", "sender_fault"];
+            deserializer.visit_struct("BatchResultErrorEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for BatchResultErrorEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a BatchResultErrorEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a BatchResultErrorEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Code" , &self.value.code
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Message" ,
+                                                    &self.value.message ,
+                                                    ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "SenderFault" ,
+                                                    &self.value.sender_fault ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("BatchResultErrorEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&BatchResultErrorEntry>,})
+        }
+    }
+}
+/// Parse BatchResultErrorEntry from XML
+struct BatchResultErrorEntryParser;
+impl BatchResultErrorEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<BatchResultErrorEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = BatchResultErrorEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Code" {
+                obj.code = try!(StringParser:: parse_xml ( "Code" , stack ));
+                continue ;
+            }
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            if current_name == "Message" {
+                obj.message =
+                    Some(try!(StringParser:: parse_xml ( "Message" , stack
+                              )));
+                continue ;
+            }
+            if current_name == "SenderFault" {
+                obj.sender_fault =
+                    try!(BooleanParser:: parse_xml ( "SenderFault" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write BatchResultErrorEntry contents to a SignedRequest
+struct BatchResultErrorEntryWriter;
+impl BatchResultErrorEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &BatchResultErrorEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Code"),
+                                   &obj.code);
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+        if let Some(ref obj) = obj.message {
+            StringWriter::write_params(params,
+                                       &(prefix.to_string() + "Message"),
+                                       obj);
+        }
+        BooleanWriter::write_params(params,
+                                    &(prefix.to_string() + "SenderFault"),
+                                    &obj.sender_fault);
+    }
+}
+pub type BatchResultErrorEntryList = Vec<BatchResultErrorEntry>;
+/// Parse BatchResultErrorEntryList from XML
+struct BatchResultErrorEntryListParser;
+impl BatchResultErrorEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<BatchResultErrorEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "BatchResultErrorEntry" {
+            obj.push(try!(BatchResultErrorEntryParser:: parse_xml (
+                          "BatchResultErrorEntry" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write BatchResultErrorEntryList contents to a SignedRequest
+struct BatchResultErrorEntryListWriter;
+impl BatchResultErrorEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &BatchResultErrorEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            BatchResultErrorEntryWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+pub type Binary = ::blob::Blob;
+/// Parse Binary from XML
+struct BinaryParser;
+impl BinaryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<Binary, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj =
+            ::blob::Blob(try!(:: blob:: decode_base64 (
+                              & try ! ( characters ( stack ) ) )));
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write Binary contents to a SignedRequest
+struct BinaryWriter;
+impl BinaryWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &Binary) {
+        params.put(name, &::blob::encode_base64(obj));
+    }
+}
+pub type BinaryList = Vec<Binary>;
+/// Parse BinaryList from XML
+struct BinaryListParser;
+impl BinaryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<BinaryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "BinaryListValue" {
+            obj.push(try!(BinaryParser:: parse_xml ( "BinaryListValue" , stack
+                          )));
+        }
+        Ok(obj)
+    }
+}
+/// Write BinaryList contents to a SignedRequest
+struct BinaryListWriter;
+impl BinaryListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &BinaryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            BinaryWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+pub type Boolean = bool;
+/// Parse Boolean from XML
+struct BooleanParser;
+impl BooleanParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<Boolean, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj =
+            try!(bool:: from_str (
+                 try ! ( characters ( stack ) ) . as_ref (  ) ));
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write Boolean contents to a SignedRequest
+struct BooleanWriter;
+impl BooleanWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &Boolean) {
+        params.put(name, &obj.to_string());
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ChangeMessageVisibilityBatchRequest {
+    /// A list of receipt handles of the messages for which the visibility timeout must be changed.
+    pub entries: ChangeMessageVisibilityBatchRequestEntryList,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for ChangeMessageVisibilityBatchRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ChangeMessageVisibilityBatchRequest, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Entries" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ChangeMessageVisibilityBatchRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchRequest{entries:
+                                                                   __field0,
+                                                               queue_url:
+                                                                   __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Entries" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchRequest{entries:
+                                                                   __field0,
+                                                               queue_url:
+                                                                   __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["entries", "queue_url"];
+            deserializer.visit_struct("ChangeMessageVisibilityBatchRequest",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ChangeMessageVisibilityBatchRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ChangeMessageVisibilityBatchRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ChangeMessageVisibilityBatchRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Entries" ,
+                                                    &self.value.entries ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ChangeMessageVisibilityBatchRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ChangeMessageVisibilityBatchRequest>,})
+        }
+    }
+}
+/// Parse ChangeMessageVisibilityBatchRequest from XML
+struct ChangeMessageVisibilityBatchRequestParser;
+impl ChangeMessageVisibilityBatchRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ChangeMessageVisibilityBatchRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "ChangeMessageVisibilityBatchRequestEntry" {
+                obj.entries =
+                    try!(ChangeMessageVisibilityBatchRequestEntryListParser::
+                         parse_xml (
+                         "ChangeMessageVisibilityBatchRequestEntry" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchRequest contents to a SignedRequest
+struct ChangeMessageVisibilityBatchRequestWriter;
+impl ChangeMessageVisibilityBatchRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        ChangeMessageVisibilityBatchRequestEntryListWriter::write_params(params,
+                                                                         &(prefix.to_string()
+                                                                               +
+                                                                               "ChangeMessageVisibilityBatchRequestEntry"),
+                                                                         &obj.entries);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// Encloses a receipt handle and an entry id for each message in ChangeMessageVisibilityBatch.
+///
+/// All of the following parameters are list parameters that must be prefixed with `ChangeMessageVisibilityBatchRequestEntry.n`, where `n` is an integer value starting with 1. For example, a parameter list for this action might look like this:
+///
+/// `&ChangeMessageVisibilityBatchRequestEntry.1.Id=change_visibility_msg_2`
+///
+/// `&ChangeMessageVisibilityBatchRequestEntry.1.ReceiptHandle=Your_Receipt_Handle`
+///
+/// `&ChangeMessageVisibilityBatchRequestEntry.1.VisibilityTimeout=45`
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ChangeMessageVisibilityBatchRequestEntry {
+    /// An identifier for this particular receipt handle. This is used to communicate the result. Note that the `Id`s of a batch request need to be unique within the request.
+    pub id: String,
+    /// A receipt handle.
+    pub receipt_handle: String,
+    /// The new value (in seconds) for the message's visibility timeout.
+    pub visibility_timeout: Option<Integer>,
+}
+impl ::serde::de::Deserialize for ChangeMessageVisibilityBatchRequestEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     ->
+         ::std::result::Result<ChangeMessageVisibilityBatchRequestEntry,
+                               __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Id" => { Ok(__Field::__field0) }
+                                "ReceiptHandle" => { Ok(__Field::__field1) }
+                                "VisibilityTimeout" => {
+                                    Ok(__Field::__field2)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ChangeMessageVisibilityBatchRequestEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchRequestEntry{id:
+                                                                        __field0,
+                                                                    receipt_handle:
+                                                                        __field1,
+                                                                    visibility_timeout:
+                                                                        __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "ReceiptHandle"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "VisibilityTimeout" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchRequestEntry{id:
+                                                                        __field0,
+                                                                    receipt_handle:
+                                                                        __field1,
+                                                                    visibility_timeout:
+                                                                        __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["id", "receipt_handle", "visibility_timeout"];
+            deserializer.visit_struct("ChangeMessageVisibilityBatchRequestEntry",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ChangeMessageVisibilityBatchRequestEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ChangeMessageVisibilityBatchRequestEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a ChangeMessageVisibilityBatchRequestEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "ReceiptHandle" ,
+                                                    &self.value.receipt_handle
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "VisibilityTimeout" ,
+                                                    &self.value.visibility_timeout
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ChangeMessageVisibilityBatchRequestEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ChangeMessageVisibilityBatchRequestEntry>,})
+        }
+    }
+}
+/// Parse ChangeMessageVisibilityBatchRequestEntry from XML
+struct ChangeMessageVisibilityBatchRequestEntryParser;
+impl ChangeMessageVisibilityBatchRequestEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchRequestEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ChangeMessageVisibilityBatchRequestEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            if current_name == "ReceiptHandle" {
+                obj.receipt_handle =
+                    try!(StringParser:: parse_xml ( "ReceiptHandle" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "VisibilityTimeout" {
+                obj.visibility_timeout =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "VisibilityTimeout" , stack )));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchRequestEntry contents to a SignedRequest
+struct ChangeMessageVisibilityBatchRequestEntryWriter;
+impl ChangeMessageVisibilityBatchRequestEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchRequestEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "ReceiptHandle"),
+                                   &obj.receipt_handle);
+        if let Some(ref obj) = obj.visibility_timeout {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "VisibilityTimeout"), obj);
+        }
+    }
+}
+pub type ChangeMessageVisibilityBatchRequestEntryList =
+    Vec<ChangeMessageVisibilityBatchRequestEntry>;
+/// Parse ChangeMessageVisibilityBatchRequestEntryList from XML
+struct ChangeMessageVisibilityBatchRequestEntryListParser;
+impl ChangeMessageVisibilityBatchRequestEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchRequestEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) ==
+                  "ChangeMessageVisibilityBatchRequestEntry" {
+            obj.push(try!(ChangeMessageVisibilityBatchRequestEntryParser::
+                          parse_xml (
+                          "ChangeMessageVisibilityBatchRequestEntry" , stack
+                          )));
+        }
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchRequestEntryList contents to a SignedRequest
+struct ChangeMessageVisibilityBatchRequestEntryListWriter;
+impl ChangeMessageVisibilityBatchRequestEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchRequestEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            ChangeMessageVisibilityBatchRequestEntryWriter::write_params(params,
+                                                                         key,
+                                                                         &element);
+            index += 1;
+        }
+    }
+}
+/// For each message in the batch, the response contains a ChangeMessageVisibilityBatchResultEntry tag if the message succeeds or a BatchResultErrorEntry tag if the message fails.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ChangeMessageVisibilityBatchResult {
+    /// A list of BatchResultErrorEntry items.
+    pub failed: BatchResultErrorEntryList,
+    /// A list of ChangeMessageVisibilityBatchResultEntry items.
+    pub successful: ChangeMessageVisibilityBatchResultEntryList,
+}
+impl ::serde::de::Deserialize for ChangeMessageVisibilityBatchResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ChangeMessageVisibilityBatchResult, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Failed" => { Ok(__Field::__field0) }
+                                "Successful" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ChangeMessageVisibilityBatchResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchResult,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchResult{failed:
+                                                                  __field0,
+                                                              successful:
+                                                                  __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchResult,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Failed" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Successful"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchResult{failed:
+                                                                  __field0,
+                                                              successful:
+                                                                  __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["failed", "successful"];
+            deserializer.visit_struct("ChangeMessageVisibilityBatchResult",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ChangeMessageVisibilityBatchResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ChangeMessageVisibilityBatchResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a ChangeMessageVisibilityBatchResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Failed" ,
+                                                    &self.value.failed , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Successful" ,
+                                                    &self.value.successful ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("ChangeMessageVisibilityBatchResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ChangeMessageVisibilityBatchResult>,})
+        }
+    }
+}
+/// Parse ChangeMessageVisibilityBatchResult from XML
+struct ChangeMessageVisibilityBatchResultParser;
+impl ChangeMessageVisibilityBatchResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ChangeMessageVisibilityBatchResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "BatchResultErrorEntry" {
+                obj.failed =
+                    try!(BatchResultErrorEntryListParser:: parse_xml (
+                         "BatchResultErrorEntry" , stack ));
+                continue ;
+            }
+            if current_name == "ChangeMessageVisibilityBatchResultEntry" {
+                obj.successful =
+                    try!(ChangeMessageVisibilityBatchResultEntryListParser::
+                         parse_xml (
+                         "ChangeMessageVisibilityBatchResultEntry" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchResult contents to a SignedRequest
+struct ChangeMessageVisibilityBatchResultWriter;
+impl ChangeMessageVisibilityBatchResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        BatchResultErrorEntryListWriter::write_params(params,
+                                                      &(prefix.to_string() +
+                                                            "BatchResultErrorEntry"),
+                                                      &obj.failed);
+        ChangeMessageVisibilityBatchResultEntryListWriter::write_params(params,
+                                                                        &(prefix.to_string()
+                                                                              +
+                                                                              "ChangeMessageVisibilityBatchResultEntry"),
+                                                                        &obj.successful);
+    }
+}
+/// Encloses the id of an entry in ChangeMessageVisibilityBatch.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ChangeMessageVisibilityBatchResultEntry {
+    /// Represents a message whose visibility timeout has been changed successfully.
+    pub id: String,
+}
+impl ::serde::de::Deserialize for ChangeMessageVisibilityBatchResultEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     ->
+         ::std::result::Result<ChangeMessageVisibilityBatchResultEntry,
+                               __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Id" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ChangeMessageVisibilityBatchResultEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchResultEntry{id:
+                                                                       __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityBatchResultEntry{id:
+                                                                       __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["id"];
+            deserializer.visit_struct("ChangeMessageVisibilityBatchResultEntry",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ChangeMessageVisibilityBatchResultEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ChangeMessageVisibilityBatchResultEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a ChangeMessageVisibilityBatchResultEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ChangeMessageVisibilityBatchResultEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ChangeMessageVisibilityBatchResultEntry>,})
+        }
+    }
+}
+/// Parse ChangeMessageVisibilityBatchResultEntry from XML
+struct ChangeMessageVisibilityBatchResultEntryParser;
+impl ChangeMessageVisibilityBatchResultEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchResultEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ChangeMessageVisibilityBatchResultEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchResultEntry contents to a SignedRequest
+struct ChangeMessageVisibilityBatchResultEntryWriter;
+impl ChangeMessageVisibilityBatchResultEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchResultEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+    }
+}
+pub type ChangeMessageVisibilityBatchResultEntryList =
+    Vec<ChangeMessageVisibilityBatchResultEntry>;
+/// Parse ChangeMessageVisibilityBatchResultEntryList from XML
+struct ChangeMessageVisibilityBatchResultEntryListParser;
+impl ChangeMessageVisibilityBatchResultEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityBatchResultEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) ==
+                  "ChangeMessageVisibilityBatchResultEntry" {
+            obj.push(try!(ChangeMessageVisibilityBatchResultEntryParser::
+                          parse_xml (
+                          "ChangeMessageVisibilityBatchResultEntry" , stack
+                          )));
+        }
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityBatchResultEntryList contents to a SignedRequest
+struct ChangeMessageVisibilityBatchResultEntryListWriter;
+impl ChangeMessageVisibilityBatchResultEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityBatchResultEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            ChangeMessageVisibilityBatchResultEntryWriter::write_params(params,
+                                                                        key,
+                                                                        &element);
+            index += 1;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ChangeMessageVisibilityRequest {
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+    /// The receipt handle associated with the message whose visibility timeout should be changed. This parameter is returned by the ReceiveMessage action.
+    pub receipt_handle: String,
+    /// The new value (in seconds - from 0 to 43200 - maximum 12 hours) for the message's visibility timeout.
+    pub visibility_timeout: Integer,
+}
+impl ::serde::de::Deserialize for ChangeMessageVisibilityRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ChangeMessageVisibilityRequest, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                "ReceiptHandle" => { Ok(__Field::__field1) }
+                                "VisibilityTimeout" => {
+                                    Ok(__Field::__field2)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ChangeMessageVisibilityRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityRequest{queue_url: __field0,
+                                                          receipt_handle:
+                                                              __field1,
+                                                          visibility_timeout:
+                                                              __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ChangeMessageVisibilityRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "ReceiptHandle"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "VisibilityTimeout" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ChangeMessageVisibilityRequest{queue_url: __field0,
+                                                          receipt_handle:
+                                                              __field1,
+                                                          visibility_timeout:
+                                                              __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["queue_url", "receipt_handle", "visibility_timeout"];
+            deserializer.visit_struct("ChangeMessageVisibilityRequest",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ChangeMessageVisibilityRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ChangeMessageVisibilityRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ChangeMessageVisibilityRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "ReceiptHandle" ,
+                                                    &self.value.receipt_handle
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "VisibilityTimeout" ,
+                                                    &self.value.visibility_timeout
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("ChangeMessageVisibilityRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ChangeMessageVisibilityRequest>,})
+        }
+    }
+}
+/// Parse ChangeMessageVisibilityRequest from XML
+struct ChangeMessageVisibilityRequestParser;
+impl ChangeMessageVisibilityRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ChangeMessageVisibilityRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ChangeMessageVisibilityRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            if current_name == "ReceiptHandle" {
+                obj.receipt_handle =
+                    try!(StringParser:: parse_xml ( "ReceiptHandle" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "VisibilityTimeout" {
+                obj.visibility_timeout =
+                    try!(IntegerParser:: parse_xml (
+                         "VisibilityTimeout" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ChangeMessageVisibilityRequest contents to a SignedRequest
+struct ChangeMessageVisibilityRequestWriter;
+impl ChangeMessageVisibilityRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ChangeMessageVisibilityRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "ReceiptHandle"),
+                                   &obj.receipt_handle);
+        IntegerWriter::write_params(params,
+                                    &(prefix.to_string() +
+                                          "VisibilityTimeout"),
+                                    &obj.visibility_timeout);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct CreateQueueRequest {
+    /// A map of attributes with their corresponding values.
+    ///
+    /// The following lists the names, descriptions, and values of the special request parameters the `CreateQueue` action uses:
+    ///
+    /// * `DelaySeconds` - The time in seconds that the delivery of all messages in the queue will be delayed. An integer from 0 to 900 (15 minutes). The default for this attribute is 0 (zero).
+    /// * `MaximumMessageSize` - The limit of how many bytes a message can contain before Amazon SQS rejects it. An integer from 1024 bytes (1 KiB) up to 262144 bytes (256 KiB). The default for this attribute is 262144 (256 KiB).
+    /// * `MessageRetentionPeriod` - The number of seconds Amazon SQS retains a message. Integer representing seconds, from 60 (1 minute) to 1209600 (14 days). The default for this attribute is 345600 (4 days).
+    /// * `Policy` - The queue's policy. A valid AWS policy. For more information about policy structure, see [Overview of AWS IAM Policies](http://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html) in the *Amazon IAM User Guide*.
+    /// * `ReceiveMessageWaitTimeSeconds` - The time for which a ReceiveMessage call will wait for a message to arrive. An integer from 0 to 20 (seconds). The default for this attribute is 0.
+    /// * `VisibilityTimeout` - The visibility timeout for the queue. An integer from 0 to 43200 (12 hours). The default for this attribute is 30. For more information about visibility timeout, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.
+    pub attributes: Option<AttributeMap>,
+    /// The name for the queue to be created.
+    pub queue_name: String,
+}
+impl ::serde::de::Deserialize for CreateQueueRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateQueueRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Attributes" => { Ok(__Field::__field0) }
+                                "QueueName" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateQueueRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateQueueRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateQueueRequest{attributes: __field0,
+                                              queue_name: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateQueueRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Attributes"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueName" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateQueueRequest{attributes: __field0,
+                                              queue_name: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["attributes", "queue_name"];
+            deserializer.visit_struct("CreateQueueRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateQueueRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateQueueRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateQueueRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Attributes" ,
+                                                    &self.value.attributes ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueName" ,
+                                                    &self.value.queue_name ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("CreateQueueRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateQueueRequest>,})
+        }
+    }
+}
+/// Parse CreateQueueRequest from XML
+struct CreateQueueRequestParser;
+impl CreateQueueRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<CreateQueueRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = CreateQueueRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Attribute" {
+                obj.attributes =
+                    Some(try!(AttributeMapParser:: parse_xml (
+                              "Attribute" , stack )));
+                continue ;
+            }
+            if current_name == "QueueName" {
+                obj.queue_name =
+                    try!(StringParser:: parse_xml ( "QueueName" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write CreateQueueRequest contents to a SignedRequest
+struct CreateQueueRequestWriter;
+impl CreateQueueRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &CreateQueueRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.attributes {
+            AttributeMapWriter::write_params(params,
+                                             &(prefix.to_string() +
+                                                   "Attribute"), obj);
+        }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "QueueName"),
+                                   &obj.queue_name);
+    }
+}
+/// Returns the QueueUrl element of the created queue.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct CreateQueueResult {
+    /// The URL for the created Amazon SQS queue.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for CreateQueueResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<CreateQueueResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                CreateQueueResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateQueueResult, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateQueueResult{queue_url: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<CreateQueueResult, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(CreateQueueResult{queue_url: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_url"];
+            deserializer.visit_struct("CreateQueueResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for CreateQueueResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a CreateQueueResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a CreateQueueResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("CreateQueueResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&CreateQueueResult>,})
+        }
+    }
+}
+/// Parse CreateQueueResult from XML
+struct CreateQueueResultParser;
+impl CreateQueueResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<CreateQueueResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = CreateQueueResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write CreateQueueResult contents to a SignedRequest
+struct CreateQueueResultWriter;
+impl CreateQueueResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &CreateQueueResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteMessageBatchRequest {
+    /// A list of receipt handles for the messages to be deleted.
+    pub entries: DeleteMessageBatchRequestEntryList,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for DeleteMessageBatchRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteMessageBatchRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Entries" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteMessageBatchRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchRequest{entries: __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Entries" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchRequest{entries: __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["entries", "queue_url"];
+            deserializer.visit_struct("DeleteMessageBatchRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteMessageBatchRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteMessageBatchRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteMessageBatchRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Entries" ,
+                                                    &self.value.entries ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DeleteMessageBatchRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteMessageBatchRequest>,})
+        }
+    }
+}
+/// Parse DeleteMessageBatchRequest from XML
+struct DeleteMessageBatchRequestParser;
+impl DeleteMessageBatchRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteMessageBatchRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "DeleteMessageBatchRequestEntry" {
+                obj.entries =
+                    try!(DeleteMessageBatchRequestEntryListParser:: parse_xml
+                         ( "DeleteMessageBatchRequestEntry" , stack ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchRequest contents to a SignedRequest
+struct DeleteMessageBatchRequestWriter;
+impl DeleteMessageBatchRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        DeleteMessageBatchRequestEntryListWriter::write_params(params,
+                                                               &(prefix.to_string()
+                                                                     +
+                                                                     "DeleteMessageBatchRequestEntry"),
+                                                               &obj.entries);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// Encloses a receipt handle and an identifier for it.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteMessageBatchRequestEntry {
+    /// An identifier for this particular receipt handle. This is used to communicate the result. Note that the `Id`s of a batch request need to be unique within the request.
+    pub id: String,
+    /// A receipt handle.
+    pub receipt_handle: String,
+}
+impl ::serde::de::Deserialize for DeleteMessageBatchRequestEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteMessageBatchRequestEntry, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Id" => { Ok(__Field::__field0) }
+                                "ReceiptHandle" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteMessageBatchRequestEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchRequestEntry{id: __field0,
+                                                          receipt_handle:
+                                                              __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "ReceiptHandle"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchRequestEntry{id: __field0,
+                                                          receipt_handle:
+                                                              __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["id", "receipt_handle"];
+            deserializer.visit_struct("DeleteMessageBatchRequestEntry",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteMessageBatchRequestEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteMessageBatchRequestEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteMessageBatchRequestEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "ReceiptHandle" ,
+                                                    &self.value.receipt_handle
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DeleteMessageBatchRequestEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteMessageBatchRequestEntry>,})
+        }
+    }
+}
+/// Parse DeleteMessageBatchRequestEntry from XML
+struct DeleteMessageBatchRequestEntryParser;
+impl DeleteMessageBatchRequestEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchRequestEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteMessageBatchRequestEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            if current_name == "ReceiptHandle" {
+                obj.receipt_handle =
+                    try!(StringParser:: parse_xml ( "ReceiptHandle" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchRequestEntry contents to a SignedRequest
+struct DeleteMessageBatchRequestEntryWriter;
+impl DeleteMessageBatchRequestEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchRequestEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "ReceiptHandle"),
+                                   &obj.receipt_handle);
+    }
+}
+pub type DeleteMessageBatchRequestEntryList =
+    Vec<DeleteMessageBatchRequestEntry>;
+/// Parse DeleteMessageBatchRequestEntryList from XML
+struct DeleteMessageBatchRequestEntryListParser;
+impl DeleteMessageBatchRequestEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchRequestEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "DeleteMessageBatchRequestEntry"
+              {
+            obj.push(try!(DeleteMessageBatchRequestEntryParser:: parse_xml (
+                          "DeleteMessageBatchRequestEntry" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchRequestEntryList contents to a SignedRequest
+struct DeleteMessageBatchRequestEntryListWriter;
+impl DeleteMessageBatchRequestEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchRequestEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            DeleteMessageBatchRequestEntryWriter::write_params(params, key,
+                                                               &element);
+            index += 1;
+        }
+    }
+}
+/// For each message in the batch, the response contains a DeleteMessageBatchResultEntry tag if the message is deleted or a BatchResultErrorEntry tag if the message cannot be deleted.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteMessageBatchResult {
+    /// A list of BatchResultErrorEntry items.
+    pub failed: BatchResultErrorEntryList,
+    /// A list of DeleteMessageBatchResultEntry items.
+    pub successful: DeleteMessageBatchResultEntryList,
+}
+impl ::serde::de::Deserialize for DeleteMessageBatchResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteMessageBatchResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Failed" => { Ok(__Field::__field0) }
+                                "Successful" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteMessageBatchResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchResult,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchResult{failed: __field0,
+                                                    successful: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchResult,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Failed" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Successful"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchResult{failed: __field0,
+                                                    successful: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["failed", "successful"];
+            deserializer.visit_struct("DeleteMessageBatchResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteMessageBatchResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteMessageBatchResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteMessageBatchResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Failed" ,
+                                                    &self.value.failed , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Successful" ,
+                                                    &self.value.successful ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DeleteMessageBatchResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteMessageBatchResult>,})
+        }
+    }
+}
+/// Parse DeleteMessageBatchResult from XML
+struct DeleteMessageBatchResultParser;
+impl DeleteMessageBatchResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteMessageBatchResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "BatchResultErrorEntry" {
+                obj.failed =
+                    try!(BatchResultErrorEntryListParser:: parse_xml (
+                         "BatchResultErrorEntry" , stack ));
+                continue ;
+            }
+            if current_name == "DeleteMessageBatchResultEntry" {
+                obj.successful =
+                    try!(DeleteMessageBatchResultEntryListParser:: parse_xml (
+                         "DeleteMessageBatchResultEntry" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchResult contents to a SignedRequest
+struct DeleteMessageBatchResultWriter;
+impl DeleteMessageBatchResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        BatchResultErrorEntryListWriter::write_params(params,
+                                                      &(prefix.to_string() +
+                                                            "BatchResultErrorEntry"),
+                                                      &obj.failed);
+        DeleteMessageBatchResultEntryListWriter::write_params(params,
+                                                              &(prefix.to_string()
+                                                                    +
+                                                                    "DeleteMessageBatchResultEntry"),
+                                                              &obj.successful);
+    }
+}
+/// Encloses the id an entry in DeleteMessageBatch.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteMessageBatchResultEntry {
+    /// Represents a successfully deleted message.
+    pub id: String,
+}
+impl ::serde::de::Deserialize for DeleteMessageBatchResultEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteMessageBatchResultEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Id" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteMessageBatchResultEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchResultEntry{id: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<DeleteMessageBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageBatchResultEntry{id: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["id"];
+            deserializer.visit_struct("DeleteMessageBatchResultEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteMessageBatchResultEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteMessageBatchResultEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteMessageBatchResultEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DeleteMessageBatchResultEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteMessageBatchResultEntry>,})
+        }
+    }
+}
+/// Parse DeleteMessageBatchResultEntry from XML
+struct DeleteMessageBatchResultEntryParser;
+impl DeleteMessageBatchResultEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchResultEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteMessageBatchResultEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchResultEntry contents to a SignedRequest
+struct DeleteMessageBatchResultEntryWriter;
+impl DeleteMessageBatchResultEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchResultEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+    }
+}
+pub type DeleteMessageBatchResultEntryList =
+    Vec<DeleteMessageBatchResultEntry>;
+/// Parse DeleteMessageBatchResultEntryList from XML
+struct DeleteMessageBatchResultEntryListParser;
+impl DeleteMessageBatchResultEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageBatchResultEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "DeleteMessageBatchResultEntry"
+              {
+            obj.push(try!(DeleteMessageBatchResultEntryParser:: parse_xml (
+                          "DeleteMessageBatchResultEntry" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageBatchResultEntryList contents to a SignedRequest
+struct DeleteMessageBatchResultEntryListWriter;
+impl DeleteMessageBatchResultEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageBatchResultEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            DeleteMessageBatchResultEntryWriter::write_params(params, key,
+                                                              &element);
+            index += 1;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteMessageRequest {
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+    /// The receipt handle associated with the message to delete.
+    pub receipt_handle: String,
+}
+impl ::serde::de::Deserialize for DeleteMessageRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteMessageRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                "ReceiptHandle" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteMessageRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteMessageRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageRequest{queue_url: __field0,
+                                                receipt_handle: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteMessageRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "ReceiptHandle"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteMessageRequest{queue_url: __field0,
+                                                receipt_handle: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["queue_url", "receipt_handle"];
+            deserializer.visit_struct("DeleteMessageRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteMessageRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteMessageRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteMessageRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "ReceiptHandle" ,
+                                                    &self.value.receipt_handle
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("DeleteMessageRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteMessageRequest>,})
+        }
+    }
+}
+/// Parse DeleteMessageRequest from XML
+struct DeleteMessageRequestParser;
+impl DeleteMessageRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteMessageRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteMessageRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            if current_name == "ReceiptHandle" {
+                obj.receipt_handle =
+                    try!(StringParser:: parse_xml ( "ReceiptHandle" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteMessageRequest contents to a SignedRequest
+struct DeleteMessageRequestWriter;
+impl DeleteMessageRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteMessageRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "ReceiptHandle"),
+                                   &obj.receipt_handle);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DeleteQueueRequest {
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for DeleteQueueRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<DeleteQueueRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                DeleteQueueRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteQueueRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteQueueRequest{queue_url: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<DeleteQueueRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(DeleteQueueRequest{queue_url: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_url"];
+            deserializer.visit_struct("DeleteQueueRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for DeleteQueueRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a DeleteQueueRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a DeleteQueueRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("DeleteQueueRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&DeleteQueueRequest>,})
+        }
+    }
+}
+/// Parse DeleteQueueRequest from XML
+struct DeleteQueueRequestParser;
+impl DeleteQueueRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<DeleteQueueRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = DeleteQueueRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write DeleteQueueRequest contents to a SignedRequest
+struct DeleteQueueRequestWriter;
+impl DeleteQueueRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &DeleteQueueRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// Batch request does not contain an entry.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct EmptyBatchRequest;
+impl ::serde::de::Deserialize for EmptyBatchRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<EmptyBatchRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                EmptyBatchRequest;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<EmptyBatchRequest, E> where
+                 E: ::serde::de::Error {
+                    Ok(EmptyBatchRequest)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<EmptyBatchRequest, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("EmptyBatchRequest", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for EmptyBatchRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("EmptyBatchRequest")
+    }
+}
+/// Parse EmptyBatchRequest from XML
+struct EmptyBatchRequestParser;
+impl EmptyBatchRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<EmptyBatchRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = EmptyBatchRequest::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write EmptyBatchRequest contents to a SignedRequest
+struct EmptyBatchRequestWriter;
+impl EmptyBatchRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &EmptyBatchRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct GetQueueAttributesRequest {
+    /// A list of attributes to retrieve information for.
+    pub attribute_names: Option<AttributeNameList>,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for GetQueueAttributesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetQueueAttributesRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AttributeNames" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetQueueAttributesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetQueueAttributesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueAttributesRequest{attribute_names:
+                                                         __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetQueueAttributesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "AttributeNames" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueAttributesRequest{attribute_names:
+                                                         __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["attribute_names", "queue_url"];
+            deserializer.visit_struct("GetQueueAttributesRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetQueueAttributesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetQueueAttributesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetQueueAttributesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AttributeNames" ,
+                                                    &self.value.attribute_names
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("GetQueueAttributesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetQueueAttributesRequest>,})
+        }
+    }
+}
+/// Parse GetQueueAttributesRequest from XML
+struct GetQueueAttributesRequestParser;
+impl GetQueueAttributesRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<GetQueueAttributesRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = GetQueueAttributesRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "AttributeName" {
+                obj.attribute_names =
+                    Some(try!(AttributeNameListParser:: parse_xml (
+                              "AttributeName" , stack )));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write GetQueueAttributesRequest contents to a SignedRequest
+struct GetQueueAttributesRequestWriter;
+impl GetQueueAttributesRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &GetQueueAttributesRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.attribute_names {
+            AttributeNameListWriter::write_params(params,
+                                                  &(prefix.to_string() +
+                                                        "AttributeName"),
+                                                  obj);
+        }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// A list of returned queue attributes.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct GetQueueAttributesResult {
+    /// A map of attributes to the respective values.
+    pub attributes: AttributeMap,
+}
+impl ::serde::de::Deserialize for GetQueueAttributesResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetQueueAttributesResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Attributes" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetQueueAttributesResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetQueueAttributesResult,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueAttributesResult{attributes: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<GetQueueAttributesResult,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Attributes"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueAttributesResult{attributes: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["attributes"];
+            deserializer.visit_struct("GetQueueAttributesResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetQueueAttributesResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetQueueAttributesResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetQueueAttributesResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Attributes" ,
+                                                    &self.value.attributes ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GetQueueAttributesResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetQueueAttributesResult>,})
+        }
+    }
+}
+/// Parse GetQueueAttributesResult from XML
+struct GetQueueAttributesResultParser;
+impl GetQueueAttributesResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<GetQueueAttributesResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = GetQueueAttributesResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Attribute" {
+                obj.attributes =
+                    try!(AttributeMapParser:: parse_xml ( "Attribute" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write GetQueueAttributesResult contents to a SignedRequest
+struct GetQueueAttributesResultWriter;
+impl GetQueueAttributesResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &GetQueueAttributesResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        AttributeMapWriter::write_params(params,
+                                         &(prefix.to_string() + "Attribute"),
+                                         &obj.attributes);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct GetQueueUrlRequest {
+    /// The name of the queue whose URL must be fetched. Maximum 80 characters; alphanumeric characters, hyphens (-), and underscores (_) are allowed.
+    pub queue_name: String,
+    /// The AWS account ID of the account that created the queue.
+    pub queue_owner_aws_account_id: Option<String>,
+}
+impl ::serde::de::Deserialize for GetQueueUrlRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetQueueUrlRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueName" => { Ok(__Field::__field0) }
+                                "QueueOwnerAWSAccountId" => {
+                                    Ok(__Field::__field1)
+                                }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetQueueUrlRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetQueueUrlRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueUrlRequest{queue_name: __field0,
+                                              queue_owner_aws_account_id:
+                                                  __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetQueueUrlRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueName" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "QueueOwnerAWSAccountId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueUrlRequest{queue_name: __field0,
+                                              queue_owner_aws_account_id:
+                                                  __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["queue_name", "queue_owner_aws_account_id"];
+            deserializer.visit_struct("GetQueueUrlRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetQueueUrlRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetQueueUrlRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetQueueUrlRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueName" ,
+                                                    &self.value.queue_name ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueOwnerAWSAccountId" ,
+                                                    &self.value.queue_owner_aws_account_id
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("GetQueueUrlRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetQueueUrlRequest>,})
+        }
+    }
+}
+/// Parse GetQueueUrlRequest from XML
+struct GetQueueUrlRequestParser;
+impl GetQueueUrlRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<GetQueueUrlRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = GetQueueUrlRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueName" {
+                obj.queue_name =
+                    try!(StringParser:: parse_xml ( "QueueName" , stack ));
+                continue ;
+            }
+            if current_name == "QueueOwnerAWSAccountId" {
+                obj.queue_owner_aws_account_id =
+                    Some(try!(StringParser:: parse_xml (
+                              "QueueOwnerAWSAccountId" , stack )));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write GetQueueUrlRequest contents to a SignedRequest
+struct GetQueueUrlRequestWriter;
+impl GetQueueUrlRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &GetQueueUrlRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "QueueName"),
+                                   &obj.queue_name);
+        if let Some(ref obj) = obj.queue_owner_aws_account_id {
+            StringWriter::write_params(params,
+                                       &(prefix.to_string() +
+                                             "QueueOwnerAWSAccountId"), obj);
+        }
+    }
+}
+/// For more information, see [Responses](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/UnderstandingResponses.html) in the *Amazon SQS Developer Guide*.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct GetQueueUrlResult {
+    /// The URL for the queue.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for GetQueueUrlResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<GetQueueUrlResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                GetQueueUrlResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetQueueUrlResult, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueUrlResult{queue_url: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<GetQueueUrlResult, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(GetQueueUrlResult{queue_url: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_url"];
+            deserializer.visit_struct("GetQueueUrlResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for GetQueueUrlResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a GetQueueUrlResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a GetQueueUrlResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("GetQueueUrlResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&GetQueueUrlResult>,})
+        }
+    }
+}
+/// Parse GetQueueUrlResult from XML
+struct GetQueueUrlResultParser;
+impl GetQueueUrlResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<GetQueueUrlResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = GetQueueUrlResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write GetQueueUrlResult contents to a SignedRequest
+struct GetQueueUrlResultWriter;
+impl GetQueueUrlResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &GetQueueUrlResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+pub type Integer = i32;
+/// Parse Integer from XML
+struct IntegerParser;
+impl IntegerParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<Integer, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj =
+            try!(i32:: from_str ( try ! ( characters ( stack ) ) . as_ref (  )
+                 ));
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write Integer contents to a SignedRequest
+struct IntegerWriter;
+impl IntegerWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &Integer) {
+        params.put(name, &obj.to_string());
+    }
+}
+/// The attribute referred to does not exist.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct InvalidAttributeName;
+impl ::serde::de::Deserialize for InvalidAttributeName {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidAttributeName, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                InvalidAttributeName;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<InvalidAttributeName, E> where
+                 E: ::serde::de::Error {
+                    Ok(InvalidAttributeName)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<InvalidAttributeName, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("InvalidAttributeName", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidAttributeName {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("InvalidAttributeName")
+    }
+}
+/// Parse InvalidAttributeName from XML
+struct InvalidAttributeNameParser;
+impl InvalidAttributeNameParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<InvalidAttributeName, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = InvalidAttributeName::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write InvalidAttributeName contents to a SignedRequest
+struct InvalidAttributeNameWriter;
+impl InvalidAttributeNameWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &InvalidAttributeName) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The `Id` of a batch entry in a batch request does not abide by the specification.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct InvalidBatchEntryId;
+impl ::serde::de::Deserialize for InvalidBatchEntryId {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidBatchEntryId, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                InvalidBatchEntryId;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<InvalidBatchEntryId, E> where
+                 E: ::serde::de::Error {
+                    Ok(InvalidBatchEntryId)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<InvalidBatchEntryId, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("InvalidBatchEntryId", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidBatchEntryId {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("InvalidBatchEntryId")
+    }
+}
+/// Parse InvalidBatchEntryId from XML
+struct InvalidBatchEntryIdParser;
+impl InvalidBatchEntryIdParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<InvalidBatchEntryId, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = InvalidBatchEntryId::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write InvalidBatchEntryId contents to a SignedRequest
+struct InvalidBatchEntryIdWriter;
+impl InvalidBatchEntryIdWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &InvalidBatchEntryId) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The receipt handle is not valid for the current version.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct InvalidIdFormat;
+impl ::serde::de::Deserialize for InvalidIdFormat {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidIdFormat, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                InvalidIdFormat;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<InvalidIdFormat, E> where
+                 E: ::serde::de::Error {
+                    Ok(InvalidIdFormat)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<InvalidIdFormat, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("InvalidIdFormat", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidIdFormat {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("InvalidIdFormat")
+    }
+}
+/// Parse InvalidIdFormat from XML
+struct InvalidIdFormatParser;
+impl InvalidIdFormatParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<InvalidIdFormat, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = InvalidIdFormat::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write InvalidIdFormat contents to a SignedRequest
+struct InvalidIdFormatWriter;
+impl InvalidIdFormatWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &InvalidIdFormat) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The message contains characters outside the allowed set.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct InvalidMessageContents;
+impl ::serde::de::Deserialize for InvalidMessageContents {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<InvalidMessageContents, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                InvalidMessageContents;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<InvalidMessageContents, E> where
+                 E: ::serde::de::Error {
+                    Ok(InvalidMessageContents)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<InvalidMessageContents, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("InvalidMessageContents",
+                                           __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for InvalidMessageContents {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("InvalidMessageContents")
+    }
+}
+/// Parse InvalidMessageContents from XML
+struct InvalidMessageContentsParser;
+impl InvalidMessageContentsParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<InvalidMessageContents, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = InvalidMessageContents::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write InvalidMessageContents contents to a SignedRequest
+struct InvalidMessageContentsWriter;
+impl InvalidMessageContentsWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &InvalidMessageContents) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ListDeadLetterSourceQueuesRequest {
+    /// The queue URL of a dead letter queue.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for ListDeadLetterSourceQueuesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListDeadLetterSourceQueuesRequest, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListDeadLetterSourceQueuesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListDeadLetterSourceQueuesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListDeadLetterSourceQueuesRequest{queue_url:
+                                                                 __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListDeadLetterSourceQueuesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListDeadLetterSourceQueuesRequest{queue_url:
+                                                                 __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_url"];
+            deserializer.visit_struct("ListDeadLetterSourceQueuesRequest",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListDeadLetterSourceQueuesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListDeadLetterSourceQueuesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListDeadLetterSourceQueuesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ListDeadLetterSourceQueuesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListDeadLetterSourceQueuesRequest>,})
+        }
+    }
+}
+/// Parse ListDeadLetterSourceQueuesRequest from XML
+struct ListDeadLetterSourceQueuesRequestParser;
+impl ListDeadLetterSourceQueuesRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ListDeadLetterSourceQueuesRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ListDeadLetterSourceQueuesRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ListDeadLetterSourceQueuesRequest contents to a SignedRequest
+struct ListDeadLetterSourceQueuesRequestWriter;
+impl ListDeadLetterSourceQueuesRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ListDeadLetterSourceQueuesRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// A list of your dead letter source queues.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ListDeadLetterSourceQueuesResult {
+    /// A list of source queue URLs that have the RedrivePolicy queue attribute configured with a dead letter queue.
+    pub queue_urls: QueueUrlList,
+}
+impl ::serde::de::Deserialize for ListDeadLetterSourceQueuesResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListDeadLetterSourceQueuesResult, __D::Error>
+     where __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "queueUrls" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListDeadLetterSourceQueuesResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListDeadLetterSourceQueuesResult,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListDeadLetterSourceQueuesResult{queue_urls:
+                                                                __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<ListDeadLetterSourceQueuesResult,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "queueUrls" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListDeadLetterSourceQueuesResult{queue_urls:
+                                                                __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_urls"];
+            deserializer.visit_struct("ListDeadLetterSourceQueuesResult",
+                                      FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListDeadLetterSourceQueuesResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListDeadLetterSourceQueuesResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListDeadLetterSourceQueuesResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "queueUrls" ,
+                                                    &self.value.queue_urls ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ListDeadLetterSourceQueuesResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListDeadLetterSourceQueuesResult>,})
+        }
+    }
+}
+/// Parse ListDeadLetterSourceQueuesResult from XML
+struct ListDeadLetterSourceQueuesResultParser;
+impl ListDeadLetterSourceQueuesResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ListDeadLetterSourceQueuesResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ListDeadLetterSourceQueuesResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_urls =
+                    try!(QueueUrlListParser:: parse_xml ( "QueueUrl" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ListDeadLetterSourceQueuesResult contents to a SignedRequest
+struct ListDeadLetterSourceQueuesResultWriter;
+impl ListDeadLetterSourceQueuesResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ListDeadLetterSourceQueuesResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        QueueUrlListWriter::write_params(params,
+                                         &(prefix.to_string() + "QueueUrl"),
+                                         &obj.queue_urls);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ListQueuesRequest {
+    /// A string to use for filtering the list results. Only those queues whose name begins with the specified string are returned.
+    pub queue_name_prefix: String,
+}
+impl ::serde::de::Deserialize for ListQueuesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListQueuesRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueNamePrefix" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListQueuesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListQueuesRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListQueuesRequest{queue_name_prefix: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListQueuesRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "QueueNamePrefix" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListQueuesRequest{queue_name_prefix: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_name_prefix"];
+            deserializer.visit_struct("ListQueuesRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListQueuesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListQueuesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListQueuesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueNamePrefix" ,
+                                                    &self.value.queue_name_prefix
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ListQueuesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListQueuesRequest>,})
+        }
+    }
+}
+/// Parse ListQueuesRequest from XML
+struct ListQueuesRequestParser;
+impl ListQueuesRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ListQueuesRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ListQueuesRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueNamePrefix" {
+                obj.queue_name_prefix =
+                    try!(StringParser:: parse_xml ( "QueueNamePrefix" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ListQueuesRequest contents to a SignedRequest
+struct ListQueuesRequestWriter;
+impl ListQueuesRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ListQueuesRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "QueueNamePrefix"),
+                                   &obj.queue_name_prefix);
+    }
+}
+/// A list of your queues.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ListQueuesResult {
+    /// A list of queue URLs, up to 1000 entries.
+    pub queue_urls: QueueUrlList,
+}
+impl ::serde::de::Deserialize for ListQueuesResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ListQueuesResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrls" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ListQueuesResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListQueuesResult, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListQueuesResult{queue_urls: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ListQueuesResult, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrls" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ListQueuesResult{queue_urls: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_urls"];
+            deserializer.visit_struct("ListQueuesResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ListQueuesResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ListQueuesResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a ListQueuesResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrls" ,
+                                                    &self.value.queue_urls ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ListQueuesResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ListQueuesResult>,})
+        }
+    }
+}
+/// Parse ListQueuesResult from XML
+struct ListQueuesResultParser;
+impl ListQueuesResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ListQueuesResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ListQueuesResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_urls =
+                    try!(QueueUrlListParser:: parse_xml ( "QueueUrl" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ListQueuesResult contents to a SignedRequest
+struct ListQueuesResultWriter;
+impl ListQueuesResultWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &ListQueuesResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        QueueUrlListWriter::write_params(params,
+                                         &(prefix.to_string() + "QueueUrl"),
+                                         &obj.queue_urls);
+    }
+}
+/// An Amazon SQS message.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct Message {
+    /// `SenderId`, `SentTimestamp`, `ApproximateReceiveCount`, and/or `ApproximateFirstReceiveTimestamp`. `SentTimestamp` and `ApproximateFirstReceiveTimestamp` are each returned as an integer representing the [epoch time](http://en.wikipedia.org/wiki/Unix_time) in milliseconds.
+    pub attributes: AttributeMap,
+    /// The message's contents (not URL-encoded).
+    pub body: String,
+    /// An MD5 digest of the non-URL-encoded message body string.
+    pub md5_of_body: String,
+    /// An MD5 digest of the non-URL-encoded message attribute string. This can be used to verify that Amazon SQS received the message correctly. Amazon SQS first URL decodes the message before creating the MD5 digest. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    pub md5_of_message_attributes: String,
+    /// Each message attribute consists of a Name, Type, and Value. For more information, see [Message Attribute Items](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSMessageAttributes.html#SQSMessageAttributesNTV).
+    pub message_attributes: MessageAttributeMap,
+    /// A unique identifier for the message. Message IDs are considered unique across all AWS accounts for an extended period of time.
+    pub message_id: String,
+    /// An identifier associated with the act of receiving the message. A new receipt handle is returned every time you receive a message. When deleting a message, you provide the last received receipt handle to delete the message.
+    pub receipt_handle: String,
+}
+impl ::serde::de::Deserialize for Message {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<Message, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field {
+                __field0,
+                __field1,
+                __field2,
+                __field3,
+                __field4,
+                __field5,
+                __field6,
+            }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                5usize => { Ok(__Field::__field5) }
+                                6usize => { Ok(__Field::__field6) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Attributes" => { Ok(__Field::__field0) }
+                                "Body" => { Ok(__Field::__field1) }
+                                "MD5OfBody" => { Ok(__Field::__field2) }
+                                "MD5OfMessageAttributes" => {
+                                    Ok(__Field::__field3)
+                                }
+                                "MessageAttributes" => {
+                                    Ok(__Field::__field4)
+                                }
+                                "MessageId" => { Ok(__Field::__field5) }
+                                "ReceiptHandle" => { Ok(__Field::__field6) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                Message;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<Message, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field5 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field6 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(Message{attributes: __field0,
+                                   body: __field1,
+                                   md5_of_body: __field2,
+                                   md5_of_message_attributes: __field3,
+                                   message_attributes: __field4,
+                                   message_id: __field5,
+                                   receipt_handle: __field6,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<Message, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        let mut __field5 = None;
+                        let mut __field6 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field5 => {
+                                    __field5 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field6 => {
+                                    __field6 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Attributes"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Body" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "MD5OfBody" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MD5OfMessageAttributes" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MessageAttributes" )),
+                            };
+                        let __field5 =
+                            match __field5 {
+                                Some(__field5) => __field5,
+                                None =>
+                                try!(visitor . missing_field ( "MessageId" )),
+                            };
+                        let __field6 =
+                            match __field6 {
+                                Some(__field6) => __field6,
+                                None =>
+                                try!(visitor . missing_field ( "ReceiptHandle"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(Message{attributes: __field0,
+                                   body: __field1,
+                                   md5_of_body: __field2,
+                                   md5_of_message_attributes: __field3,
+                                   message_attributes: __field4,
+                                   message_id: __field5,
+                                   receipt_handle: __field6,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["attributes", "body", "md5_of_body",
+                  "md5_of_message_attributes", "message_attributes",
+                  "message_id", "receipt_handle"];
+            deserializer.visit_struct("Message", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for Message {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a Message,
+                _structure_ty: ::std::marker::PhantomData<&'__a Message>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Attributes" ,
+                                                    &self.value.attributes ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Body" , &self.value.body
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfBody" ,
+                                                    &self.value.md5_of_body ,
+                                                    ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfMessageAttributes" ,
+                                                    &self.value.md5_of_message_attributes
+                                                    , ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageAttributes" ,
+                                                    &self.value.message_attributes
+                                                    , ))));
+                            }
+                            5usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageId" ,
+                                                    &self.value.message_id ,
+                                                    ))));
+                            }
+                            6usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "ReceiptHandle" ,
+                                                    &self.value.receipt_handle
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> {
+                    Some(0 + 1 + 1 + 1 + 1 + 1 + 1 + 1)
+                }
+            }
+            serializer.visit_struct("Message",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&Message>,})
+        }
+    }
+}
+/// Parse Message from XML
+struct MessageParser;
+impl MessageParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<Message, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = Message::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Attribute" {
+                obj.attributes =
+                    try!(AttributeMapParser:: parse_xml ( "Attribute" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "Body" {
+                obj.body = try!(StringParser:: parse_xml ( "Body" , stack ));
+                continue ;
+            }
+            if current_name == "MD5OfBody" {
+                obj.md5_of_body =
+                    try!(StringParser:: parse_xml ( "MD5OfBody" , stack ));
+                continue ;
+            }
+            if current_name == "MD5OfMessageAttributes" {
+                obj.md5_of_message_attributes =
+                    try!(StringParser:: parse_xml (
+                         "MD5OfMessageAttributes" , stack ));
+                continue ;
+            }
+            if current_name == "MessageAttribute" {
+                obj.message_attributes =
+                    try!(MessageAttributeMapParser:: parse_xml (
+                         "MessageAttribute" , stack ));
+                continue ;
+            }
+            if current_name == "MessageId" {
+                obj.message_id =
+                    try!(StringParser:: parse_xml ( "MessageId" , stack ));
+                continue ;
+            }
+            if current_name == "ReceiptHandle" {
+                obj.receipt_handle =
+                    try!(StringParser:: parse_xml ( "ReceiptHandle" , stack
+                         ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write Message contents to a SignedRequest
+struct MessageWriter;
+impl MessageWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &Message) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        AttributeMapWriter::write_params(params,
+                                         &(prefix.to_string() + "Attribute"),
+                                         &obj.attributes);
+        StringWriter::write_params(params, &(prefix.to_string() + "Body"),
+                                   &obj.body);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MD5OfBody"),
+                                   &obj.md5_of_body);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() +
+                                         "MD5OfMessageAttributes"),
+                                   &obj.md5_of_message_attributes);
+        MessageAttributeMapWriter::write_params(params,
+                                                &(prefix.to_string() +
+                                                      "MessageAttribute"),
+                                                &obj.message_attributes);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MessageId"),
+                                   &obj.message_id);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "ReceiptHandle"),
+                                   &obj.receipt_handle);
+    }
+}
+pub type MessageAttributeMap = HashMap<String, MessageAttributeValue>;
+/// Parse MessageAttributeMap from XML
+struct MessageAttributeMapParser;
+impl MessageAttributeMapParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageAttributeMap, XmlParseError> {
+        let mut obj = HashMap::new();
+        while try!(peek_at_name ( stack )) == tag_name {
+            try!(start_element ( tag_name , stack ));
+            let key = try!(StringParser:: parse_xml ( "Name" , stack ));
+            let value =
+                try!(MessageAttributeValueParser:: parse_xml ( "Value" , stack
+                     ));
+            obj.insert(key, value);
+            try!(end_element ( tag_name , stack ));
+        }
+        Ok(obj)
+    }
+}
+/// Write MessageAttributeMap contents to a SignedRequest
+struct MessageAttributeMapWriter;
+impl MessageAttributeMapWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &MessageAttributeMap) {
+        let mut index = 1;
+        for (key, value) in obj {
+            let prefix = &format!("{}.{}" , name , index);
+            StringWriter::write_params(params,
+                                       &format!("{}.{}" , prefix , "Name"),
+                                       &key);
+            MessageAttributeValueWriter::write_params(params,
+                                                      &format!("{}.{}" ,
+                                                               prefix ,
+                                                               "Value"),
+                                                      &value);
+            index += 1;
+        }
+    }
+}
+pub type MessageAttributeName = String;
+/// Parse MessageAttributeName from XML
+struct MessageAttributeNameParser;
+impl MessageAttributeNameParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageAttributeName, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj = try!(characters ( stack ));
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write MessageAttributeName contents to a SignedRequest
+struct MessageAttributeNameWriter;
+impl MessageAttributeNameWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &MessageAttributeName) {
+        params.put(name, obj);
+    }
+}
+pub type MessageAttributeNameList = Vec<MessageAttributeName>;
+/// Parse MessageAttributeNameList from XML
+struct MessageAttributeNameListParser;
+impl MessageAttributeNameListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageAttributeNameList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "MessageAttributeName" {
+            obj.push(try!(MessageAttributeNameParser:: parse_xml (
+                          "MessageAttributeName" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write MessageAttributeNameList contents to a SignedRequest
+struct MessageAttributeNameListWriter;
+impl MessageAttributeNameListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &MessageAttributeNameList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            MessageAttributeNameWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+/// The user-specified message attribute value. For string data types, the value attribute has the same restrictions on the content as the message body. For more information, see [SendMessage](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/APIReference/API_SendMessage.html).
+///
+/// Name, type, and value must not be empty or null. In addition, the message body should not be empty or null. All parts of the message attribute, including name, type, and value, are included in the message size restriction, which is currently 256 KB (262,144 bytes).
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct MessageAttributeValue {
+    /// Not implemented. Reserved for future use.
+    pub binary_list_values: Option<BinaryList>,
+    /// Binary type attributes can store any binary data, for example, compressed data, encrypted data, or images.
+    pub binary_value: Option<Binary>,
+    /// Amazon SQS supports the following logical data types: String, Number, and Binary. In addition, you can append your own custom labels. For more information, see [Message Attribute Data Types](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSMessageAttributes.html#SQSMessageAttributes.DataTypes).
+    pub data_type: String,
+    /// Not implemented. Reserved for future use.
+    pub string_list_values: Option<StringList>,
+    /// Strings are Unicode with UTF8 binary encoding. For a list of code values, see [http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters](http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters).
+    pub string_value: Option<String>,
+}
+impl ::serde::de::Deserialize for MessageAttributeValue {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<MessageAttributeValue, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, __field4, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "BinaryListValues" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "BinaryValue" => { Ok(__Field::__field1) }
+                                "DataType" => { Ok(__Field::__field2) }
+                                "StringListValues" => {
+                                    Ok(__Field::__field3)
+                                }
+                                "StringValue" => { Ok(__Field::__field4) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                MessageAttributeValue;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<MessageAttributeValue, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(MessageAttributeValue{binary_list_values: __field0,
+                                                 binary_value: __field1,
+                                                 data_type: __field2,
+                                                 string_list_values: __field3,
+                                                 string_value: __field4,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<MessageAttributeValue, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "BinaryListValues" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "BinaryValue"
+                                     )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "DataType" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "StringListValues" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field ( "StringValue"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(MessageAttributeValue{binary_list_values: __field0,
+                                                 binary_value: __field1,
+                                                 data_type: __field2,
+                                                 string_list_values: __field3,
+                                                 string_value: __field4,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["binary_list_values", "binary_value", "data_type",
+                  "string_list_values", "string_value"];
+            deserializer.visit_struct("MessageAttributeValue", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for MessageAttributeValue {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a MessageAttributeValue,
+                _structure_ty: ::std::marker::PhantomData<&'__a MessageAttributeValue>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "BinaryListValues" ,
+                                                    &self.value.binary_list_values
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "BinaryValue" ,
+                                                    &self.value.binary_value ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DataType" ,
+                                                    &self.value.data_type ,
+                                                    ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "StringListValues" ,
+                                                    &self.value.string_list_values
+                                                    , ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "StringValue" ,
+                                                    &self.value.string_value ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("MessageAttributeValue",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&MessageAttributeValue>,})
+        }
+    }
+}
+/// Parse MessageAttributeValue from XML
+struct MessageAttributeValueParser;
+impl MessageAttributeValueParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageAttributeValue, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = MessageAttributeValue::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "BinaryListValue" {
+                obj.binary_list_values =
+                    Some(try!(BinaryListParser:: parse_xml (
+                              "BinaryListValue" , stack )));
+                continue ;
+            }
+            if current_name == "BinaryValue" {
+                obj.binary_value =
+                    Some(try!(BinaryParser:: parse_xml ( "BinaryValue" , stack
+                              )));
+                continue ;
+            }
+            if current_name == "DataType" {
+                obj.data_type =
+                    try!(StringParser:: parse_xml ( "DataType" , stack ));
+                continue ;
+            }
+            if current_name == "StringListValue" {
+                obj.string_list_values =
+                    Some(try!(StringListParser:: parse_xml (
+                              "StringListValue" , stack )));
+                continue ;
+            }
+            if current_name == "StringValue" {
+                obj.string_value =
+                    Some(try!(StringParser:: parse_xml ( "StringValue" , stack
+                              )));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write MessageAttributeValue contents to a SignedRequest
+struct MessageAttributeValueWriter;
+impl MessageAttributeValueWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &MessageAttributeValue) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.binary_list_values {
+            BinaryListWriter::write_params(params,
+                                           &(prefix.to_string() +
+                                                 "BinaryListValue"), obj);
+        }
+        if let Some(ref obj) = obj.binary_value {
+            BinaryWriter::write_params(params,
+                                       &(prefix.to_string() + "BinaryValue"),
+                                       obj);
+        }
+        StringWriter::write_params(params, &(prefix.to_string() + "DataType"),
+                                   &obj.data_type);
+        if let Some(ref obj) = obj.string_list_values {
+            StringListWriter::write_params(params,
+                                           &(prefix.to_string() +
+                                                 "StringListValue"), obj);
+        }
+        if let Some(ref obj) = obj.string_value {
+            StringWriter::write_params(params,
+                                       &(prefix.to_string() + "StringValue"),
+                                       obj);
+        }
+    }
+}
+pub type MessageList = Vec<Message>;
+/// Parse MessageList from XML
+struct MessageListParser;
+impl MessageListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "Message" {
+            obj.push(try!(MessageParser:: parse_xml ( "Message" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write MessageList contents to a SignedRequest
+struct MessageListWriter;
+impl MessageListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &MessageList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            MessageWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+/// The message referred to is not in flight.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct MessageNotInflight;
+impl ::serde::de::Deserialize for MessageNotInflight {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<MessageNotInflight, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                MessageNotInflight;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<MessageNotInflight, E> where
+                 E: ::serde::de::Error {
+                    Ok(MessageNotInflight)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<MessageNotInflight, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("MessageNotInflight", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for MessageNotInflight {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("MessageNotInflight")
+    }
+}
+/// Parse MessageNotInflight from XML
+struct MessageNotInflightParser;
+impl MessageNotInflightParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<MessageNotInflight, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = MessageNotInflight::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write MessageNotInflight contents to a SignedRequest
+struct MessageNotInflightWriter;
+impl MessageNotInflightWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &MessageNotInflight) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The action that you requested would violate a limit. For example, ReceiveMessage returns this error if the maximum number of messages inflight has already been reached. AddPermission returns this error if the maximum number of permissions for the queue has already been reached.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct OverLimit;
+impl ::serde::de::Deserialize for OverLimit {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<OverLimit, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                OverLimit;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<OverLimit, E> where
+                 E: ::serde::de::Error {
+                    Ok(OverLimit)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<OverLimit, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("OverLimit", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for OverLimit {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("OverLimit")
+    }
+}
+/// Parse OverLimit from XML
+struct OverLimitParser;
+impl OverLimitParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<OverLimit, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = OverLimit::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write OverLimit contents to a SignedRequest
+struct OverLimitWriter;
+impl OverLimitWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &OverLimit) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// Indicates that the specified queue previously received a `PurgeQueue` request within the last 60 seconds, the time it can take to delete the messages in the queue.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct PurgeQueueInProgress;
+impl ::serde::de::Deserialize for PurgeQueueInProgress {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<PurgeQueueInProgress, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                PurgeQueueInProgress;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<PurgeQueueInProgress, E> where
+                 E: ::serde::de::Error {
+                    Ok(PurgeQueueInProgress)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<PurgeQueueInProgress, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("PurgeQueueInProgress", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for PurgeQueueInProgress {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("PurgeQueueInProgress")
+    }
+}
+/// Parse PurgeQueueInProgress from XML
+struct PurgeQueueInProgressParser;
+impl PurgeQueueInProgressParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<PurgeQueueInProgress, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = PurgeQueueInProgress::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write PurgeQueueInProgress contents to a SignedRequest
+struct PurgeQueueInProgressWriter;
+impl PurgeQueueInProgressWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &PurgeQueueInProgress) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct PurgeQueueRequest {
+    /// The queue URL of the queue to delete the messages from when using the `PurgeQueue` API.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for PurgeQueueRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<PurgeQueueRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "QueueUrl" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                PurgeQueueRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<PurgeQueueRequest, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(PurgeQueueRequest{queue_url: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<PurgeQueueRequest, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(PurgeQueueRequest{queue_url: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["queue_url"];
+            deserializer.visit_struct("PurgeQueueRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for PurgeQueueRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a PurgeQueueRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a PurgeQueueRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("PurgeQueueRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&PurgeQueueRequest>,})
+        }
+    }
+}
+/// Parse PurgeQueueRequest from XML
+struct PurgeQueueRequestParser;
+impl PurgeQueueRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<PurgeQueueRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = PurgeQueueRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write PurgeQueueRequest contents to a SignedRequest
+struct PurgeQueueRequestWriter;
+impl PurgeQueueRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &PurgeQueueRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub enum QueueAttributeName {
+    Policy,
+    VisibilityTimeout,
+    MaximumMessageSize,
+    MessageRetentionPeriod,
+    ApproximateNumberOfMessages,
+    ApproximateNumberOfMessagesNotVisible,
+    CreatedTimestamp,
+    LastModifiedTimestamp,
+    QueueArn,
+    ApproximateNumberOfMessagesDelayed,
+    DelaySeconds,
+    ReceiveMessageWaitTimeSeconds,
+    RedrivePolicy,
+
+    /// A value that wasn't known when this client was generated.
+    Unknown(String),
+}
+impl QueueAttributeName {
+    /// The value as sent to and from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueueAttributeName::Policy => "Policy",
+            QueueAttributeName::VisibilityTimeout => "VisibilityTimeout",
+            QueueAttributeName::MaximumMessageSize => "MaximumMessageSize",
+            QueueAttributeName::MessageRetentionPeriod =>
+            "MessageRetentionPeriod",
+            QueueAttributeName::ApproximateNumberOfMessages =>
+            "ApproximateNumberOfMessages",
+            QueueAttributeName::ApproximateNumberOfMessagesNotVisible =>
+            "ApproximateNumberOfMessagesNotVisible",
+            QueueAttributeName::CreatedTimestamp => "CreatedTimestamp",
+            QueueAttributeName::LastModifiedTimestamp =>
+            "LastModifiedTimestamp",
+            QueueAttributeName::QueueArn => "QueueArn",
+            QueueAttributeName::ApproximateNumberOfMessagesDelayed =>
+            "ApproximateNumberOfMessagesDelayed",
+            QueueAttributeName::DelaySeconds => "DelaySeconds",
+            QueueAttributeName::ReceiveMessageWaitTimeSeconds =>
+            "ReceiveMessageWaitTimeSeconds",
+            QueueAttributeName::RedrivePolicy => "RedrivePolicy",
+            QueueAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+impl Default for QueueAttributeName {
+    fn default() -> QueueAttributeName {
+        QueueAttributeName::Unknown(String::new())
+    }
+}
+impl <'a> From<&'a str> for QueueAttributeName {
+    fn from(value: &'a str) -> QueueAttributeName {
+        match value {
+            "Policy" => QueueAttributeName::Policy,
+            "VisibilityTimeout" => QueueAttributeName::VisibilityTimeout,
+            "MaximumMessageSize" => QueueAttributeName::MaximumMessageSize,
+            "MessageRetentionPeriod" =>
+            QueueAttributeName::MessageRetentionPeriod,
+            "ApproximateNumberOfMessages" =>
+            QueueAttributeName::ApproximateNumberOfMessages,
+            "ApproximateNumberOfMessagesNotVisible" =>
+            QueueAttributeName::ApproximateNumberOfMessagesNotVisible,
+            "CreatedTimestamp" => QueueAttributeName::CreatedTimestamp,
+            "LastModifiedTimestamp" =>
+            QueueAttributeName::LastModifiedTimestamp,
+            "QueueArn" => QueueAttributeName::QueueArn,
+            "ApproximateNumberOfMessagesDelayed" =>
+            QueueAttributeName::ApproximateNumberOfMessagesDelayed,
+            "DelaySeconds" => QueueAttributeName::DelaySeconds,
+            "ReceiveMessageWaitTimeSeconds" =>
+            QueueAttributeName::ReceiveMessageWaitTimeSeconds,
+            "RedrivePolicy" => QueueAttributeName::RedrivePolicy,
+            _ => QueueAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+impl ::std::fmt::Display for QueueAttributeName {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f , "{}" , self . as_str (  ))
+    }
+}
+impl ::serde::Serialize for QueueAttributeName {
+    fn serialize<S>(&self, serializer: &mut S)
+     -> ::std::result::Result<(), S::Error> where S: ::serde::Serializer {
+        ::serde::Serialize::serialize(self.as_str(), serializer)
+    }
+}
+impl ::serde::Deserialize for QueueAttributeName {
+    fn deserialize<D>(deserializer: &mut D)
+     -> ::std::result::Result<QueueAttributeName, D::Error> where
+     D: ::serde::Deserializer {
+        let value: String =
+            try!(:: serde:: Deserialize:: deserialize ( deserializer ));
+        Ok(QueueAttributeName::from(&value[..]))
+    }
+}
+/// Parse QueueAttributeName from XML
+struct QueueAttributeNameParser;
+impl QueueAttributeNameParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<QueueAttributeName, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj = QueueAttributeName::from(&try!(characters ( stack ))[..]);
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write QueueAttributeName contents to a SignedRequest
+struct QueueAttributeNameWriter;
+impl QueueAttributeNameWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &QueueAttributeName) {
+        params.put(name, obj.as_str());
+    }
+}
+/// You must wait 60 seconds after deleting a queue before you can create another with the same name.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct QueueDeletedRecently;
+impl ::serde::de::Deserialize for QueueDeletedRecently {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<QueueDeletedRecently, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                QueueDeletedRecently;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<QueueDeletedRecently, E> where
+                 E: ::serde::de::Error {
+                    Ok(QueueDeletedRecently)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<QueueDeletedRecently, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("QueueDeletedRecently", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for QueueDeletedRecently {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("QueueDeletedRecently")
+    }
+}
+/// Parse QueueDeletedRecently from XML
+struct QueueDeletedRecentlyParser;
+impl QueueDeletedRecentlyParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<QueueDeletedRecently, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = QueueDeletedRecently::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write QueueDeletedRecently contents to a SignedRequest
+struct QueueDeletedRecentlyWriter;
+impl QueueDeletedRecentlyWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &QueueDeletedRecently) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// The queue referred to does not exist.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct QueueDoesNotExist;
+impl ::serde::de::Deserialize for QueueDoesNotExist {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<QueueDoesNotExist, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                QueueDoesNotExist;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<QueueDoesNotExist, E> where
+                 E: ::serde::de::Error {
+                    Ok(QueueDoesNotExist)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<QueueDoesNotExist, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("QueueDoesNotExist", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for QueueDoesNotExist {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("QueueDoesNotExist")
+    }
+}
+/// Parse QueueDoesNotExist from XML
+struct QueueDoesNotExistParser;
+impl QueueDoesNotExistParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<QueueDoesNotExist, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = QueueDoesNotExist::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write QueueDoesNotExist contents to a SignedRequest
+struct QueueDoesNotExistWriter;
+impl QueueDoesNotExistWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &QueueDoesNotExist) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// A queue already exists with this name. Amazon SQS returns this error only if the request includes attributes whose values differ from those of the existing queue.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct QueueNameExists;
+impl ::serde::de::Deserialize for QueueNameExists {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<QueueNameExists, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                QueueNameExists;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<QueueNameExists, E> where
+                 E: ::serde::de::Error {
+                    Ok(QueueNameExists)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<QueueNameExists, V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("QueueNameExists", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for QueueNameExists {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("QueueNameExists")
+    }
+}
+/// Parse QueueNameExists from XML
+struct QueueNameExistsParser;
+impl QueueNameExistsParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<QueueNameExists, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = QueueNameExists::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write QueueNameExists contents to a SignedRequest
+struct QueueNameExistsWriter;
+impl QueueNameExistsWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &QueueNameExists) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+pub type QueueUrlList = Vec<String>;
+/// Parse QueueUrlList from XML
+struct QueueUrlListParser;
+impl QueueUrlListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<QueueUrlList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "QueueUrl" {
+            obj.push(try!(StringParser:: parse_xml ( "QueueUrl" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write QueueUrlList contents to a SignedRequest
+struct QueueUrlListWriter;
+impl QueueUrlListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &QueueUrlList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            StringWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+/// The receipt handle provided is not valid.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ReceiptHandleIsInvalid;
+impl ::serde::de::Deserialize for ReceiptHandleIsInvalid {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ReceiptHandleIsInvalid, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                ReceiptHandleIsInvalid;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<ReceiptHandleIsInvalid, E> where
+                 E: ::serde::de::Error {
+                    Ok(ReceiptHandleIsInvalid)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<ReceiptHandleIsInvalid, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("ReceiptHandleIsInvalid",
+                                           __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for ReceiptHandleIsInvalid {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("ReceiptHandleIsInvalid")
+    }
+}
+/// Parse ReceiptHandleIsInvalid from XML
+struct ReceiptHandleIsInvalidParser;
+impl ReceiptHandleIsInvalidParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ReceiptHandleIsInvalid, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ReceiptHandleIsInvalid::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ReceiptHandleIsInvalid contents to a SignedRequest
+struct ReceiptHandleIsInvalidWriter;
+impl ReceiptHandleIsInvalidWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ReceiptHandleIsInvalid) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ReceiveMessageRequest {
+    /// A list of attributes that need to be returned along with each message.
+    ///
+    /// The following lists the names and descriptions of the attributes that can be returned:
+    ///
+    /// * `All` - returns all values.
+    /// * `ApproximateFirstReceiveTimestamp` - returns the time when the message was first received from the queue (epoch time in milliseconds).
+    /// * `ApproximateReceiveCount` - returns the number of times a message has been received from the queue but not deleted.
+    /// * `SenderId` - returns the AWS account number (or the IP address, if anonymous access is allowed) of the sender.
+    /// * `SentTimestamp` - returns the time when the message was sent to the queue (epoch time in milliseconds).
+    pub attribute_names: Option<AttributeNameList>,
+    /// The maximum number of messages to return. Amazon SQS never returns more messages than this value but may return fewer. Values can be from 1 to 10. Default is 1.
+    ///
+    /// All of the messages are not necessarily returned.
+    pub max_number_of_messages: Option<Integer>,
+    /// The name of the message attribute, where *N* is the index. The message attribute name can contain the following characters: A-Z, a-z, 0-9, underscore (_), hyphen (-), and period (.). The name must not start or end with a period, and it should not have successive periods. The name is case sensitive and must be unique among all attribute names for the message. The name can be up to 256 characters long. The name cannot start with "AWS." or "Amazon." (or any variations in casing), because these prefixes are reserved for use by Amazon Web Services.
+    ///
+    /// When using `ReceiveMessage`, you can send a list of attribute names to receive, or you can return all of the attributes by specifying "All" or ".*" in your request. You can also use "foo.*" to return all message attributes starting with the "foo" prefix.
+    pub message_attribute_names: Option<MessageAttributeNameList>,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+    /// The duration (in seconds) that the received messages are hidden from subsequent retrieve requests after being retrieved by a `ReceiveMessage` request.
+    pub visibility_timeout: Option<Integer>,
+    /// The duration (in seconds) for which the call will wait for a message to arrive in the queue before returning. If a message is available, the call will return sooner than WaitTimeSeconds.
+    pub wait_time_seconds: Option<Integer>,
+}
+impl ::serde::de::Deserialize for ReceiveMessageRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ReceiveMessageRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field {
+                __field0,
+                __field1,
+                __field2,
+                __field3,
+                __field4,
+                __field5,
+            }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                4usize => { Ok(__Field::__field4) }
+                                5usize => { Ok(__Field::__field5) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "AttributeNames" => { Ok(__Field::__field0) }
+                                "MaxNumberOfMessages" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "MessageAttributeNames" => {
+                                    Ok(__Field::__field2)
+                                }
+                                "QueueUrl" => { Ok(__Field::__field3) }
+                                "VisibilityTimeout" => {
+                                    Ok(__Field::__field4)
+                                }
+                                "WaitTimeSeconds" => { Ok(__Field::__field5) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ReceiveMessageRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReceiveMessageRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field4 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field5 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReceiveMessageRequest{attribute_names: __field0,
+                                                 max_number_of_messages:
+                                                     __field1,
+                                                 message_attribute_names:
+                                                     __field2,
+                                                 queue_url: __field3,
+                                                 visibility_timeout: __field4,
+                                                 wait_time_seconds:
+                                                     __field5,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReceiveMessageRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        let mut __field4 = None;
+                        let mut __field5 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field4 => {
+                                    __field4 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field5 => {
+                                    __field5 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "AttributeNames" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MaxNumberOfMessages" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MessageAttributeNames" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        let __field4 =
+                            match __field4 {
+                                Some(__field4) => __field4,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "VisibilityTimeout" )),
+                            };
+                        let __field5 =
+                            match __field5 {
+                                Some(__field5) => __field5,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "WaitTimeSeconds" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReceiveMessageRequest{attribute_names: __field0,
+                                                 max_number_of_messages:
+                                                     __field1,
+                                                 message_attribute_names:
+                                                     __field2,
+                                                 queue_url: __field3,
+                                                 visibility_timeout: __field4,
+                                                 wait_time_seconds:
+                                                     __field5,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["attribute_names", "max_number_of_messages",
+                  "message_attribute_names", "queue_url",
+                  "visibility_timeout", "wait_time_seconds"];
+            deserializer.visit_struct("ReceiveMessageRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ReceiveMessageRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ReceiveMessageRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a ReceiveMessageRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "AttributeNames" ,
+                                                    &self.value.attribute_names
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MaxNumberOfMessages" ,
+                                                    &self.value.max_number_of_messages
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageAttributeNames" ,
+                                                    &self.value.message_attribute_names
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            4usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "VisibilityTimeout" ,
+                                                    &self.value.visibility_timeout
+                                                    , ))));
+                            }
+                            5usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "WaitTimeSeconds" ,
+                                                    &self.value.wait_time_seconds
+                                                    , ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> {
+                    Some(0 + 1 + 1 + 1 + 1 + 1 + 1)
+                }
+            }
+            serializer.visit_struct("ReceiveMessageRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ReceiveMessageRequest>,})
+        }
+    }
+}
+/// Parse ReceiveMessageRequest from XML
+struct ReceiveMessageRequestParser;
+impl ReceiveMessageRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ReceiveMessageRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ReceiveMessageRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "AttributeName" {
+                obj.attribute_names =
+                    Some(try!(AttributeNameListParser:: parse_xml (
+                              "AttributeName" , stack )));
+                continue ;
+            }
+            if current_name == "MaxNumberOfMessages" {
+                obj.max_number_of_messages =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "MaxNumberOfMessages" , stack )));
+                continue ;
+            }
+            if current_name == "MessageAttributeName" {
+                obj.message_attribute_names =
+                    Some(try!(MessageAttributeNameListParser:: parse_xml (
+                              "MessageAttributeName" , stack )));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            if current_name == "VisibilityTimeout" {
+                obj.visibility_timeout =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "VisibilityTimeout" , stack )));
+                continue ;
+            }
+            if current_name == "WaitTimeSeconds" {
+                obj.wait_time_seconds =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "WaitTimeSeconds" , stack )));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ReceiveMessageRequest contents to a SignedRequest
+struct ReceiveMessageRequestWriter;
+impl ReceiveMessageRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ReceiveMessageRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.attribute_names {
+            AttributeNameListWriter::write_params(params,
+                                                  &(prefix.to_string() +
+                                                        "AttributeName"),
+                                                  obj);
+        }
+        if let Some(ref obj) = obj.max_number_of_messages {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "MaxNumberOfMessages"), obj);
+        }
+        if let Some(ref obj) = obj.message_attribute_names {
+            MessageAttributeNameListWriter::write_params(params,
+                                                         &(prefix.to_string()
+                                                               +
+                                                               "MessageAttributeName"),
+                                                         obj);
+        }
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+        if let Some(ref obj) = obj.visibility_timeout {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "VisibilityTimeout"), obj);
+        }
+        if let Some(ref obj) = obj.wait_time_seconds {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "WaitTimeSeconds"), obj);
+        }
+    }
+}
+/// A list of received messages.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct ReceiveMessageResult {
+    /// A list of messages.
+    pub messages: MessageList,
+}
+impl ::serde::de::Deserialize for ReceiveMessageResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<ReceiveMessageResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Messages" => { Ok(__Field::__field0) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                ReceiveMessageResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReceiveMessageResult, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReceiveMessageResult{messages: __field0,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<ReceiveMessageResult, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Messages" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(ReceiveMessageResult{messages: __field0,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["messages"];
+            deserializer.visit_struct("ReceiveMessageResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for ReceiveMessageResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a ReceiveMessageResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a ReceiveMessageResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Messages" ,
+                                                    &self.value.messages ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1) }
+            }
+            serializer.visit_struct("ReceiveMessageResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&ReceiveMessageResult>,})
+        }
+    }
+}
+/// Parse ReceiveMessageResult from XML
+struct ReceiveMessageResultParser;
+impl ReceiveMessageResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<ReceiveMessageResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = ReceiveMessageResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Message" {
+                obj.messages =
+                    try!(MessageListParser:: parse_xml ( "Message" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write ReceiveMessageResult contents to a SignedRequest
+struct ReceiveMessageResultWriter;
+impl ReceiveMessageResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &ReceiveMessageResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        MessageListWriter::write_params(params,
+                                        &(prefix.to_string() + "Message"),
+                                        &obj.messages);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct RemovePermissionRequest {
+    /// The identification of the permission to remove. This is the label added with the AddPermission action.
+    pub label: String,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for RemovePermissionRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<RemovePermissionRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Label" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                RemovePermissionRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RemovePermissionRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RemovePermissionRequest{label: __field0,
+                                                   queue_url: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<RemovePermissionRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Label" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(RemovePermissionRequest{label: __field0,
+                                                   queue_url: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["label", "queue_url"];
+            deserializer.visit_struct("RemovePermissionRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for RemovePermissionRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a RemovePermissionRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a RemovePermissionRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Label" ,
+                                                    &self.value.label , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("RemovePermissionRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&RemovePermissionRequest>,})
+        }
+    }
+}
+/// Parse RemovePermissionRequest from XML
+struct RemovePermissionRequestParser;
+impl RemovePermissionRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<RemovePermissionRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = RemovePermissionRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Label" {
+                obj.label =
+                    try!(StringParser:: parse_xml ( "Label" , stack ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write RemovePermissionRequest contents to a SignedRequest
+struct RemovePermissionRequestWriter;
+impl RemovePermissionRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &RemovePermissionRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Label"),
+                                   &obj.label);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageBatchRequest {
+    /// A list of SendMessageBatchRequestEntry items.
+    pub entries: SendMessageBatchRequestEntryList,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for SendMessageBatchRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageBatchRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Entries" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageBatchRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageBatchRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchRequest{entries: __field0,
+                                                   queue_url: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageBatchRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Entries" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchRequest{entries: __field0,
+                                                   queue_url: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["entries", "queue_url"];
+            deserializer.visit_struct("SendMessageBatchRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageBatchRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageBatchRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageBatchRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Entries" ,
+                                                    &self.value.entries ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageBatchRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageBatchRequest>,})
+        }
+    }
+}
+/// Parse SendMessageBatchRequest from XML
+struct SendMessageBatchRequestParser;
+impl SendMessageBatchRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageBatchRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "SendMessageBatchRequestEntry" {
+                obj.entries =
+                    try!(SendMessageBatchRequestEntryListParser:: parse_xml (
+                         "SendMessageBatchRequestEntry" , stack ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchRequest contents to a SignedRequest
+struct SendMessageBatchRequestWriter;
+impl SendMessageBatchRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        SendMessageBatchRequestEntryListWriter::write_params(params,
+                                                             &(prefix.to_string()
+                                                                   +
+                                                                   "SendMessageBatchRequestEntry"),
+                                                             &obj.entries);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// Contains the details of a single Amazon SQS message along with a `Id`.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageBatchRequestEntry {
+    /// The number of seconds for which the message has to be delayed.
+    pub delay_seconds: Option<Integer>,
+    /// An identifier for the message in this batch. This is used to communicate the result. Note that the `Id`s of a batch request need to be unique within the request.
+    pub id: String,
+    /// Each message attribute consists of a Name, Type, and Value. For more information, see [Message Attribute Items](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSMessageAttributes.html#SQSMessageAttributesNTV).
+    pub message_attributes: Option<MessageAttributeMap>,
+    /// Body of the message.
+    pub message_body: String,
+}
+impl ::serde::de::Deserialize for SendMessageBatchRequestEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageBatchRequestEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "DelaySeconds" => { Ok(__Field::__field0) }
+                                "Id" => { Ok(__Field::__field1) }
+                                "MessageAttributes" => {
+                                    Ok(__Field::__field2)
+                                }
+                                "MessageBody" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageBatchRequestEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SendMessageBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchRequestEntry{delay_seconds:
+                                                            __field0,
+                                                        id: __field1,
+                                                        message_attributes:
+                                                            __field2,
+                                                        message_body:
+                                                            __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SendMessageBatchRequestEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "DelaySeconds"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MessageAttributes" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "MessageBody"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchRequestEntry{delay_seconds:
+                                                            __field0,
+                                                        id: __field1,
+                                                        message_attributes:
+                                                            __field2,
+                                                        message_body:
+                                                            __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["delay_seconds", "id", "message_attributes",
+                  "message_body"];
+            deserializer.visit_struct("SendMessageBatchRequestEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageBatchRequestEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageBatchRequestEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageBatchRequestEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DelaySeconds" ,
+                                                    &self.value.delay_seconds
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageAttributes" ,
+                                                    &self.value.message_attributes
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageBody" ,
+                                                    &self.value.message_body ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageBatchRequestEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageBatchRequestEntry>,})
+        }
+    }
+}
+/// Parse SendMessageBatchRequestEntry from XML
+struct SendMessageBatchRequestEntryParser;
+impl SendMessageBatchRequestEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchRequestEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageBatchRequestEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "DelaySeconds" {
+                obj.delay_seconds =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "DelaySeconds" , stack )));
+                continue ;
+            }
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            if current_name == "MessageAttribute" {
+                obj.message_attributes =
+                    Some(try!(MessageAttributeMapParser:: parse_xml (
+                              "MessageAttribute" , stack )));
+                continue ;
+            }
+            if current_name == "MessageBody" {
+                obj.message_body =
+                    try!(StringParser:: parse_xml ( "MessageBody" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchRequestEntry contents to a SignedRequest
+struct SendMessageBatchRequestEntryWriter;
+impl SendMessageBatchRequestEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchRequestEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.delay_seconds {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "DelaySeconds"), obj);
+        }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+        if let Some(ref obj) = obj.message_attributes {
+            MessageAttributeMapWriter::write_params(params,
+                                                    &(prefix.to_string() +
+                                                          "MessageAttribute"),
+                                                    obj);
+        }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MessageBody"),
+                                   &obj.message_body);
+    }
+}
+pub type SendMessageBatchRequestEntryList = Vec<SendMessageBatchRequestEntry>;
+/// Parse SendMessageBatchRequestEntryList from XML
+struct SendMessageBatchRequestEntryListParser;
+impl SendMessageBatchRequestEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchRequestEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "SendMessageBatchRequestEntry" {
+            obj.push(try!(SendMessageBatchRequestEntryParser:: parse_xml (
+                          "SendMessageBatchRequestEntry" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchRequestEntryList contents to a SignedRequest
+struct SendMessageBatchRequestEntryListWriter;
+impl SendMessageBatchRequestEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchRequestEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            SendMessageBatchRequestEntryWriter::write_params(params, key,
+                                                             &element);
+            index += 1;
+        }
+    }
+}
+/// For each message in the batch, the response contains a SendMessageBatchResultEntry tag if the message succeeds or a BatchResultErrorEntry tag if the message fails.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageBatchResult {
+    /// A list of BatchResultErrorEntry items with the error detail about each message that could not be enqueued.
+    pub failed: BatchResultErrorEntryList,
+    /// A list of SendMessageBatchResultEntry items.
+    pub successful: SendMessageBatchResultEntryList,
+}
+impl ::serde::de::Deserialize for SendMessageBatchResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageBatchResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Failed" => { Ok(__Field::__field0) }
+                                "Successful" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageBatchResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageBatchResult, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchResult{failed: __field0,
+                                                  successful: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageBatchResult, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Failed" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "Successful"
+                                     )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchResult{failed: __field0,
+                                                  successful: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] = &["failed", "successful"];
+            deserializer.visit_struct("SendMessageBatchResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageBatchResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageBatchResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageBatchResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Failed" ,
+                                                    &self.value.failed , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Successful" ,
+                                                    &self.value.successful ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageBatchResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageBatchResult>,})
+        }
+    }
+}
+/// Parse SendMessageBatchResult from XML
+struct SendMessageBatchResultParser;
+impl SendMessageBatchResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageBatchResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "BatchResultErrorEntry" {
+                obj.failed =
+                    try!(BatchResultErrorEntryListParser:: parse_xml (
+                         "BatchResultErrorEntry" , stack ));
+                continue ;
+            }
+            if current_name == "SendMessageBatchResultEntry" {
+                obj.successful =
+                    try!(SendMessageBatchResultEntryListParser:: parse_xml (
+                         "SendMessageBatchResultEntry" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchResult contents to a SignedRequest
+struct SendMessageBatchResultWriter;
+impl SendMessageBatchResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        BatchResultErrorEntryListWriter::write_params(params,
+                                                      &(prefix.to_string() +
+                                                            "BatchResultErrorEntry"),
+                                                      &obj.failed);
+        SendMessageBatchResultEntryListWriter::write_params(params,
+                                                            &(prefix.to_string()
+                                                                  +
+                                                                  "SendMessageBatchResultEntry"),
+                                                            &obj.successful);
+    }
+}
+/// Encloses a message ID for successfully enqueued message of a SendMessageBatch.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageBatchResultEntry {
+    /// An identifier for the message in this batch.
+    pub id: String,
+    /// An MD5 digest of the non-URL-encoded message attribute string. This can be used to verify that Amazon SQS received the message batch correctly. Amazon SQS first URL decodes the message before creating the MD5 digest. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    pub md5_of_message_attributes: Option<String>,
+    /// An MD5 digest of the non-URL-encoded message body string. This can be used to verify that Amazon SQS received the message correctly. Amazon SQS first URL decodes the message before creating the MD5 digest. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    pub md5_of_message_body: String,
+    /// An identifier for the message.
+    pub message_id: String,
+}
+impl ::serde::de::Deserialize for SendMessageBatchResultEntry {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageBatchResultEntry, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Id" => { Ok(__Field::__field0) }
+                                "MD5OfMessageAttributes" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "MD5OfMessageBody" => {
+                                    Ok(__Field::__field2)
+                                }
+                                "MessageId" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageBatchResultEntry;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SendMessageBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchResultEntry{id: __field0,
+                                                       md5_of_message_attributes:
+                                                           __field1,
+                                                       md5_of_message_body:
+                                                           __field2,
+                                                       message_id: __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SendMessageBatchResultEntry,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Id" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MD5OfMessageAttributes" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MD5OfMessageBody" )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "MessageId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageBatchResultEntry{id: __field0,
+                                                       md5_of_message_attributes:
+                                                           __field1,
+                                                       md5_of_message_body:
+                                                           __field2,
+                                                       message_id: __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["id", "md5_of_message_attributes", "md5_of_message_body",
+                  "message_id"];
+            deserializer.visit_struct("SendMessageBatchResultEntry", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageBatchResultEntry {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageBatchResultEntry,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageBatchResultEntry>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Id" , &self.value.id ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfMessageAttributes" ,
+                                                    &self.value.md5_of_message_attributes
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfMessageBody" ,
+                                                    &self.value.md5_of_message_body
+                                                    , ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageId" ,
+                                                    &self.value.message_id ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageBatchResultEntry",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageBatchResultEntry>,})
+        }
+    }
+}
+/// Parse SendMessageBatchResultEntry from XML
+struct SendMessageBatchResultEntryParser;
+impl SendMessageBatchResultEntryParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchResultEntry, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageBatchResultEntry::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Id" {
+                obj.id = try!(StringParser:: parse_xml ( "Id" , stack ));
+                continue ;
+            }
+            if current_name == "MD5OfMessageAttributes" {
+                obj.md5_of_message_attributes =
+                    Some(try!(StringParser:: parse_xml (
+                              "MD5OfMessageAttributes" , stack )));
+                continue ;
+            }
+            if current_name == "MD5OfMessageBody" {
+                obj.md5_of_message_body =
+                    try!(StringParser:: parse_xml ( "MD5OfMessageBody" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "MessageId" {
+                obj.message_id =
+                    try!(StringParser:: parse_xml ( "MessageId" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchResultEntry contents to a SignedRequest
+struct SendMessageBatchResultEntryWriter;
+impl SendMessageBatchResultEntryWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchResultEntry) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params, &(prefix.to_string() + "Id"),
+                                   &obj.id);
+        if let Some(ref obj) = obj.md5_of_message_attributes {
+            StringWriter::write_params(params,
+                                       &(prefix.to_string() +
+                                             "MD5OfMessageAttributes"), obj);
+        }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MD5OfMessageBody"),
+                                   &obj.md5_of_message_body);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MessageId"),
+                                   &obj.message_id);
+    }
+}
+pub type SendMessageBatchResultEntryList = Vec<SendMessageBatchResultEntry>;
+/// Parse SendMessageBatchResultEntryList from XML
+struct SendMessageBatchResultEntryListParser;
+impl SendMessageBatchResultEntryListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageBatchResultEntryList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "SendMessageBatchResultEntry" {
+            obj.push(try!(SendMessageBatchResultEntryParser:: parse_xml (
+                          "SendMessageBatchResultEntry" , stack )));
+        }
+        Ok(obj)
+    }
+}
+/// Write SendMessageBatchResultEntryList contents to a SignedRequest
+struct SendMessageBatchResultEntryListWriter;
+impl SendMessageBatchResultEntryListWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageBatchResultEntryList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            SendMessageBatchResultEntryWriter::write_params(params, key,
+                                                            &element);
+            index += 1;
+        }
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageRequest {
+    /// The number of seconds (0 to 900 - 15 minutes) to delay a specific message. Messages with a positive `DelaySeconds` value become available for processing after the delay time is finished. If you don't specify a value, the default value for the queue applies.
+    pub delay_seconds: Option<Integer>,
+    /// Each message attribute consists of a Name, Type, and Value. For more information, see [Message Attribute Items](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSMessageAttributes.html#SQSMessageAttributesNTV).
+    pub message_attributes: Option<MessageAttributeMap>,
+    /// The message to send. String maximum 256 KB in size. For a list of allowed characters, see the preceding important note.
+    pub message_body: String,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for SendMessageRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, __field3, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                3usize => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "DelaySeconds" => { Ok(__Field::__field0) }
+                                "MessageAttributes" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "MessageBody" => { Ok(__Field::__field2) }
+                                "QueueUrl" => { Ok(__Field::__field3) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageRequest, __V::Error>
+                 where __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field3 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageRequest{delay_seconds: __field0,
+                                              message_attributes: __field1,
+                                              message_body: __field2,
+                                              queue_url: __field3,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageRequest, __V::Error>
+                 where __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        let mut __field3 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field3 => {
+                                    __field3 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "DelaySeconds"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MessageAttributes" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "MessageBody"
+                                     )),
+                            };
+                        let __field3 =
+                            match __field3 {
+                                Some(__field3) => __field3,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageRequest{delay_seconds: __field0,
+                                              message_attributes: __field1,
+                                              message_body: __field2,
+                                              queue_url: __field3,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["delay_seconds", "message_attributes", "message_body",
+                  "queue_url"];
+            deserializer.visit_struct("SendMessageRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "DelaySeconds" ,
+                                                    &self.value.delay_seconds
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageAttributes" ,
+                                                    &self.value.message_attributes
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageBody" ,
+                                                    &self.value.message_body ,
+                                                    ))));
+                            }
+                            3usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageRequest>,})
+        }
+    }
+}
+/// Parse SendMessageRequest from XML
+struct SendMessageRequestParser;
+impl SendMessageRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "DelaySeconds" {
+                obj.delay_seconds =
+                    Some(try!(IntegerParser:: parse_xml (
+                              "DelaySeconds" , stack )));
+                continue ;
+            }
+            if current_name == "MessageAttribute" {
+                obj.message_attributes =
+                    Some(try!(MessageAttributeMapParser:: parse_xml (
+                              "MessageAttribute" , stack )));
+                continue ;
+            }
+            if current_name == "MessageBody" {
+                obj.message_body =
+                    try!(StringParser:: parse_xml ( "MessageBody" , stack ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageRequest contents to a SignedRequest
+struct SendMessageRequestWriter;
+impl SendMessageRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        if let Some(ref obj) = obj.delay_seconds {
+            IntegerWriter::write_params(params,
+                                        &(prefix.to_string() +
+                                              "DelaySeconds"), obj);
+        }
+        if let Some(ref obj) = obj.message_attributes {
+            MessageAttributeMapWriter::write_params(params,
+                                                    &(prefix.to_string() +
+                                                          "MessageAttribute"),
+                                                    obj);
+        }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MessageBody"),
+                                   &obj.message_body);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// The MD5OfMessageBody and MessageId elements.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SendMessageResult {
+    /// An MD5 digest of the non-URL-encoded message attribute string. This can be used to verify that Amazon SQS received the message correctly. Amazon SQS first URL decodes the message before creating the MD5 digest. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    pub md5_of_message_attributes: String,
+    /// An MD5 digest of the non-URL-encoded message body string. This can be used to verify that Amazon SQS received the message correctly. Amazon SQS first URL decodes the message before creating the MD5 digest. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    pub md5_of_message_body: String,
+    /// An element containing the message ID of the message sent to the queue. For more information, see [Queue and Message Identifiers](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ImportantIdentifiers.html) in the *Amazon SQS Developer Guide*.
+    pub message_id: String,
+}
+impl ::serde::de::Deserialize for SendMessageResult {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SendMessageResult, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, __field2, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                2usize => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "MD5OfMessageAttributes" => {
+                                    Ok(__Field::__field0)
+                                }
+                                "MD5OfMessageBody" => {
+                                    Ok(__Field::__field1)
+                                }
+                                "MessageId" => { Ok(__Field::__field2) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SendMessageResult;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageResult, __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field2 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageResult{md5_of_message_attributes:
+                                                 __field0,
+                                             md5_of_message_body: __field1,
+                                             message_id: __field2,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 -> ::std::result::Result<SendMessageResult, __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        let mut __field2 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field2 => {
+                                    __field2 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MD5OfMessageAttributes" )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field (
+                                     "MD5OfMessageBody" )),
+                            };
+                        let __field2 =
+                            match __field2 {
+                                Some(__field2) => __field2,
+                                None =>
+                                try!(visitor . missing_field ( "MessageId" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SendMessageResult{md5_of_message_attributes:
+                                                 __field0,
+                                             md5_of_message_body: __field1,
+                                             message_id: __field2,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["md5_of_message_attributes", "md5_of_message_body",
+                  "message_id"];
+            deserializer.visit_struct("SendMessageResult", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SendMessageResult {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SendMessageResult,
+                _structure_ty: ::std::marker::PhantomData<&'__a SendMessageResult>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfMessageAttributes" ,
+                                                    &self.value.md5_of_message_attributes
+                                                    , ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MD5OfMessageBody" ,
+                                                    &self.value.md5_of_message_body
+                                                    , ))));
+                            }
+                            2usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "MessageId" ,
+                                                    &self.value.message_id ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1 + 1) }
+            }
+            serializer.visit_struct("SendMessageResult",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SendMessageResult>,})
+        }
+    }
+}
+/// Parse SendMessageResult from XML
+struct SendMessageResultParser;
+impl SendMessageResultParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SendMessageResult, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SendMessageResult::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "MD5OfMessageAttributes" {
+                obj.md5_of_message_attributes =
+                    try!(StringParser:: parse_xml (
+                         "MD5OfMessageAttributes" , stack ));
+                continue ;
+            }
+            if current_name == "MD5OfMessageBody" {
+                obj.md5_of_message_body =
+                    try!(StringParser:: parse_xml ( "MD5OfMessageBody" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "MessageId" {
+                obj.message_id =
+                    try!(StringParser:: parse_xml ( "MessageId" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SendMessageResult contents to a SignedRequest
+struct SendMessageResultWriter;
+impl SendMessageResultWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SendMessageResult) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() +
+                                         "MD5OfMessageAttributes"),
+                                   &obj.md5_of_message_attributes);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MD5OfMessageBody"),
+                                   &obj.md5_of_message_body);
+        StringWriter::write_params(params,
+                                   &(prefix.to_string() + "MessageId"),
+                                   &obj.message_id);
+    }
+}
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct SetQueueAttributesRequest {
+    /// A map of attributes to set.
+    ///
+    /// The following lists the names, descriptions, and values of the special request parameters the `SetQueueAttributes` action uses:
+    ///
+    /// * `DelaySeconds` - The time in seconds that the delivery of all messages in the queue will be delayed. An integer from 0 to 900 (15 minutes). The default for this attribute is 0 (zero).
+    /// * `MaximumMessageSize` - The limit of how many bytes a message can contain before Amazon SQS rejects it. An integer from 1024 bytes (1 KiB) up to 262144 bytes (256 KiB). The default for this attribute is 262144 (256 KiB).
+    /// * `MessageRetentionPeriod` - The number of seconds Amazon SQS retains a message. Integer representing seconds, from 60 (1 minute) to 1209600 (14 days). The default for this attribute is 345600 (4 days).
+    /// * `Policy` - The queue's policy. A valid AWS policy. For more information about policy structure, see [Overview of AWS IAM Policies](http://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html) in the *Amazon IAM User Guide*.
+    /// * `ReceiveMessageWaitTimeSeconds` - The time for which a ReceiveMessage call will wait for a message to arrive. An integer from 0 to 20 (seconds). The default for this attribute is 0.
+    /// * `VisibilityTimeout` - The visibility timeout for the queue. An integer from 0 to 43200 (12 hours). The default for this attribute is 30. For more information about visibility timeout, see Visibility Timeout in the *Amazon SQS Developer Guide*.
+    /// * `RedrivePolicy` - The parameters for dead letter queue functionality of the source queue. For more information about RedrivePolicy and dead letter queues, see Using Amazon SQS Dead Letter Queues in the *Amazon SQS Developer Guide*.
+    pub attributes: AttributeMap,
+    /// The URL of the Amazon SQS queue to take action on.
+    pub queue_url: String,
+}
+impl ::serde::de::Deserialize for SetQueueAttributesRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<SetQueueAttributesRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            #[allow(non_camel_case_types)]
+            enum __Field { __field0, __field1, }
+            impl ::serde::de::Deserialize for __Field {
+                #[inline]
+                fn deserialize<D>(deserializer: &mut D)
+                 -> ::std::result::Result<__Field, D::Error> where
+                 D: ::serde::de::Deserializer {
+                    use std::marker::PhantomData;
+                    struct __FieldVisitor<D> {
+                        phantom: PhantomData<D>,
+                    }
+                    impl <__D> ::serde::de::Visitor for __FieldVisitor<__D>
+                     where __D: ::serde::de::Deserializer {
+                        type
+                        Value
+                        =
+                        __Field;
+                        fn visit_usize<E>(&mut self, value: usize)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                0usize => { Ok(__Field::__field0) }
+                                1usize => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::syntax("expected a field"))
+                                }
+                            }
+                        }
+                        fn visit_str<E>(&mut self, value: &str)
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match value {
+                                "Attributes" => { Ok(__Field::__field0) }
+                                "QueueUrl" => { Ok(__Field::__field1) }
+                                _ => {
+                                    Err(::serde::de::Error::unknown_field(value))
+                                }
+                            }
+                        }
+                        fn visit_bytes<E>(&mut self, value: &[u8])
+                         -> ::std::result::Result<__Field, E> where
+                         E: ::serde::de::Error {
+                            match ::std::str::from_utf8(value) {
+                                Ok(s) => self.visit_str(s),
+                                _ => {
+                                    Err(::serde::de::Error::syntax("could not convert a byte string to a String"))
+                                }
+                            }
+                        }
+                    }
+                    deserializer.visit_struct_field(__FieldVisitor::<D>{phantom:
+                                                                            PhantomData,})
+                }
+            }
+            struct __Visitor<__D: ::serde::de::Deserializer>(::std::marker::PhantomData<__D>);
+            impl <__D: ::serde::de::Deserializer> ::serde::de::Visitor for
+             __Visitor<__D> {
+                type
+                Value
+                =
+                SetQueueAttributesRequest;
+                #[inline]
+                fn visit_seq<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SetQueueAttributesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::SeqVisitor {
+                    {
+                        let __field0 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        let __field1 =
+                            match try!(visitor . visit (  )) {
+                                Some(value) => { value }
+                                None => {
+                                    return Err(::serde::de::Error::end_of_stream());
+                                }
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SetQueueAttributesRequest{attributes: __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+                #[inline]
+                fn visit_map<__V>(&mut self, mut visitor: __V)
+                 ->
+                     ::std::result::Result<SetQueueAttributesRequest,
+                                           __V::Error> where
+                 __V: ::serde::de::MapVisitor {
+                    {
+                        let mut __field0 = None;
+                        let mut __field1 = None;
+                        while let Some(key) = try!(visitor . visit_key (  )) {
+                            match key {
+                                __Field::__field0 => {
+                                    __field0 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                                __Field::__field1 => {
+                                    __field1 =
+                                        Some(try!(visitor . visit_value (
+                                                  )));
+                                }
+                            }
+                        }
+                        let __field0 =
+                            match __field0 {
+                                Some(__field0) => __field0,
+                                None =>
+                                try!(visitor . missing_field ( "Attributes"
+                                     )),
+                            };
+                        let __field1 =
+                            match __field1 {
+                                Some(__field1) => __field1,
+                                None =>
+                                try!(visitor . missing_field ( "QueueUrl" )),
+                            };
+                        try!(visitor . end (  ));
+                        Ok(SetQueueAttributesRequest{attributes: __field0,
+                                                     queue_url: __field1,})
+                    }
+                }
+            }
+            const FIELDS: &'static [&'static str] =
+                &["attributes", "queue_url"];
+            deserializer.visit_struct("SetQueueAttributesRequest", FIELDS,
+                                      __Visitor::<__D>(::std::marker::PhantomData))
+        }
+    }
+}
+impl ::serde::ser::Serialize for SetQueueAttributesRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        {
+            struct Visitor<'__a> {
+                state: usize,
+                value: &'__a SetQueueAttributesRequest,
+                _structure_ty: ::std::marker::PhantomData<&'__a SetQueueAttributesRequest>,
+            }
+            impl <'__a> ::serde::ser::MapVisitor for Visitor<'__a> {
+                #[inline]
+                fn visit<S>(&mut self, serializer: &mut S)
+                 -> ::std::result::Result<Option<()>, S::Error> where
+                 S: ::serde::ser::Serializer {
+                    loop  {
+                        match self.state {
+                            0usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "Attributes" ,
+                                                    &self.value.attributes ,
+                                                    ))));
+                            }
+                            1usize => {
+                                self.state += 1;
+                                { }
+                                return Ok(Some(try!(serializer .
+                                                    visit_struct_elt (
+                                                    "QueueUrl" ,
+                                                    &self.value.queue_url ,
+                                                    ))));
+                            }
+                            _ => { return Ok(None); }
+                        }
+                    }
+                }
+                #[inline]
+                fn len(&self) -> Option<usize> { Some(0 + 1 + 1) }
+            }
+            serializer.visit_struct("SetQueueAttributesRequest",
+                                    Visitor{value: self,
+                                            state: 0,
+                                            _structure_ty:
+                                                ::std::marker::PhantomData::<&SetQueueAttributesRequest>,})
+        }
+    }
+}
+/// Parse SetQueueAttributesRequest from XML
+struct SetQueueAttributesRequestParser;
+impl SetQueueAttributesRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<SetQueueAttributesRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = SetQueueAttributesRequest::default();
+        loop  {
+            let current_name = try!(peek_at_name ( stack ));
+            if current_name == "Attribute" {
+                obj.attributes =
+                    try!(AttributeMapParser:: parse_xml ( "Attribute" , stack
+                         ));
+                continue ;
+            }
+            if current_name == "QueueUrl" {
+                obj.queue_url =
+                    try!(StringParser:: parse_xml ( "QueueUrl" , stack ));
+                continue ;
+            }
+            break ;
+        }
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write SetQueueAttributesRequest contents to a SignedRequest
+struct SetQueueAttributesRequestWriter;
+impl SetQueueAttributesRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &SetQueueAttributesRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+        AttributeMapWriter::write_params(params,
+                                         &(prefix.to_string() + "Attribute"),
+                                         &obj.attributes);
+        StringWriter::write_params(params, &(prefix.to_string() + "QueueUrl"),
+                                   &obj.queue_url);
+    }
+}
+/// Parse String from XML
+struct StringParser;
+impl StringParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<String, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let obj = try!(characters ( stack ));
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write String contents to a SignedRequest
+struct StringWriter;
+impl StringWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &String) {
+        params.put(name, obj);
+    }
+}
+pub type StringList = Vec<String>;
+/// Parse StringList from XML
+struct StringListParser;
+impl StringListParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<StringList, XmlParseError> {
+        let mut obj = Vec::new();
+        while try!(peek_at_name ( stack )) == "StringListValue" {
+            obj.push(try!(StringParser:: parse_xml ( "StringListValue" , stack
+                          )));
+        }
+        Ok(obj)
+    }
+}
+/// Write StringList contents to a SignedRequest
+struct StringListWriter;
+impl StringListWriter {
+    fn write_params(params: &mut Params, name: &str, obj: &StringList) {
+        let mut index = 1;
+        for element in obj.iter() {
+            let key = &format!("{}.{}" , name , index);
+            StringWriter::write_params(params, key, &element);
+            index += 1;
+        }
+    }
+}
+/// Batch request contains more number of entries than permissible.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct TooManyEntriesInBatchRequest;
+impl ::serde::de::Deserialize for TooManyEntriesInBatchRequest {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<TooManyEntriesInBatchRequest, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                TooManyEntriesInBatchRequest;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<TooManyEntriesInBatchRequest, E>
+                 where E: ::serde::de::Error {
+                    Ok(TooManyEntriesInBatchRequest)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 ->
+                     ::std::result::Result<TooManyEntriesInBatchRequest,
+                                           V::Error> where
+                 V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("TooManyEntriesInBatchRequest",
+                                           __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for TooManyEntriesInBatchRequest {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("TooManyEntriesInBatchRequest")
+    }
+}
+/// Parse TooManyEntriesInBatchRequest from XML
+struct TooManyEntriesInBatchRequestParser;
+impl TooManyEntriesInBatchRequestParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<TooManyEntriesInBatchRequest, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = TooManyEntriesInBatchRequest::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write TooManyEntriesInBatchRequest contents to a SignedRequest
+struct TooManyEntriesInBatchRequestWriter;
+impl TooManyEntriesInBatchRequestWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &TooManyEntriesInBatchRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+/// Error code 400. Unsupported operation.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct UnsupportedOperation;
+impl ::serde::de::Deserialize for UnsupportedOperation {
+    fn deserialize<__D>(deserializer: &mut __D)
+     -> ::std::result::Result<UnsupportedOperation, __D::Error> where
+     __D: ::serde::de::Deserializer {
+        {
+            struct __Visitor;
+            impl ::serde::de::Visitor for __Visitor {
+                type
+                Value
+                =
+                UnsupportedOperation;
+                #[inline]
+                fn visit_unit<E>(&mut self)
+                 -> ::std::result::Result<UnsupportedOperation, E> where
+                 E: ::serde::de::Error {
+                    Ok(UnsupportedOperation)
+                }
+                #[inline]
+                fn visit_seq<V>(&mut self, mut visitor: V)
+                 -> ::std::result::Result<UnsupportedOperation, V::Error>
+                 where V: ::serde::de::SeqVisitor {
+                    try!(visitor . end (  ));
+                    self.visit_unit()
+                }
+            }
+            deserializer.visit_unit_struct("UnsupportedOperation", __Visitor)
+        }
+    }
+}
+impl ::serde::ser::Serialize for UnsupportedOperation {
+    fn serialize<__S>(&self, serializer: &mut __S)
+     -> ::std::result::Result<(), __S::Error> where
+     __S: ::serde::ser::Serializer {
+        serializer.visit_unit_struct("UnsupportedOperation")
+    }
+}
+/// Parse UnsupportedOperation from XML
+struct UnsupportedOperationParser;
+impl UnsupportedOperationParser {
+    fn parse_xml<'a, T: Peek + Next>(tag_name: &str, stack: &mut T)
+     -> Result<UnsupportedOperation, XmlParseError> {
+        try!(start_element ( tag_name , stack ));
+        let mut obj = UnsupportedOperation::default();
+        try!(end_element ( tag_name , stack ));
+        Ok(obj)
+    }
+}
+/// Write UnsupportedOperation contents to a SignedRequest
+struct UnsupportedOperationWriter;
+impl UnsupportedOperationWriter {
+    fn write_params(params: &mut Params, name: &str,
+                    obj: &UnsupportedOperation) {
+        let mut prefix = name.to_string();
+        if prefix != "" { prefix.push_str("."); }
+    }
+}
+impl AddPermissionRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.label;
+            if value.is_empty() {
+                return Err(AWSError::new("AddPermissionRequest.Label is required"));
+            }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("AddPermissionRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ChangeMessageVisibilityBatchRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.entries;
+            if value.len() > 10 {
+                return Err(AWSError::new("ChangeMessageVisibilityBatchRequest.Entries is longer than the maximum length of 10"));
+            }
+            for item in value.iter() { try!(item . validate (  )); }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("ChangeMessageVisibilityBatchRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ChangeMessageVisibilityBatchRequestEntry {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.id;
+            if value.is_empty() {
+                return Err(AWSError::new("ChangeMessageVisibilityBatchRequestEntry.Id is required"));
+            }
+        }
+        {
+            let value = &self.receipt_handle;
+            if value.is_empty() {
+                return Err(AWSError::new("ChangeMessageVisibilityBatchRequestEntry.ReceiptHandle is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ChangeMessageVisibilityRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("ChangeMessageVisibilityRequest.QueueUrl is required"));
+            }
+        }
+        {
+            let value = &self.receipt_handle;
+            if value.is_empty() {
+                return Err(AWSError::new("ChangeMessageVisibilityRequest.ReceiptHandle is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl CreateQueueRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_name;
+            if value.is_empty() {
+                return Err(AWSError::new("CreateQueueRequest.QueueName is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DeleteMessageBatchRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.entries;
+            if value.len() > 10 {
+                return Err(AWSError::new("DeleteMessageBatchRequest.Entries is longer than the maximum length of 10"));
+            }
+            for item in value.iter() { try!(item . validate (  )); }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteMessageBatchRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DeleteMessageBatchRequestEntry {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.id;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteMessageBatchRequestEntry.Id is required"));
+            }
+        }
+        {
+            let value = &self.receipt_handle;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteMessageBatchRequestEntry.ReceiptHandle is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DeleteMessageRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteMessageRequest.QueueUrl is required"));
+            }
+        }
+        {
+            let value = &self.receipt_handle;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteMessageRequest.ReceiptHandle is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl DeleteQueueRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("DeleteQueueRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GetQueueAttributesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("GetQueueAttributesRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl GetQueueUrlRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_name;
+            if value.is_empty() {
+                return Err(AWSError::new("GetQueueUrlRequest.QueueName is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListDeadLetterSourceQueuesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("ListDeadLetterSourceQueuesRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ListQueuesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> { Ok(()) }
+}
+impl MessageAttributeValue {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.data_type;
+            if value.is_empty() {
+                return Err(AWSError::new("MessageAttributeValue.DataType is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl PurgeQueueRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("PurgeQueueRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl ReceiveMessageRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("ReceiveMessageRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl RemovePermissionRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.label;
+            if value.is_empty() {
+                return Err(AWSError::new("RemovePermissionRequest.Label is required"));
+            }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("RemovePermissionRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl SendMessageBatchRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.entries;
+            if value.len() > 10 {
+                return Err(AWSError::new("SendMessageBatchRequest.Entries is longer than the maximum length of 10"));
+            }
+            for item in value.iter() { try!(item . validate (  )); }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("SendMessageBatchRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl SendMessageBatchRequestEntry {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.id;
+            if value.is_empty() {
+                return Err(AWSError::new("SendMessageBatchRequestEntry.Id is required"));
+            }
+        }
+        if let Some(ref value) = self.message_attributes {
+            for (_, value) in value.iter() { try!(value . validate (  )); }
+        }
+        {
+            let value = &self.message_body;
+            if value.is_empty() {
+                return Err(AWSError::new("SendMessageBatchRequestEntry.MessageBody is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl SendMessageRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        if let Some(ref value) = self.message_attributes {
+            for (_, value) in value.iter() { try!(value . validate (  )); }
+        }
+        {
+            let value = &self.message_body;
+            if value.is_empty() {
+                return Err(AWSError::new("SendMessageRequest.MessageBody is required"));
+            }
+        }
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("SendMessageRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl SetQueueAttributesRequest {
+    /// Checks the members against the constraints in the service's model.
+    pub fn validate(&self) -> ::std::result::Result<(), AWSError> {
+        {
+            let value = &self.queue_url;
+            if value.is_empty() {
+                return Err(AWSError::new("SetQueueAttributesRequest.QueueUrl is required"));
+            }
+        }
+        Ok(())
+    }
+}
+impl AddPermissionRequest {
+    /// A `AddPermissionRequest` with the given required members and no optional ones.
+    pub fn new<V2: Into<String>,
+               V3: Into<String>>(aws_account_ids: AWSAccountIdList,
+                                 actions: ActionNameList, label: V2,
+                                 queue_url: V3) -> AddPermissionRequest {
+        AddPermissionRequest{aws_account_ids: aws_account_ids,
+                             actions: actions,
+                             label: label.into(),
+                             queue_url: queue_url.into(),}
+    }
+}
+impl ChangeMessageVisibilityBatchRequest {
+    /// A `ChangeMessageVisibilityBatchRequest` with the given required members and no optional ones.
+    pub fn new<V1: Into<String>>(entries:
+                                     ChangeMessageVisibilityBatchRequestEntryList,
+                                 queue_url: V1)
+     -> ChangeMessageVisibilityBatchRequest {
+        ChangeMessageVisibilityBatchRequest{entries: entries,
+                                            queue_url: queue_url.into(),}
+    }
+}
+impl ChangeMessageVisibilityBatchRequestEntry {
+    /// A `ChangeMessageVisibilityBatchRequestEntry` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>, V1: Into<String>>(id: V0, receipt_handle: V1)
+     -> ChangeMessageVisibilityBatchRequestEntry {
+        ChangeMessageVisibilityBatchRequestEntry{id: id.into(),
+                                                 receipt_handle:
+                                                     receipt_handle.into(),
+                                                                              ..Default::default()}
+    }
+    /// Sets `VisibilityTimeout`.
+    pub fn with_visibility_timeout(mut self, value: Integer)
+     -> ChangeMessageVisibilityBatchRequestEntry {
+        self.visibility_timeout = Some(value);
+        self
+    }
+}
+impl ChangeMessageVisibilityRequest {
+    /// A `ChangeMessageVisibilityRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>,
+               V1: Into<String>>(queue_url: V0, receipt_handle: V1,
+                                 visibility_timeout: Integer)
+     -> ChangeMessageVisibilityRequest {
+        ChangeMessageVisibilityRequest{queue_url: queue_url.into(),
+                                       receipt_handle: receipt_handle.into(),
+                                       visibility_timeout:
+                                           visibility_timeout,}
+    }
+}
+impl CreateQueueRequest {
+    /// A `CreateQueueRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_name: V0) -> CreateQueueRequest {
+        CreateQueueRequest{queue_name:
+                               queue_name.into(), ..Default::default()}
+    }
+    /// Sets `Attributes`.
+    pub fn with_attributes(mut self, value: AttributeMap)
+     -> CreateQueueRequest {
+        self.attributes = Some(value);
+        self
+    }
+}
+impl DeleteMessageBatchRequest {
+    /// A `DeleteMessageBatchRequest` with the given required members and no optional ones.
+    pub fn new<V1: Into<String>>(entries: DeleteMessageBatchRequestEntryList,
+                                 queue_url: V1) -> DeleteMessageBatchRequest {
+        DeleteMessageBatchRequest{entries: entries,
+                                  queue_url: queue_url.into(),}
+    }
+}
+impl DeleteMessageBatchRequestEntry {
+    /// A `DeleteMessageBatchRequestEntry` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>, V1: Into<String>>(id: V0, receipt_handle: V1)
+     -> DeleteMessageBatchRequestEntry {
+        DeleteMessageBatchRequestEntry{id: id.into(),
+                                       receipt_handle: receipt_handle.into(),}
+    }
+}
+impl DeleteMessageRequest {
+    /// A `DeleteMessageRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>,
+               V1: Into<String>>(queue_url: V0, receipt_handle: V1)
+     -> DeleteMessageRequest {
+        DeleteMessageRequest{queue_url: queue_url.into(),
+                             receipt_handle: receipt_handle.into(),}
+    }
+}
+impl DeleteQueueRequest {
+    /// A `DeleteQueueRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_url: V0) -> DeleteQueueRequest {
+        DeleteQueueRequest{queue_url: queue_url.into(),}
+    }
+}
+impl GetQueueAttributesRequest {
+    /// A `GetQueueAttributesRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_url: V0) -> GetQueueAttributesRequest {
+        GetQueueAttributesRequest{queue_url:
+                                      queue_url.into(), ..Default::default()}
+    }
+    /// Sets `AttributeNames`.
+    pub fn with_attribute_names(mut self, value: AttributeNameList)
+     -> GetQueueAttributesRequest {
+        self.attribute_names = Some(value);
+        self
+    }
+}
+impl GetQueueUrlRequest {
+    /// A `GetQueueUrlRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_name: V0) -> GetQueueUrlRequest {
+        GetQueueUrlRequest{queue_name:
+                               queue_name.into(), ..Default::default()}
+    }
+    /// Sets `QueueOwnerAWSAccountId`.
+    pub fn with_queue_owner_aws_account_id<V: Into<String>>(mut self,
+                                                            value: V)
+     -> GetQueueUrlRequest {
+        self.queue_owner_aws_account_id = Some(value.into());
+        self
+    }
+}
+impl ListDeadLetterSourceQueuesRequest {
+    /// A `ListDeadLetterSourceQueuesRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_url: V0)
+     -> ListDeadLetterSourceQueuesRequest {
+        ListDeadLetterSourceQueuesRequest{queue_url: queue_url.into(),}
+    }
+}
+impl ListQueuesRequest {
+    /// A `ListQueuesRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_name_prefix: V0) -> ListQueuesRequest {
+        ListQueuesRequest{queue_name_prefix: queue_name_prefix.into(),}
+    }
+}
+impl MessageAttributeValue {
+    /// A `MessageAttributeValue` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(data_type: V0) -> MessageAttributeValue {
+        MessageAttributeValue{data_type:
+                                  data_type.into(), ..Default::default()}
+    }
+    /// Sets `BinaryListValues`.
+    pub fn with_binary_list_values(mut self, value: BinaryList)
+     -> MessageAttributeValue {
+        self.binary_list_values = Some(value);
+        self
+    }
+    /// Sets `BinaryValue`.
+    pub fn with_binary_value<V: Into<Binary>>(mut self, value: V)
+     -> MessageAttributeValue {
+        self.binary_value = Some(value.into());
+        self
+    }
+    /// Sets `StringListValues`.
+    pub fn with_string_list_values(mut self, value: StringList)
+     -> MessageAttributeValue {
+        self.string_list_values = Some(value);
+        self
+    }
+    /// Sets `StringValue`.
+    pub fn with_string_value<V: Into<String>>(mut self, value: V)
+     -> MessageAttributeValue {
+        self.string_value = Some(value.into());
+        self
+    }
+}
+impl PurgeQueueRequest {
+    /// A `PurgeQueueRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_url: V0) -> PurgeQueueRequest {
+        PurgeQueueRequest{queue_url: queue_url.into(),}
+    }
+}
+impl ReceiveMessageRequest {
+    /// A `ReceiveMessageRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>>(queue_url: V0) -> ReceiveMessageRequest {
+        ReceiveMessageRequest{queue_url:
+                                  queue_url.into(), ..Default::default()}
+    }
+    /// Sets `AttributeNames`.
+    pub fn with_attribute_names(mut self, value: AttributeNameList)
+     -> ReceiveMessageRequest {
+        self.attribute_names = Some(value);
+        self
+    }
+    /// Sets `MaxNumberOfMessages`.
+    pub fn with_max_number_of_messages(mut self, value: Integer)
+     -> ReceiveMessageRequest {
+        self.max_number_of_messages = Some(value);
+        self
+    }
+    /// Sets `MessageAttributeNames`.
+    pub fn with_message_attribute_names(mut self,
+                                        value: MessageAttributeNameList)
+     -> ReceiveMessageRequest {
+        self.message_attribute_names = Some(value);
+        self
+    }
+    /// Sets `VisibilityTimeout`.
+    pub fn with_visibility_timeout(mut self, value: Integer)
+     -> ReceiveMessageRequest {
+        self.visibility_timeout = Some(value);
+        self
+    }
+    /// Sets `WaitTimeSeconds`.
+    pub fn with_wait_time_seconds(mut self, value: Integer)
+     -> ReceiveMessageRequest {
+        self.wait_time_seconds = Some(value);
+        self
+    }
+}
+impl RemovePermissionRequest {
+    /// A `RemovePermissionRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>, V1: Into<String>>(label: V0, queue_url: V1)
+     -> RemovePermissionRequest {
+        RemovePermissionRequest{label: label.into(),
+                                queue_url: queue_url.into(),}
+    }
+}
+impl SendMessageBatchRequest {
+    /// A `SendMessageBatchRequest` with the given required members and no optional ones.
+    pub fn new<V1: Into<String>>(entries: SendMessageBatchRequestEntryList,
+                                 queue_url: V1) -> SendMessageBatchRequest {
+        SendMessageBatchRequest{entries: entries,
+                                queue_url: queue_url.into(),}
+    }
+}
+impl SendMessageBatchRequestEntry {
+    /// A `SendMessageBatchRequestEntry` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>, V1: Into<String>>(id: V0, message_body: V1)
+     -> SendMessageBatchRequestEntry {
+        SendMessageBatchRequestEntry{id: id.into(),
+                                     message_body:
+                                         message_body.into(),
+                                                                ..Default::default()}
+    }
+    /// Sets `DelaySeconds`.
+    pub fn with_delay_seconds(mut self, value: Integer)
+     -> SendMessageBatchRequestEntry {
+        self.delay_seconds = Some(value);
+        self
+    }
+    /// Sets `MessageAttributes`.
+    pub fn with_message_attributes(mut self, value: MessageAttributeMap)
+     -> SendMessageBatchRequestEntry {
+        self.message_attributes = Some(value);
+        self
+    }
+}
+impl SendMessageRequest {
+    /// A `SendMessageRequest` with the given required members and no optional ones.
+    pub fn new<V0: Into<String>,
+               V1: Into<String>>(message_body: V0, queue_url: V1)
+     -> SendMessageRequest {
+        SendMessageRequest{message_body: message_body.into(),
+                           queue_url: queue_url.into(), ..Default::default()}
+    }
+    /// Sets `DelaySeconds`.
+    pub fn with_delay_seconds(mut self, value: Integer)
+     -> SendMessageRequest {
+        self.delay_seconds = Some(value);
+        self
+    }
+    /// Sets `MessageAttributes`.
+    pub fn with_message_attributes(mut self, value: MessageAttributeMap)
+     -> SendMessageRequest {
+        self.message_attributes = Some(value);
+        self
+    }
+}
+impl SetQueueAttributesRequest {
+    /// A `SetQueueAttributesRequest` with the given required members and no optional ones.
+    pub fn new<V1: Into<String>>(attributes: AttributeMap, queue_url: V1)
+     -> SetQueueAttributesRequest {
+        SetQueueAttributesRequest{attributes: attributes,
+                                  queue_url: queue_url.into(),}
+    }
+}
+/// Welcome to the *Amazon Simple Queue Service API Reference*. This section describes who should read this guide, how the guide is organized, and other resources related to the Amazon Simple Queue Service (Amazon SQS).
+///
+/// Amazon SQS offers reliable and scalable hosted queues for storing messages as they travel between computers. By using Amazon SQS, you can move data between distributed components of your applications that perform different tasks without losing messages or requiring each component to be always available.
+///
+/// Helpful Links:
+///
+/// * [Current WSDL (2012-11-05)](http://queue.amazonaws.com/doc/2012-11-05/QueueService.wsdl)
+/// * [Making API Requests](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/MakingRequestsArticle.html)
+/// * [Amazon SQS product page](http://aws.amazon.com/sqs/)
+/// * [Using Amazon SQS Message Attributes](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSMessageAttributes.html)
+/// * [Using Amazon SQS Dead Letter Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSDeadLetterQueue.html)
+/// * [Regions and Endpoints](http://docs.aws.amazon.com/general/latest/gr/rande.html#sqs_region)
+///
+/// We also provide SDKs that enable you to access Amazon SQS from your preferred programming language. The SDKs contain functionality that automatically takes care of tasks such as:
+///
+/// * Cryptographically signing your service requests
+/// * Retrying requests
+/// * Handling error responses
+///
+/// For a list of available SDKs, go to [Tools for Amazon Web Services](http://aws.amazon.com/tools/).
+pub struct SQSClient<'a> {
+    creds: Box<AWSCredentialsProvider+ 'a>,
+    region: &'a ::regions::Region,
+}
+impl <'a> SQSClient<'a> {
+    pub fn new<P: AWSCredentialsProvider +
+               'a>(creds: P, region: &'a ::regions::Region) -> SQSClient<'a> {
+        SQSClient{creds: Box::new(creds), region: region,}
+    }
+    /// Adds a permission to a queue for a specific [principal](http://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P). This allows for sharing access to the queue.
+    ///
+    /// When you create a queue, you have full control access rights for the queue. Only you (as owner of the queue) can grant or deny permissions to the queue. For more information about these permissions, see [Shared Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/acp-overview.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// `AddPermission` writes an Amazon SQS-generated policy. If you want to write your own policy, use SetQueueAttributes to upload your policy. For more information about writing your own policy, see [Using The Access Policy Language](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AccessPolicyLanguage.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn add_permission(&mut self, input: &AddPermissionRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "AddPermission");
+        AddPermissionRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Changes the visibility timeout of a specified message in a queue to a new value. The maximum allowed timeout value you can set the value to is 12 hours. This means you can't extend the timeout of a message in an existing queue to more than a total visibility timeout of 12 hours. (For more information visibility timeout, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.)
+    ///
+    /// For example, let's say you have a message and its default message visibility timeout is 5 minutes. After 3 minutes, you call `ChangeMessageVisiblity` with a timeout of 10 minutes. At that time, the timeout for the message would be extended by 10 minutes beyond the time of the ChangeMessageVisibility call. This results in a total visibility timeout of 13 minutes. You can continue to call ChangeMessageVisibility to extend the visibility timeout to a maximum of 12 hours. If you try to extend beyond 12 hours, the request will be rejected.
+    ///
+    /// There is a 120,000 limit for the number of inflight messages per queue. Messages are inflight after they have been received from the queue by a consuming component, but have not yet been deleted from the queue. If you reach the 120,000 limit, you will receive an OverLimit error message from Amazon SQS. To help avoid reaching the limit, you should delete the messages from the queue after they have been processed. You can also increase the number of queues you use to process the messages.
+    ///
+    /// If you attempt to set the `VisibilityTimeout` to an amount more than the maximum time left, Amazon SQS returns an error. It will not automatically recalculate and increase the timeout to the maximum time remaining.
+    ///
+    /// Unlike with a queue, when you change the visibility timeout for a specific message, that timeout value is applied immediately but is not saved in memory for that message. If you don't delete a message after it is received, the visibility timeout for the message the next time it is received reverts to the original timeout value, not the value you set with the `ChangeMessageVisibility` action.
+    pub fn change_message_visibility(&mut self,
+                                     input: &ChangeMessageVisibilityRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "ChangeMessageVisibility");
+        ChangeMessageVisibilityRequestWriter::write_params(&mut params, "",
+                                                           &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Changes the visibility timeout of multiple messages. This is a batch version of ChangeMessageVisibility. The result of the action on each message is reported individually in the response. You can send up to 10 ChangeMessageVisibility requests with each `ChangeMessageVisibilityBatch` action.
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn change_message_visibility_batch(&mut self,
+                                           input:
+                                               &ChangeMessageVisibilityBatchRequest)
+     -> Result<ChangeMessageVisibilityBatchResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "ChangeMessageVisibilityBatch");
+        ChangeMessageVisibilityBatchRequestWriter::write_params(&mut params,
+                                                                "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(ChangeMessageVisibilityBatchResultParser:: parse_xml (
+                "ChangeMessageVisibilityBatchResult" , & mut stack )))
+    }
+    /// Creates a new queue, or returns the URL of an existing one. When you request `CreateQueue`, you provide a name for the queue. To successfully create a new queue, you must provide a name that is unique within the scope of your own queues.
+    ///
+    /// If you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.
+    ///
+    /// You may pass one or more attributes in the request. If you do not provide a value for any attribute, the queue will have the default value for that attribute. Permitted attributes are the same that can be set using SetQueueAttributes.
+    ///
+    /// Use GetQueueUrl to get a queue's URL. GetQueueUrl requires only the `QueueName` parameter.
+    ///
+    /// If you provide the name of an existing queue, along with the exact names and values of all the queue's attributes, `CreateQueue` returns the queue URL for the existing queue. If the queue name, attribute names, or attribute values do not match an existing queue, `CreateQueue` returns an error.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn create_queue(&mut self, input: &CreateQueueRequest)
+     -> Result<CreateQueueResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "CreateQueue");
+        CreateQueueRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(CreateQueueResultParser:: parse_xml (
+                "CreateQueueResult" , & mut stack )))
+    }
+    /// Deletes the specified message from the specified queue. You specify the message by using the message's `receipt handle` and not the `message ID` you received when you sent the message. Even if the message is locked by another reader due to the visibility timeout setting, it is still deleted from the queue. If you leave a message in the queue for longer than the queue's configured retention period, Amazon SQS automatically deletes it.
+    ///
+    /// The receipt handle is associated with a specific instance of receiving the message. If you receive a message more than once, the receipt handle you get each time you receive the message is different. When you request `DeleteMessage`, if you don't provide the most recently received receipt handle for the message, the request will still succeed, but the message might not be deleted.
+    ///
+    /// It is possible you will receive a message even after you have deleted it. This might happen on rare occasions if one of the servers storing a copy of the message is unavailable when you request to delete the message. The copy remains on the server and might be returned to you again on a subsequent receive request. You should create your system to be idempotent so that receiving a particular message more than once is not a problem.
+    pub fn delete_message(&mut self, input: &DeleteMessageRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "DeleteMessage");
+        DeleteMessageRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Deletes up to ten messages from the specified queue. This is a batch version of DeleteMessage. The result of the delete action on each message is reported individually in the response.
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn delete_message_batch(&mut self, input: &DeleteMessageBatchRequest)
+     -> Result<DeleteMessageBatchResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "DeleteMessageBatch");
+        DeleteMessageBatchRequestWriter::write_params(&mut params, "",
+                                                      &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(DeleteMessageBatchResultParser:: parse_xml (
+                "DeleteMessageBatchResult" , & mut stack )))
+    }
+    /// Deletes the queue specified by the **queue URL**, regardless of whether the queue is empty. If the specified queue does not exist, Amazon SQS returns a successful response.
+    ///
+    /// Use `DeleteQueue` with care; once you delete your queue, any messages in the queue are no longer available.
+    ///
+    /// When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a SendMessage request might succeed, but after the 60 seconds, the queue and that message you sent no longer exist. Also, when you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.
+    ///
+    /// We reserve the right to delete queues that have had no activity for more than 30 days. For more information, see [How Amazon SQS Queues Work](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSConcepts.html) in the *Amazon SQS Developer Guide*.
+    pub fn delete_queue(&mut self, input: &DeleteQueueRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "DeleteQueue");
+        DeleteQueueRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Gets attributes for the specified queue. The following attributes are supported:
+    ///
+    /// * `All` - returns all values.
+    /// * `ApproximateNumberOfMessages` - returns the approximate number of visible messages in a queue. For more information, see [Resources Required to Process Messages](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ApproximateNumber.html) in the *Amazon SQS Developer Guide*.
+    /// * `ApproximateNumberOfMessagesNotVisible` - returns the approximate number of messages that are not timed-out and not deleted. For more information, see [Resources Required to Process Messages](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ApproximateNumber.html) in the *Amazon SQS Developer Guide*.
+    /// * `VisibilityTimeout` - returns the visibility timeout for the queue. For more information about visibility timeout, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.
+    /// * `CreatedTimestamp` - returns the time when the queue was created (epoch time in seconds).
+    /// * `LastModifiedTimestamp` - returns the time when the queue was last changed (epoch time in seconds).
+    /// * `Policy` - returns the queue's policy.
+    /// * `MaximumMessageSize` - returns the limit of how many bytes a message can contain before Amazon SQS rejects it.
+    /// * `MessageRetentionPeriod` - returns the number of seconds Amazon SQS retains a message.
+    /// * `QueueArn` - returns the queue's Amazon resource name (ARN).
+    /// * `ApproximateNumberOfMessagesDelayed` - returns the approximate number of messages that are pending to be added to the queue.
+    /// * `DelaySeconds` - returns the default delay on the queue in seconds.
+    /// * `ReceiveMessageWaitTimeSeconds` - returns the time for which a ReceiveMessage call will wait for a message to arrive.
+    /// * `RedrivePolicy` - returns the parameters for dead letter queue functionality of the source queue. For more information about RedrivePolicy and dead letter queues, see [Using Amazon SQS Dead Letter Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSDeadLetterQueue.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn get_queue_attributes(&mut self, input: &GetQueueAttributesRequest)
+     -> Result<GetQueueAttributesResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "GetQueueAttributes");
+        GetQueueAttributesRequestWriter::write_params(&mut params, "",
+                                                      &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(GetQueueAttributesResultParser:: parse_xml (
+                "GetQueueAttributesResult" , & mut stack )))
+    }
+    /// Returns the URL of an existing queue. This action provides a simple way to retrieve the URL of an Amazon SQS queue.
+    ///
+    /// To access a queue that belongs to another AWS account, use the `QueueOwnerAWSAccountId` parameter to specify the account ID of the queue's owner. The queue's owner must grant you permission to access the queue. For more information about shared queue access, see AddPermission or go to [Shared Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/acp-overview.html) in the *Amazon SQS Developer Guide*.
+    pub fn get_queue_url(&mut self, input: &GetQueueUrlRequest)
+     -> Result<GetQueueUrlResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "GetQueueUrl");
+        GetQueueUrlRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(GetQueueUrlResultParser:: parse_xml (
+                "GetQueueUrlResult" , & mut stack )))
+    }
+    /// Returns a list of your queues that have the RedrivePolicy queue attribute configured with a dead letter queue.
+    ///
+    /// For more information about using dead letter queues, see [Using Amazon SQS Dead Letter Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSDeadLetterQueue.html).
+    pub fn list_dead_letter_source_queues(&mut self,
+                                          input:
+                                              &ListDeadLetterSourceQueuesRequest)
+     -> Result<ListDeadLetterSourceQueuesResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "ListDeadLetterSourceQueues");
+        ListDeadLetterSourceQueuesRequestWriter::write_params(&mut params, "",
+                                                              &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(ListDeadLetterSourceQueuesResultParser:: parse_xml (
+                "ListDeadLetterSourceQueuesResult" , & mut stack )))
+    }
+    /// Returns a list of your queues. The maximum number of queues that can be returned is 1000. If you specify a value for the optional `QueueNamePrefix` parameter, only queues with a name beginning with the specified value are returned.
+    pub fn list_queues(&mut self, input: &ListQueuesRequest)
+     -> Result<ListQueuesResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "ListQueues");
+        ListQueuesRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(ListQueuesResultParser:: parse_xml (
+                "ListQueuesResult" , & mut stack )))
+    }
+    /// Deletes the messages in a queue specified by the **queue URL**.
+    ///
+    /// When you use the `PurgeQueue` API, the deleted messages in the queue cannot be retrieved.
+    ///
+    /// When you purge a queue, the message deletion process takes up to 60 seconds. All messages sent to the queue before calling `PurgeQueue` will be deleted; messages sent to the queue while it is being purged may be deleted. While the queue is being purged, messages sent to the queue before `PurgeQueue` was called may be received, but will be deleted within the next minute.
+    pub fn purge_queue(&mut self, input: &PurgeQueueRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "PurgeQueue");
+        PurgeQueueRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Retrieves one or more messages, with a maximum limit of 10 messages, from the specified queue. Long poll support is enabled by using the `WaitTimeSeconds` parameter. For more information, see [Amazon SQS Long Poll](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-long-polling.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Short poll is the default behavior where a weighted random set of machines is sampled on a `ReceiveMessage` call. This means only the messages on the sampled machines are returned. If the number of messages in the queue is small (less than 1000), it is likely you will get fewer messages than you requested per `ReceiveMessage` call. If the number of messages in the queue is extremely small, you might not receive any messages in a particular `ReceiveMessage` response; in which case you should repeat the request.
+    ///
+    /// For each message returned, the response includes the following:
+    ///
+    /// * Message body
+    /// * MD5 digest of the message body. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    /// * Message ID you received when you sent the message to the queue.
+    /// * Receipt handle.
+    /// * Message attributes.
+    /// * MD5 digest of the message attributes.
+    ///
+    /// The receipt handle is the identifier you must provide when deleting the message. For more information, see [Queue and Message Identifiers](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ImportantIdentifiers.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// You can provide the `VisibilityTimeout` parameter in your request, which will be applied to the messages that Amazon SQS returns in the response. If you do not include the parameter, the overall visibility timeout for the queue is used for the returned messages. For more information, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    pub fn receive_message(&mut self, input: &ReceiveMessageRequest)
+     -> Result<ReceiveMessageResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "ReceiveMessage");
+        ReceiveMessageRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(ReceiveMessageResultParser:: parse_xml (
+                "ReceiveMessageResult" , & mut stack )))
+    }
+    /// Revokes any permissions in the queue policy that matches the specified `Label` parameter. Only the owner of the queue can remove permissions.
+    pub fn remove_permission(&mut self, input: &RemovePermissionRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "RemovePermission");
+        RemovePermissionRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+    /// Delivers a message to the specified queue. With Amazon SQS, you now have the ability to send large payload messages that are up to 256KB (262,144 bytes) in size. To send large payloads, you must use an AWS SDK that supports SigV4 signing. To verify whether SigV4 is supported for an AWS SDK, check the SDK release notes.
+    ///
+    /// The following list shows the characters (in Unicode) allowed in your message, according to the W3C XML specification. For more information, go to [http://www.w3.org/TR/REC-xml/#charsets](http://www.w3.org/TR/REC-xml/#charsets) If you send any characters not included in the list, your request will be rejected.
+    ///
+    /// #x9 | #xA | #xD | [#x20 to #xD7FF] | [#xE000 to #xFFFD] | [#x10000 to #x10FFFF]
+    pub fn send_message(&mut self, input: &SendMessageRequest)
+     -> Result<SendMessageResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "SendMessage");
+        SendMessageRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(SendMessageResultParser:: parse_xml (
+                "SendMessageResult" , & mut stack )))
+    }
+    /// Delivers up to ten messages to the specified queue. This is a batch version of SendMessage. The result of the send action on each message is reported individually in the response. The maximum allowed individual message size is 256 KB (262,144 bytes).
+    ///
+    /// The maximum total payload size (i.e., the sum of all a batch's individual message lengths) is also 256 KB (262,144 bytes).
+    ///
+    /// If the `DelaySeconds` parameter is not specified for an entry, the default for the queue is used.
+    ///
+    /// The following list shows the characters (in Unicode) that are allowed in your message, according to the W3C XML specification. For more information, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html). If you send any characters that are not included in the list, your request will be rejected.
+    ///
+    /// #x9 | #xA | #xD | [#x20 to #xD7FF] | [#xE000 to #xFFFD] | [#x10000 to #x10FFFF]
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    pub fn send_message_batch(&mut self, input: &SendMessageBatchRequest)
+     -> Result<SendMessageBatchResult, AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "SendMessageBatch");
+        SendMessageBatchRequestWriter::write_params(&mut params, "", &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        let mut reader = EventReader::new(result);
+        let mut stack = XmlResponseFromAws::new(reader.events().peekable());
+        stack.next();
+        stack.next();
+        Ok(try!(SendMessageBatchResultParser:: parse_xml (
+                "SendMessageBatchResult" , & mut stack )))
+    }
+    /// Sets the value of one or more queue attributes. When you change a queue's attributes, the change can take up to 60 seconds for most of the attributes to propagate throughout the SQS system. Changes made to the `MessageRetentionPeriod` attribute can take up to 15 minutes.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    pub fn set_queue_attributes(&mut self, input: &SetQueueAttributesRequest)
+     -> Result<(), AWSError> {
+        try!(input . validate (  ));
+        let mut request =
+            SignedRequest::new("POST", "sqs", &self.region, "/");
+        let mut params = Params::new();
+        params.put("Action", "SetQueueAttributes");
+        SetQueueAttributesRequestWriter::write_params(&mut params, "",
+                                                      &input);
+        request.set_params(params);
+        let mut result =
+            request.sign_and_execute(try!(self . creds . get_credentials (
+                                          )));
+        let status = result.status.to_u16();
+        if !result.status.is_success() {
+            let mut body = String::new();
+            try!(result . read_to_string ( & mut body ));
+            return Err(AWSError::new(format!("{}: {}" , status , body)));
+        }
+        Ok(())
+    }
+}
+/// The operations of `SQSClient`, for code that should also work with a substitute client.
+pub trait Sqs {
+    /// Adds a permission to a queue for a specific [principal](http://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P). This allows for sharing access to the queue.
+    ///
+    /// When you create a queue, you have full control access rights for the queue. Only you (as owner of the queue) can grant or deny permissions to the queue. For more information about these permissions, see [Shared Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/acp-overview.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// `AddPermission` writes an Amazon SQS-generated policy. If you want to write your own policy, use SetQueueAttributes to upload your policy. For more information about writing your own policy, see [Using The Access Policy Language](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AccessPolicyLanguage.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn add_permission(&mut self, input: &AddPermissionRequest)
+    -> Result<(), AWSError>;
+    /// Changes the visibility timeout of a specified message in a queue to a new value. The maximum allowed timeout value you can set the value to is 12 hours. This means you can't extend the timeout of a message in an existing queue to more than a total visibility timeout of 12 hours. (For more information visibility timeout, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.)
+    ///
+    /// For example, let's say you have a message and its default message visibility timeout is 5 minutes. After 3 minutes, you call `ChangeMessageVisiblity` with a timeout of 10 minutes. At that time, the timeout for the message would be extended by 10 minutes beyond the time of the ChangeMessageVisibility call. This results in a total visibility timeout of 13 minutes. You can continue to call ChangeMessageVisibility to extend the visibility timeout to a maximum of 12 hours. If you try to extend beyond 12 hours, the request will be rejected.
+    ///
+    /// There is a 120,000 limit for the number of inflight messages per queue. Messages are inflight after they have been received from the queue by a consuming component, but have not yet been deleted from the queue. If you reach the 120,000 limit, you will receive an OverLimit error message from Amazon SQS. To help avoid reaching the limit, you should delete the messages from the queue after they have been processed. You can also increase the number of queues you use to process the messages.
+    ///
+    /// If you attempt to set the `VisibilityTimeout` to an amount more than the maximum time left, Amazon SQS returns an error. It will not automatically recalculate and increase the timeout to the maximum time remaining.
+    ///
+    /// Unlike with a queue, when you change the visibility timeout for a specific message, that timeout value is applied immediately but is not saved in memory for that message. If you don't delete a message after it is received, the visibility timeout for the message the next time it is received reverts to the original timeout value, not the value you set with the `ChangeMessageVisibility` action.
+    fn change_message_visibility(&mut self,
+                                 input: &ChangeMessageVisibilityRequest)
+    -> Result<(), AWSError>;
+    /// Changes the visibility timeout of multiple messages. This is a batch version of ChangeMessageVisibility. The result of the action on each message is reported individually in the response. You can send up to 10 ChangeMessageVisibility requests with each `ChangeMessageVisibilityBatch` action.
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn change_message_visibility_batch(&mut self,
+                                       input:
+                                           &ChangeMessageVisibilityBatchRequest)
+    -> Result<ChangeMessageVisibilityBatchResult, AWSError>;
+    /// Creates a new queue, or returns the URL of an existing one. When you request `CreateQueue`, you provide a name for the queue. To successfully create a new queue, you must provide a name that is unique within the scope of your own queues.
+    ///
+    /// If you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.
+    ///
+    /// You may pass one or more attributes in the request. If you do not provide a value for any attribute, the queue will have the default value for that attribute. Permitted attributes are the same that can be set using SetQueueAttributes.
+    ///
+    /// Use GetQueueUrl to get a queue's URL. GetQueueUrl requires only the `QueueName` parameter.
+    ///
+    /// If you provide the name of an existing queue, along with the exact names and values of all the queue's attributes, `CreateQueue` returns the queue URL for the existing queue. If the queue name, attribute names, or attribute values do not match an existing queue, `CreateQueue` returns an error.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn create_queue(&mut self, input: &CreateQueueRequest)
+    -> Result<CreateQueueResult, AWSError>;
+    /// Deletes the specified message from the specified queue. You specify the message by using the message's `receipt handle` and not the `message ID` you received when you sent the message. Even if the message is locked by another reader due to the visibility timeout setting, it is still deleted from the queue. If you leave a message in the queue for longer than the queue's configured retention period, Amazon SQS automatically deletes it.
+    ///
+    /// The receipt handle is associated with a specific instance of receiving the message. If you receive a message more than once, the receipt handle you get each time you receive the message is different. When you request `DeleteMessage`, if you don't provide the most recently received receipt handle for the message, the request will still succeed, but the message might not be deleted.
+    ///
+    /// It is possible you will receive a message even after you have deleted it. This might happen on rare occasions if one of the servers storing a copy of the message is unavailable when you request to delete the message. The copy remains on the server and might be returned to you again on a subsequent receive request. You should create your system to be idempotent so that receiving a particular message more than once is not a problem.
+    fn delete_message(&mut self, input: &DeleteMessageRequest)
+    -> Result<(), AWSError>;
+    /// Deletes up to ten messages from the specified queue. This is a batch version of DeleteMessage. The result of the delete action on each message is reported individually in the response.
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn delete_message_batch(&mut self, input: &DeleteMessageBatchRequest)
+    -> Result<DeleteMessageBatchResult, AWSError>;
+    /// Deletes the queue specified by the **queue URL**, regardless of whether the queue is empty. If the specified queue does not exist, Amazon SQS returns a successful response.
+    ///
+    /// Use `DeleteQueue` with care; once you delete your queue, any messages in the queue are no longer available.
+    ///
+    /// When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a SendMessage request might succeed, but after the 60 seconds, the queue and that message you sent no longer exist. Also, when you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.
+    ///
+    /// We reserve the right to delete queues that have had no activity for more than 30 days. For more information, see [How Amazon SQS Queues Work](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSConcepts.html) in the *Amazon SQS Developer Guide*.
+    fn delete_queue(&mut self, input: &DeleteQueueRequest)
+    -> Result<(), AWSError>;
+    /// Gets attributes for the specified queue. The following attributes are supported:
+    ///
+    /// * `All` - returns all values.
+    /// * `ApproximateNumberOfMessages` - returns the approximate number of visible messages in a queue. For more information, see [Resources Required to Process Messages](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ApproximateNumber.html) in the *Amazon SQS Developer Guide*.
+    /// * `ApproximateNumberOfMessagesNotVisible` - returns the approximate number of messages that are not timed-out and not deleted. For more information, see [Resources Required to Process Messages](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ApproximateNumber.html) in the *Amazon SQS Developer Guide*.
+    /// * `VisibilityTimeout` - returns the visibility timeout for the queue. For more information about visibility timeout, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.
+    /// * `CreatedTimestamp` - returns the time when the queue was created (epoch time in seconds).
+    /// * `LastModifiedTimestamp` - returns the time when the queue was last changed (epoch time in seconds).
+    /// * `Policy` - returns the queue's policy.
+    /// * `MaximumMessageSize` - returns the limit of how many bytes a message can contain before Amazon SQS rejects it.
+    /// * `MessageRetentionPeriod` - returns the number of seconds Amazon SQS retains a message.
+    /// * `QueueArn` - returns the queue's Amazon resource name (ARN).
+    /// * `ApproximateNumberOfMessagesDelayed` - returns the approximate number of messages that are pending to be added to the queue.
+    /// * `DelaySeconds` - returns the default delay on the queue in seconds.
+    /// * `ReceiveMessageWaitTimeSeconds` - returns the time for which a ReceiveMessage call will wait for a message to arrive.
+    /// * `RedrivePolicy` - returns the parameters for dead letter queue functionality of the source queue. For more information about RedrivePolicy and dead letter queues, see [Using Amazon SQS Dead Letter Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSDeadLetterQueue.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn get_queue_attributes(&mut self, input: &GetQueueAttributesRequest)
+    -> Result<GetQueueAttributesResult, AWSError>;
+    /// Returns the URL of an existing queue. This action provides a simple way to retrieve the URL of an Amazon SQS queue.
+    ///
+    /// To access a queue that belongs to another AWS account, use the `QueueOwnerAWSAccountId` parameter to specify the account ID of the queue's owner. The queue's owner must grant you permission to access the queue. For more information about shared queue access, see AddPermission or go to [Shared Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/acp-overview.html) in the *Amazon SQS Developer Guide*.
+    fn get_queue_url(&mut self, input: &GetQueueUrlRequest)
+    -> Result<GetQueueUrlResult, AWSError>;
+    /// Returns a list of your queues that have the RedrivePolicy queue attribute configured with a dead letter queue.
+    ///
+    /// For more information about using dead letter queues, see [Using Amazon SQS Dead Letter Queues](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/SQSDeadLetterQueue.html).
+    fn list_dead_letter_source_queues(&mut self,
+                                      input:
+                                          &ListDeadLetterSourceQueuesRequest)
+    -> Result<ListDeadLetterSourceQueuesResult, AWSError>;
+    /// Returns a list of your queues. The maximum number of queues that can be returned is 1000. If you specify a value for the optional `QueueNamePrefix` parameter, only queues with a name beginning with the specified value are returned.
+    fn list_queues(&mut self, input: &ListQueuesRequest)
+    -> Result<ListQueuesResult, AWSError>;
+    /// Deletes the messages in a queue specified by the **queue URL**.
+    ///
+    /// When you use the `PurgeQueue` API, the deleted messages in the queue cannot be retrieved.
+    ///
+    /// When you purge a queue, the message deletion process takes up to 60 seconds. All messages sent to the queue before calling `PurgeQueue` will be deleted; messages sent to the queue while it is being purged may be deleted. While the queue is being purged, messages sent to the queue before `PurgeQueue` was called may be received, but will be deleted within the next minute.
+    fn purge_queue(&mut self, input: &PurgeQueueRequest)
+    -> Result<(), AWSError>;
+    /// Retrieves one or more messages, with a maximum limit of 10 messages, from the specified queue. Long poll support is enabled by using the `WaitTimeSeconds` parameter. For more information, see [Amazon SQS Long Poll](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-long-polling.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Short poll is the default behavior where a weighted random set of machines is sampled on a `ReceiveMessage` call. This means only the messages on the sampled machines are returned. If the number of messages in the queue is small (less than 1000), it is likely you will get fewer messages than you requested per `ReceiveMessage` call. If the number of messages in the queue is extremely small, you might not receive any messages in a particular `ReceiveMessage` response; in which case you should repeat the request.
+    ///
+    /// For each message returned, the response includes the following:
+    ///
+    /// * Message body
+    /// * MD5 digest of the message body. For information about MD5, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html).
+    /// * Message ID you received when you sent the message to the queue.
+    /// * Receipt handle.
+    /// * Message attributes.
+    /// * MD5 digest of the message attributes.
+    ///
+    /// The receipt handle is the identifier you must provide when deleting the message. For more information, see [Queue and Message Identifiers](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/ImportantIdentifiers.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// You can provide the `VisibilityTimeout` parameter in your request, which will be applied to the messages that Amazon SQS returns in the response. If you do not include the parameter, the overall visibility timeout for the queue is used for the returned messages. For more information, see [Visibility Timeout](http://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/AboutVT.html) in the *Amazon SQS Developer Guide*.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    fn receive_message(&mut self, input: &ReceiveMessageRequest)
+    -> Result<ReceiveMessageResult, AWSError>;
+    /// Revokes any permissions in the queue policy that matches the specified `Label` parameter. Only the owner of the queue can remove permissions.
+    fn remove_permission(&mut self, input: &RemovePermissionRequest)
+    -> Result<(), AWSError>;
+    /// Delivers a message to the specified queue. With Amazon SQS, you now have the ability to send large payload messages that are up to 256KB (262,144 bytes) in size. To send large payloads, you must use an AWS SDK that supports SigV4 signing. To verify whether SigV4 is supported for an AWS SDK, check the SDK release notes.
+    ///
+    /// The following list shows the characters (in Unicode) allowed in your message, according to the W3C XML specification. For more information, go to [http://www.w3.org/TR/REC-xml/#charsets](http://www.w3.org/TR/REC-xml/#charsets) If you send any characters not included in the list, your request will be rejected.
+    ///
+    /// #x9 | #xA | #xD | [#x20 to #xD7FF] | [#xE000 to #xFFFD] | [#x10000 to #x10FFFF]
+    fn send_message(&mut self, input: &SendMessageRequest)
+    -> Result<SendMessageResult, AWSError>;
+    /// Delivers up to ten messages to the specified queue. This is a batch version of SendMessage. The result of the send action on each message is reported individually in the response. The maximum allowed individual message size is 256 KB (262,144 bytes).
+    ///
+    /// The maximum total payload size (i.e., the sum of all a batch's individual message lengths) is also 256 KB (262,144 bytes).
+    ///
+    /// If the `DelaySeconds` parameter is not specified for an entry, the default for the queue is used.
+    ///
+    /// The following list shows the characters (in Unicode) that are allowed in your message, according to the W3C XML specification. For more information, go to [http://www.faqs.org/rfcs/rfc1321.html](http://www.faqs.org/rfcs/rfc1321.html). If you send any characters that are not included in the list, your request will be rejected.
+    ///
+    /// #x9 | #xA | #xD | [#x20 to #xD7FF] | [#xE000 to #xFFFD] | [#x10000 to #x10FFFF]
+    ///
+    /// Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of 200.
+    ///
+    /// Some API actions take lists of parameters. These lists are specified using the `param.n` notation. Values of `n` are integers starting from 1. For example, a parameter list with two elements looks like this:
+    ///
+    /// `&Attribute.1=this`
+    ///
+    /// `&Attribute.2=that`
+    fn send_message_batch(&mut self, input: &SendMessageBatchRequest)
+    -> Result<SendMessageBatchResult, AWSError>;
+    /// Sets the value of one or more queue attributes. When you change a queue's attributes, the change can take up to 60 seconds for most of the attributes to propagate throughout the SQS system. Changes made to the `MessageRetentionPeriod` attribute can take up to 15 minutes.
+    ///
+    /// Going forward, new attributes might be added. If you are writing code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.
+    fn set_queue_attributes(&mut self, input: &SetQueueAttributesRequest)
+    -> Result<(), AWSError>;
+}
+impl <'a> Sqs for SQSClient<'a> {
+    fn add_permission(&mut self, input: &AddPermissionRequest)
+     -> Result<(), AWSError> {
+        SQSClient::add_permission(self, input)
+    }
+    fn change_message_visibility(&mut self,
+                                 input: &ChangeMessageVisibilityRequest)
+     -> Result<(), AWSError> {
+        SQSClient::change_message_visibility(self, input)
+    }
+    fn change_message_visibility_batch(&mut self,
+                                       input:
+                                           &ChangeMessageVisibilityBatchRequest)
+     -> Result<ChangeMessageVisibilityBatchResult, AWSError> {
+        SQSClient::change_message_visibility_batch(self, input)
+    }
+    fn create_queue(&mut self, input: &CreateQueueRequest)
+     -> Result<CreateQueueResult, AWSError> {
+        SQSClient::create_queue(self, input)
+    }
+    fn delete_message(&mut self, input: &DeleteMessageRequest)
+     -> Result<(), AWSError> {
+        SQSClient::delete_message(self, input)
+    }
+    fn delete_message_batch(&mut self, input: &DeleteMessageBatchRequest)
+     -> Result<DeleteMessageBatchResult, AWSError> {
+        SQSClient::delete_message_batch(self, input)
+    }
+    fn delete_queue(&mut self, input: &DeleteQueueRequest)
+     -> Result<(), AWSError> {
+        SQSClient::delete_queue(self, input)
+    }
+    fn get_queue_attributes(&mut self, input: &GetQueueAttributesRequest)
+     -> Result<GetQueueAttributesResult, AWSError> {
+        SQSClient::get_queue_attributes(self, input)
+    }
+    fn get_queue_url(&mut self, input: &GetQueueUrlRequest)
+     -> Result<GetQueueUrlResult, AWSError> {
+        SQSClient::get_queue_url(self, input)
+    }
+    fn list_dead_letter_source_queues(&mut self,
+                                      input:
+                                          &ListDeadLetterSourceQueuesRequest)
+     -> Result<ListDeadLetterSourceQueuesResult, AWSError> {
+        SQSClient::list_dead_letter_source_queues(self, input)
+    }
+    fn list_queues(&mut self, input: &ListQueuesRequest)
+     -> Result<ListQueuesResult, AWSError> {
+        SQSClient::list_queues(self, input)
+    }
+    fn purge_queue(&mut self, input: &PurgeQueueRequest)
+     -> Result<(), AWSError> {
+        SQSClient::purge_queue(self, input)
+    }
+    fn receive_message(&mut self, input: &ReceiveMessageRequest)
+     -> Result<ReceiveMessageResult, AWSError> {
+        SQSClient::receive_message(self, input)
+    }
+    fn remove_permission(&mut self, input: &RemovePermissionRequest)
+     -> Result<(), AWSError> {
+        SQSClient::remove_permission(self, input)
+    }
+    fn send_message(&mut self, input: &SendMessageRequest)
+     -> Result<SendMessageResult, AWSError> {
+        SQSClient::send_message(self, input)
+    }
+    fn send_message_batch(&mut self, input: &SendMessageBatchRequest)
+     -> Result<SendMessageBatchResult, AWSError> {
+        SQSClient::send_message_batch(self, input)
+    }
+    fn set_queue_attributes(&mut self, input: &SetQueueAttributesRequest)
+     -> Result<(), AWSError> {
+        SQSClient::set_queue_attributes(self, input)
+    }
+}
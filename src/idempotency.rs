@@ -0,0 +1,32 @@
+//! Idempotency tokens for operations that accept them.
+//!
+//! Botocore marks members like ECS's `clientToken` as idempotency tokens. Generated clients fill
+//! any that an input leaves empty with a new random UUID. The generated `new` constructors and
+//! `fill_idempotency_tokens` methods set them on the input itself, so sending it again carries the
+//! same token and AWS can recognize the retry instead of performing it twice.
+
+use openssl::crypto::rand::rand_bytes;
+
+/// A random (version 4) UUID, such as `5c8a1b1e-3f2d-4c6e-9a7b-0e1d2c3b4a59`.
+pub fn new_token() -> String {
+    let mut bytes = rand_bytes(16);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: Vec<String> = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}-{}-{}-{}-{}", hex[0..4].concat(), hex[4..6].concat(), hex[6..8].concat(), hex[8..10].concat(), hex[10..16].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::new_token;
+
+    #[test]
+    fn tokens_are_random_uuids() {
+        let token = new_token();
+        let groups: Vec<usize> = token.split('-').map(|group| group.len()).collect();
+        assert_eq!(groups, vec![8, 4, 4, 4, 12]);
+        assert_eq!(&token[14..15], "4");
+        assert!(token != new_token());
+    }
+}
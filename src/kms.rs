@@ -1,5 +1,6 @@
 //! The AWS KMS API.
 
-#![allow(non_snake_case)]
-
+#[cfg(feature = "regenerate")]
 include!(concat!(env!("OUT_DIR"), "/kms.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/kms.rs");
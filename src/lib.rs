@@ -21,19 +21,27 @@ extern crate chrono;
 extern crate serde_json;
 extern crate serde;
 
+#[macro_use]
+extern crate lazy_static;
 #[macro_use]
 extern crate log;
 
 #[macro_use] pub mod params;
 #[macro_use] pub mod signature;
+pub mod blob;
+pub mod crc32;
 pub mod credentials;
 pub mod error;
+pub mod idempotency;
 pub mod xmlutil;
 pub mod regions;
 pub mod request;
+pub mod timestamp;
 
 #[cfg(feature = "dynamodb")]
 pub mod dynamodb;
+#[cfg(feature = "ec2")]
+pub mod ec2;
 #[cfg(feature = "ecs")]
 pub mod ecs;
 #[cfg(feature = "kms")]
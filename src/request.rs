@@ -17,6 +17,7 @@ pub fn send_request(signed_request: &SignedRequest) -> Response {
         "PUT" => Method::Put,
         "DELETE" => Method::Delete,
         "GET" => Method::Get,
+        "HEAD" => Method::Head,
         _ => Method::Get, // make this unreachable! ?
     };
 
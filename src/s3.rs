@@ -1,5 +1,534 @@
 //! The AWS S3 API.
 
-#![allow(unused_variables, unused_mut)]
+use std::io::{BufReader, Read};
+use std::str::FromStr;
 
+use openssl::crypto::hash::Type::MD5;
+use openssl::crypto::hash::hash;
+use rustc_serialize::base64::{ToBase64, STANDARD};
+use xml::EventReader;
+
+use credentials::AWSCredentialsProvider;
+use error::AWSError;
+use params::{Params, SQSParams};
+use regions::{self, region_in_aws_format};
+use signature::SignedRequest;
+use xmlutil::{Next, Peek, XmlParseError, XmlResponseFromAws};
+use xmlutil::{at_end_element, characters, end_element, peek_at_name, start_element};
+
+// include the code generated from the S3 botocore templates
+#[cfg(feature = "regenerate")]
 include!(concat!(env!("OUT_DIR"), "/s3.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/s3.rs");
+
+const CHUNK_TO_READ: usize = 5000;
+const S3_MINIMUM_PART_SIZE: usize = 5242880;
+
+/// Wraps the generated S3 client with a higher level interface
+pub struct S3Helper<'a> {
+	client: S3Client<'a>
+}
+
+/// Canned ACL for S3
+#[derive(Debug)]
+pub enum CannedAcl {
+	Private,
+	PublicRead,
+	PublicReadWrite,
+	AuthenticatedRead,
+	BucketOwnerRead,
+	BucketOwnerFullControl,
+}
+
+impl<'a> S3Helper<'a> {
+
+	/// Creates a new S3 helper
+	pub fn new<CP: AWSCredentialsProvider + 'a>(credentials: CP, region:&'a regions::Region) -> S3Helper<'a> {
+		S3Helper { client: S3Client::new(credentials, region) }
+	}
+
+	/// Lists buckets
+	pub fn list_buckets(&mut self) -> Result<ListBucketsOutput, AWSError> {
+		self.client.list_buckets()
+	}
+
+	/// Creates bucket in default us-east-1/us-standard region.
+	pub fn create_bucket(&mut self, bucket_name: &str, canned_acl: Option<CannedAcl>) -> Result<CreateBucketOutput, AWSError> {
+		self.create_bucket_in_region(bucket_name, &regions::Region::UsEast1, canned_acl)
+	}
+
+	/// Creates bucket in specified region.
+	pub fn create_bucket_in_region(&mut self, bucket_name: &str, region: &regions::Region, canned_acl: Option<CannedAcl>) -> Result<CreateBucketOutput, AWSError> {
+		let mut request = CreateBucketRequest::default();
+
+		// us-east-1 is us-standard, don't send a location constraint
+		if needs_create_bucket_config(region) {
+			let mut create_config = CreateBucketConfiguration::default();
+			create_config.location_constraint = Some(BucketLocationConstraint::from(&region_in_aws_format(region)[..]));
+			request.create_bucket_configuration = Some(create_config);
+		}
+
+		request.bucket = bucket_name.to_string();
+		request.acl = canned_acl.map(|acl| BucketCannedACL::from(&canned_acl_in_aws_format(&acl)[..]));
+
+		self.client.create_bucket(&request)
+	}
+
+	/// Deletes specified bucket.  The request goes to the region the helper was created with.
+	pub fn delete_bucket(&mut self, bucket_name: &str) -> Result<(), AWSError> {
+		let mut request = DeleteBucketRequest::default();
+		request.bucket = bucket_name.to_string();
+		self.client.delete_bucket(&request)
+	}
+
+	/// Download a named object from bucket
+	pub fn get_object(&mut self, bucket_name: &str, object_name: &str) ->  Result<GetObjectOutput, AWSError> {
+		let mut request = GetObjectRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		self.client.get_object(&request)
+	}
+
+	/// Upload an object to specified bucket
+	pub fn put_object(&mut self, bucket_name: &str, object_name: &str, object_as_bytes: &[u8]) ->  Result<PutObjectOutput, AWSError> {
+		self.put_object_with_optional_reduced_redundancy(bucket_name, object_name, object_as_bytes, false)
+	}
+
+	/// Helper: uploads object to specified bucket using reduced redudancy storage settings
+	pub fn put_object_with_reduced_redundancy(&mut self, bucket_name: &str, object_name: &str, object_as_bytes: &[u8]) ->  Result<PutObjectOutput, AWSError> {
+		self.put_object_with_optional_reduced_redundancy(bucket_name, object_name, object_as_bytes, true)
+	}
+
+	fn put_object_with_optional_reduced_redundancy(&mut self, bucket_name: &str, object_name: &str,
+		object_as_bytes: &[u8], reduced_redundancy: bool) ->  Result<PutObjectOutput, AWSError> {
+
+		let mut request = PutObjectRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		request.body = Some(object_as_bytes.into());
+		if reduced_redundancy {
+			request.storage_class = Some(StorageClass::ReducedRedundancy);
+		}
+		self.put_object_with_request(&mut request)
+	}
+
+	/// Uploads object to specified S3 bucket with server side encryption at rest.
+	pub fn put_object_with_aws_encryption(&mut self, bucket_name: &str, object_name: &str,
+		object_as_bytes: &[u8]) ->  Result<PutObjectOutput, AWSError> {
+
+		let mut request = PutObjectRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		request.body = Some(object_as_bytes.into());
+		request.server_side_encryption = Some(ServerSideEncryption::Aes256);
+
+		self.put_object_with_request(&mut request)
+	}
+
+	/// Uploads object to specified S3 bucket using AWS KMS for key management of encryption at rest.
+	pub fn put_object_with_kms_encryption(&mut self, bucket_name: &str, object_name: &str,
+		object_as_bytes: &[u8], key_id: &str) ->  Result<PutObjectOutput, AWSError> {
+
+		let mut request = PutObjectRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		request.body = Some(object_as_bytes.into());
+		request.server_side_encryption = Some(ServerSideEncryption::AwsKms);
+		request.ssekms_key_id = Some(key_id.to_string());
+
+		self.put_object_with_request(&mut request)
+	}
+
+	/// Uploads object: lets sender specify options.
+	/// The most generic of put_object: caller specifies the whole request.
+	pub fn put_object_with_request(&mut self, request: &mut PutObjectRequest) -> Result<PutObjectOutput, AWSError> {
+		// content_md5 hashing for everyone!
+		if request.content_md5.is_none() {
+			request.content_md5 = request.body.as_ref().map(|body| hash(MD5, body).to_base64(STANDARD));
+		}
+
+		self.client.put_object(request)
+	}
+
+	/// Uploads a multi-part object to specified bucket.  Allows for large file uploads.
+	/// Returns S3's response to completing the upload, which includes the new object's ETag.
+	pub fn put_multipart_object<T: Read>(&mut self, bucket_name: &str, object_name: &str,
+		object_as_reader: &mut T) -> Result<CompleteMultipartUploadOutput, AWSError> {
+
+		let mut multipart_upload_request = CreateMultipartUploadRequest::default();
+		multipart_upload_request.key = object_name.to_string();
+		multipart_upload_request.bucket = bucket_name.to_string();
+
+		let upload_id = match try!(self.client.create_multipart_upload(&multipart_upload_request)).upload_id {
+			Some(upload_id) => upload_id,
+			None => return Err(AWSError::new("S3 didn't return an upload ID for the multipart upload")),
+		};
+
+		let mut buffered_reader = BufReader::new(object_as_reader);
+		let parts = try!(self.upload_chunks(&mut buffered_reader, &bucket_name, &upload_id, &object_name));
+
+		if parts.is_empty() {
+			return Err(AWSError::new("Can't finish upload on 0 parts."));
+		}
+
+		let mut completed_upload = CompletedMultipartUpload::default();
+		completed_upload.parts = Some(parts);
+
+		let mut complete_upload = CompleteMultipartUploadRequest::default();
+		complete_upload.key = object_name.to_string();
+		complete_upload.bucket = bucket_name.to_string();
+		complete_upload.upload_id = upload_id;
+		complete_upload.multipart_upload = Some(completed_upload);
+
+		self.client.complete_multipart_upload(&complete_upload)
+	}
+
+	fn upload_chunks<T: Read>(&mut self, buffered_reader: &mut BufReader<T>,
+			bucket_name: &str, upload_id: &str, object_name: &str) -> Result<Vec<CompletedPart>, AWSError> {
+
+		let mut s3_chunk : Vec<u8> = Vec::with_capacity(S3_MINIMUM_PART_SIZE + CHUNK_TO_READ);
+		let mut buffer = [0u8; CHUNK_TO_READ];
+
+		let mut parts : Vec<CompletedPart> = Vec::new();
+		let mut part_number = 1;
+		let mut more_chunks_to_go = true;
+		while more_chunks_to_go {
+			let bytes_read = try!(buffered_reader.read(&mut buffer));
+			if bytes_read == 0 {
+				more_chunks_to_go = false;
+			}
+			s3_chunk.extend_from_slice(&buffer[..bytes_read]);
+
+			if s3_chunk.len() >= S3_MINIMUM_PART_SIZE || (!more_chunks_to_go && !s3_chunk.is_empty()) {
+				let mut part = CompletedPart::default();
+				part.e_tag = Some(try!(self.upload_a_part(&s3_chunk, part_number, &bucket_name, &upload_id, &object_name)));
+				part.part_number = Some(part_number);
+				parts.push(part);
+
+				s3_chunk.clear();
+				part_number += 1;
+			}
+		}
+		Ok(parts)
+	}
+
+	fn upload_a_part(&mut self, buffer: &[u8], part_number: i32,
+			bucket_name: &str, upload_id: &str, object_name: &str) -> Result<String, AWSError> {
+
+		let mut upload_part_request = UploadPartRequest::default();
+		upload_part_request.content_md5 = Some(hash(MD5, buffer).to_base64(STANDARD));
+		upload_part_request.body = Some(buffer.into());
+		upload_part_request.bucket = bucket_name.to_string();
+		upload_part_request.upload_id = upload_id.to_string();
+		upload_part_request.part_number = part_number;
+		upload_part_request.key = object_name.to_string();
+
+		match try!(self.client.upload_part(&upload_part_request)).e_tag {
+			Some(e_tag) => Ok(e_tag),
+			None => Err(AWSError::new(format!("S3 didn't return an ETag for part {}", part_number))),
+		}
+	}
+
+	/// Lists multipart uploads not yet completed for specified bucket
+	pub fn list_multipart_uploads_for_bucket(&mut self, bucket_name: &str) -> Result<ListMultipartUploadsOutput, AWSError> {
+		let mut request = ListMultipartUploadsRequest::default();
+		request.bucket = bucket_name.to_string();
+
+		match self.client.list_multipart_uploads(&request) {
+			Err(why) => Err(AWSError::new(format!("Couldn't do list_multipart_uploads: {:?}", why))),
+			Ok(result) => Ok(result),
+		}
+	}
+
+	/// Deletes specified object from specified bucket.
+	pub fn delete_object(&mut self, bucket_name: &str, object_name: &str) ->  Result<DeleteObjectOutput, AWSError> {
+		let mut request = DeleteObjectRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		self.client.delete_object(&request)
+	}
+
+	/// Abort multipart upload.
+	pub fn abort_multipart_upload(&mut self, bucket_name: &str, object_name: &str, upload_id: &str) ->  Result<AbortMultipartUploadOutput, AWSError> {
+		let mut request = AbortMultipartUploadRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		request.upload_id = upload_id.to_string();
+		self.client.abort_multipart_upload(&request)
+	}
+
+	/// List parts from a multiupload request.
+	pub fn multipart_upload_list_parts(&mut self, bucket_name: &str, object_name: &str, upload_id: &str) ->  Result<ListPartsOutput, AWSError> {
+		let mut request = ListPartsRequest::default();
+		request.key = object_name.to_string();
+		request.bucket = bucket_name.to_string();
+		request.upload_id = upload_id.to_string();
+		self.client.list_parts(&request)
+	}
+}
+
+/// Helper function to determine if a create config is needed.
+pub fn needs_create_bucket_config(region: &regions::Region) -> bool {
+	match *region {
+		regions::Region::UsEast1 => false,
+		_ => true,
+	}
+}
+
+/// Maps canned acl to AWS format.  EG public-read.
+pub fn canned_acl_in_aws_format(canned_acl: &CannedAcl) -> String {
+	match *canned_acl {
+		CannedAcl::Private => "private".to_string(),
+		CannedAcl::PublicRead => "public-read".to_string(),
+		CannedAcl::PublicReadWrite => "public-read-write".to_string(),
+		CannedAcl::AuthenticatedRead => "authenticated-read".to_string(),
+		CannedAcl::BucketOwnerRead => "bucket-owner-read".to_string(),
+		CannedAcl::BucketOwnerFullControl => "bucket-owner-full-control".to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use xml::reader::*;
+	use std::io::BufReader;
+	use std::fs::File;
+	use super::{BucketLocationConstraint, CompletedMultipartUpload, CompletedPart, CreateBucketConfiguration, StorageClass};
+	use super::{BodyParser, BodySerializer, PutObjectRequest};
+	use blob::Blob;
+	use serde_json;
+	use super::needs_create_bucket_config;
+	use super::ListBucketsOutputParser;
+	use super::CreateMultipartUploadOutputParser;
+	use super::CompleteMultipartUploadOutputParser;
+	use super::CompletedMultipartUploadSerializer;
+	use super::CreateBucketConfigurationSerializer;
+	use super::ListMultipartUploadsOutputParser;
+	use super::ListPartsOutputParser;
+	use xmlutil::*;
+	use regions::{Region, region_in_aws_format};
+	use timestamp::Timestamp;
+
+	#[test]
+	fn list_buckets_happy_path() {
+		let file = File::open("tests/sample-data/s3_get_buckets.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = ListBucketsOutputParser::parse_xml("ListAllMyBucketsResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse list_buckets"),
+			Ok(result) => assert!(result.buckets.unwrap().len() > 0),
+		}
+	}
+
+	#[test]
+	fn initiate_multipart_upload_happy_path() {
+		let file = File::open("tests/sample-data/s3_initiate_multipart_upload.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = CreateMultipartUploadOutputParser::parse_xml("InitiateMultipartUploadResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse initiate_multipart_upload"),
+			Ok(result) => {
+				assert_eq!(result.bucket, Some("example-bucket".to_string()));
+				assert_eq!(result.key, Some("example-object".to_string()));
+				assert_eq!(result.upload_id, Some("VXBsb2FkIElEIGZvciA2aWWpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA".to_string()));
+			},
+		}
+	}
+
+	#[test]
+	fn complete_multipart_upload_happy_path() {
+		let file = File::open("tests/sample-data/s3_complete_multipart_upload.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = CompleteMultipartUploadOutputParser::parse_xml("CompleteMultipartUploadResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse s3_complete_multipart_upload"),
+			Ok(result) => {
+				assert_eq!(result.bucket, Some("testbucket2".to_string()));
+				assert_eq!(result.key, Some("foo.zip".to_string()));
+				assert_eq!(result.e_tag, Some("\"525a81fcbc4181997bd96e4096fa7304-1\"".to_string()));
+			}
+		}
+	}
+
+	#[test]
+	fn multipart_upload_xml_looks_right() {
+		let mut first_part = CompletedPart::default();
+		first_part.e_tag = Some("etag1".to_string());
+		first_part.part_number = Some(1);
+		let mut second_part = CompletedPart::default();
+		second_part.e_tag = Some("etag2".to_string());
+		second_part.part_number = Some(2);
+
+		let mut upload = CompletedMultipartUpload::default();
+		upload.parts = Some(vec![first_part, second_part]);
+
+		let expected_string = "<CompleteMultipartUpload><Part><ETag>etag1</ETag><PartNumber>1</PartNumber></Part><Part><ETag>etag2</ETag><PartNumber>2</PartNumber></Part></CompleteMultipartUpload>";
+
+		assert_eq!(expected_string, CompletedMultipartUploadSerializer::serialize("CompleteMultipartUpload", &upload));
+	}
+
+	#[test]
+	fn create_bucket_configuration_xml_looks_right() {
+		let mut config = CreateBucketConfiguration::default();
+		config.location_constraint = Some(BucketLocationConstraint::from(&region_in_aws_format(&Region::UsWest2)[..]));
+
+		assert_eq!(CreateBucketConfigurationSerializer::serialize_members(&config), "<LocationConstraint>us-west-2</LocationConstraint>");
+	}
+
+	#[test]
+	fn list_multipart_upload_happy_path() {
+		let file = File::open("tests/sample-data/s3_list_multipart_uploads.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = ListMultipartUploadsOutputParser::parse_xml("ListMultipartUploadsResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse s3_list_multipart_uploads.xml"),
+			Ok(result) => {
+				assert_eq!(result.bucket, Some("rusoto1440826511".to_string()));
+				assert_eq!(result.key_marker, Some("".to_string()));
+				let uploads = result.uploads.unwrap();
+				let ref an_upload = uploads[0];
+				assert_eq!(an_upload.upload_id, Some("eUeGzA6xR2jAH7KUhTSwrrNVfu8XPIYdoWpa7meOiceoGQLQhtKfPg_APCnuVRsyWd7bx8SS5jNssgdtTU5tTziGOz.j1URgseoqpdHqnyZRikJHTLd6iXF.GjKBEhky".to_string()));
+				assert_eq!(an_upload.key, Some("join.me.zip".to_string()));
+
+				let initiator = an_upload.initiator.as_ref().unwrap();
+				assert_eq!(initiator.id, Some("arn:aws:iam::347452556412:user/matthew".to_string()));
+				assert_eq!(initiator.display_name, Some("matthew".to_string()));
+
+				assert_eq!(an_upload.initiated, Some(Timestamp::from_iso8601("2015-09-01T19:22:56.000Z").unwrap()));
+
+				let owner = an_upload.owner.as_ref().unwrap();
+				assert_eq!(owner.id, Some("b84c6b0c308085829b6562b586f6664fc00faab6cfd441e90ad418ea916eed83".to_string()));
+				assert_eq!(owner.display_name, Some("matthew".to_string()));
+
+				assert_eq!(an_upload.storage_class, Some(StorageClass::Standard));
+			}
+		}
+	}
+
+	#[test]
+	fn list_multipart_upload_parts_happy_path() {
+		let file = File::open("tests/sample-data/s3_multipart_uploads_with_parts.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = ListPartsOutputParser::parse_xml("ListPartsResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse s3_multipart_uploads_with_parts.xml"),
+			Ok(result) => {
+				assert_eq!(result.bucket, Some("rusoto1440826511".to_string()));
+				assert_eq!(result.upload_id, Some("PeePB_uORK5f2AURP_SWcQ4NO1P1oqnGNNNFK3nhFfzMeksdvG7x7nFfH1qk7a3HSossNYB7t8QhcN1Fg6ax7AXbwvAKIZ9DilB4tUcpM7qyUEgkszN4iDmMvSaImGFK".to_string()));
+				assert_eq!(result.key, Some("testfile.zip".to_string()));
+
+				let initiator = result.initiator.as_ref().unwrap();
+				assert_eq!(initiator.id, Some("arn:aws:iam::347452556412:user/matthew".to_string()));
+				assert_eq!(initiator.display_name, Some("matthew".to_string()));
+
+				let owner = result.owner.as_ref().unwrap();
+				assert_eq!(owner.id, Some("b84c6b0c308085829b6562b586f6664fc00faab6cfd441e90ad418ea916eed83".to_string()));
+				assert_eq!(owner.display_name, Some("matthew".to_string()));
+
+				assert_eq!(result.storage_class, Some(StorageClass::Standard));
+
+				let parts = result.parts.unwrap();
+				assert_eq!(parts.len(), 2);
+				assert_eq!(parts[0].part_number, Some(1));
+				assert_eq!(parts[0].e_tag, Some("\"ddcaa99616d7cd06d0a5abfef6ccebbb\"".to_string()));
+				assert_eq!(parts[0].size, Some(5242880));
+				assert_eq!(parts[0].last_modified, Some(Timestamp::from_iso8601("2015-09-08T21:02:04.000Z").unwrap()));
+			}
+		}
+	}
+
+	#[test]
+	fn list_multipart_upload_no_uploads() {
+		let file = File::open("tests/sample-data/s3_list_multipart_uploads_no_multipart_uploads.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		let result = ListMultipartUploadsOutputParser::parse_xml("ListMultipartUploadsResult", &mut reader);
+
+		match result {
+			Err(_) => panic!("Couldn't parse s3_list_multipart_uploads_no_multipart_uploads.xml"),
+			Ok(result) => {
+				assert_eq!(result.bucket, Some("rusoto1440826568".to_string()));
+				assert!(result.uploads.is_none());
+			}
+		}
+	}
+
+	#[test]
+	fn list_multipart_upload_invalid_max_uploads() {
+		let file = File::open("tests/sample-data/s3_list_multipart_uploads_invalid_max_uploads.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+
+		assert!(ListMultipartUploadsOutputParser::parse_xml("ListMultipartUploadsResult", &mut reader).is_err());
+	}
+
+	#[test]
+	fn blobs_are_base64_in_xml() {
+		assert_eq!(BodySerializer::serialize("Body", &Blob(b"rusoto".to_vec())), "<Body>cnVzb3Rv</Body>");
+
+		let file = File::open("tests/sample-data/s3_body_blob.xml").unwrap();
+		let file = BufReader::new(file);
+		let mut my_parser  = EventReader::new(file);
+		let my_stack = my_parser.events().peekable();
+		let mut reader = XmlResponseFromFile::new(my_stack);
+		reader.next(); // xml start node
+		assert_eq!(BodyParser::parse_xml("Body", &mut reader).unwrap(), Blob(b"rusoto".to_vec()));
+	}
+
+	#[test]
+	fn blobs_are_base64_in_json() {
+		let mut request = PutObjectRequest::default();
+		request.body = Some(Blob(b"rusoto".to_vec()));
+
+		assert!(serde_json::to_string(&request).unwrap().contains("\"Body\":\"cnVzb3Rv\""));
+	}
+
+	#[test]
+	fn create_bucket_constraint_needed() {
+		let region = Region::UsWest2;
+		match needs_create_bucket_config(&region) {
+			false => panic!("us-west-2 should have bucket constraint."),
+			true => return,
+		}
+	}
+
+	#[test]
+	fn create_bucket_no_constraint_needed() {
+		let region = Region::UsEast1;
+		match needs_create_bucket_config(&region) {
+			true => panic!("us-east-1 should not have bucket constraint."),
+			false => return,
+		}
+	}
+}
@@ -44,6 +44,8 @@ pub struct SignedRequest<'a> {
 	headers: BTreeMap<String, Vec<Vec<u8>>>,
 	params: Params,
 	hostname: Option<String>,
+	signing_name: Option<String>,
+	signing_region: Option<String>,
 	payload: Option<&'a [u8]>,
 	content_type: Option<String>,
 	canonical_query_string: String,
@@ -61,6 +63,8 @@ impl <'a> SignedRequest <'a> {
 			headers: BTreeMap::new(),
 			params: Params::new(),
 			hostname: None,
+			signing_name: None,
+			signing_region: None,
 			payload: None,
 			content_type: None,
 			canonical_query_string: String::new(),
@@ -76,6 +80,16 @@ impl <'a> SignedRequest <'a> {
 		self.hostname = hostname;
 	}
 
+	/// Signs the request for a service name other than the one in its hostname
+	pub fn set_signing_name(&mut self, signing_name: &str) {
+		self.signing_name = Some(signing_name.to_string());
+	}
+
+	/// Signs the request for a region other than the client's, as global endpoints require
+	pub fn set_signing_region(&mut self, signing_region: &str) {
+		self.signing_region = Some(signing_region.to_string());
+	}
+
 	pub fn set_payload(&mut self, payload: Option<&'a [u8]>) {
 		self.payload = payload;
 	}
@@ -107,6 +121,20 @@ impl <'a> SignedRequest <'a> {
 		}
 	}
 
+	pub fn signing_name(&self) -> &str {
+		match self.signing_name {
+			Some(ref name) => name,
+			None => &self.service
+		}
+	}
+
+	pub fn signing_region(&self) -> String {
+		match self.signing_region {
+			Some(ref region) => region.to_string(),
+			None => region_in_aws_format(&self.region)
+		}
+	}
+
 	// If the key exists in headers, set it to blank/unoccupied:
 	pub fn remove_header(&mut self, key: &str) {
 		let key_lower = key.to_ascii_lowercase().to_string();
@@ -210,11 +238,11 @@ impl <'a> SignedRequest <'a> {
 
 		// use the hashed canonical request to build the string to sign
 		let hashed_canonical_request = to_hexdigest_from_string(&canonical_request);
-		let scope = format!("{}/{}/{}/aws4_request", date.strftime("%Y%m%d").unwrap(), region_in_aws_format(&self.region), &self.service);
+		let scope = format!("{}/{}/{}/aws4_request", date.strftime("%Y%m%d").unwrap(), self.signing_region(), self.signing_name());
 		let string_to_sign = string_to_sign(date, &hashed_canonical_request, &scope);
 
 		// construct the signing key and sign the string with it
-		let signing_key = signing_key(&creds.get_aws_secret_key(), date, &region_in_aws_format(&self.region), &self.service);
+		let signing_key = signing_key(&creds.get_aws_secret_key(), date, &self.signing_region(), self.signing_name());
 		let signature = signature(&string_to_sign, signing_key);
 
 		// build the actual auth header
@@ -439,6 +467,20 @@ mod tests {
 		assert_eq!("test-hostname", request.hostname());
 	}
 
+	#[test]
+	fn signing_name_and_region_default_to_service_and_region() {
+		let region = Region::UsWest2;
+		let mut request = SignedRequest::new("POST", "streams.dynamodb", &region, "/");
+		assert_eq!("streams.dynamodb", request.signing_name());
+		assert_eq!("us-west-2", request.signing_region());
+
+		request.set_signing_name("dynamodb");
+		request.set_signing_region("us-east-1");
+		assert_eq!("dynamodb", request.signing_name());
+		assert_eq!("us-east-1", request.signing_region());
+		assert_eq!("streams.dynamodb.us-west-2.amazonaws.com", request.hostname());
+	}
+
 	#[test]
 	fn get_redirect_location_from_s3() {
 		let file = File::open("tests/sample-data/s3_temp_redirect.xml").unwrap();
@@ -2,6 +2,7 @@
 
 #![allow(unused_variables, unused_mut)]
 
+use std::io::Read;
 use std::str::FromStr;
 
 use xml::EventReader;
@@ -9,11 +10,13 @@ use xml::EventReader;
 use credentials::AWSCredentialsProvider;
 use error::AWSError;
 use params::{Params, SQSParams};
-use regions::Region;
 use signature::SignedRequest;
 use xmlutil::{Next, Peek, XmlParseError, XmlResponseFromAws};
 use xmlutil::{characters, end_element, peek_at_name, start_element};
 
 // include the code generated from the SQS botocore templates
+#[cfg(feature = "regenerate")]
 include!(concat!(env!("OUT_DIR"), "/sqs.rs"));
+#[cfg(not(feature = "regenerate"))]
+include!("generated/sqs.rs");
 
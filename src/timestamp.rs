@@ -0,0 +1,120 @@
+//! Timestamps in AWS requests and responses.
+//!
+//! Generated shapes hold timestamps as a `Timestamp`, a `DateTime<UTC>` that can be written in the
+//! formats AWS protocols use: seconds since the epoch in JSON bodies, ISO 8601 in query parameters
+//! and XML, and RFC 822 in HTTP headers.
+
+use std::fmt;
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone, Timelike, UTC};
+use chrono::format::ParseError;
+use serde;
+
+/// A point in time, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub DateTime<UTC>);
+
+impl Timestamp {
+    /// The current time.
+    pub fn now() -> Timestamp {
+        Timestamp(UTC::now())
+    }
+
+    /// A time given as seconds since the epoch, with any fraction of a second.
+    pub fn from_epoch_seconds(seconds: f64) -> Timestamp {
+        let whole = seconds.floor();
+        Timestamp(UTC.timestamp(whole as i64, ((seconds - whole) * 1e9) as u32))
+    }
+
+    /// Seconds since the epoch, with any fraction of a second.
+    pub fn epoch_seconds(&self) -> f64 {
+        self.0.timestamp() as f64 + self.0.nanosecond() as f64 / 1e9
+    }
+
+    /// Parses an ISO 8601 time such as `2015-01-25T08:00:00.000Z`.
+    pub fn from_iso8601(value: &str) -> Result<Timestamp, ParseError> {
+        DateTime::parse_from_rfc3339(value).map(|time| Timestamp(time.with_timezone(&UTC)))
+    }
+
+    /// The time in ISO 8601 format.
+    pub fn iso8601(&self) -> String {
+        self.0.to_rfc3339()
+    }
+
+    /// Parses an RFC 822 time such as `Sun, 25 Jan 2015 08:00:00 GMT`.
+    pub fn from_rfc822(value: &str) -> Result<Timestamp, ParseError> {
+        DateTime::parse_from_rfc2822(value).map(|time| Timestamp(time.with_timezone(&UTC)))
+    }
+
+    /// The time in RFC 822 format, as used in HTTP headers.
+    pub fn rfc822(&self) -> String {
+        self.0.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Timestamp {
+        Timestamp(UTC.timestamp(0, 0))
+    }
+}
+
+impl Deref for Timestamp {
+    type Target = DateTime<UTC>;
+
+    fn deref(&self) -> &DateTime<UTC> {
+        &self.0
+    }
+}
+
+impl From<DateTime<UTC>> for Timestamp {
+    fn from(time: DateTime<UTC>) -> Timestamp {
+        Timestamp(time)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.iso8601())
+    }
+}
+
+// JSON services send timestamps as seconds since the epoch
+impl serde::Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error> where S: serde::Serializer {
+        serde::Serialize::serialize(&self.epoch_seconds(), serializer)
+    }
+}
+
+impl serde::Deserialize for Timestamp {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Timestamp, D::Error> where D: serde::Deserializer {
+        let seconds: f64 = try!(serde::Deserialize::deserialize(deserializer));
+        Ok(Timestamp::from_epoch_seconds(seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timestamp;
+
+    #[test]
+    fn iso8601_round_trip() {
+        let time = Timestamp::from_iso8601("2015-01-25T08:00:00.000Z").unwrap();
+        assert_eq!(time.epoch_seconds(), 1422172800.0);
+        assert_eq!(Timestamp::from_iso8601(&time.iso8601()).unwrap(), time);
+    }
+
+    #[test]
+    fn rfc822_round_trip() {
+        let time = Timestamp::from_rfc822("Sun, 25 Jan 2015 08:00:00 GMT").unwrap();
+        assert_eq!(time.epoch_seconds(), 1422172800.0);
+        assert_eq!(time.rfc822(), "Sun, 25 Jan 2015 08:00:00 GMT");
+    }
+
+    #[test]
+    fn epoch_seconds_keep_fractions() {
+        let time = Timestamp::from_epoch_seconds(1422172800.5);
+        assert_eq!(time.epoch_seconds(), 1422172800.5);
+        assert!(Timestamp::from_iso8601("not a time").is_err());
+    }
+}
@@ -4,7 +4,10 @@
 //! Also provides a method of supplying an XML stack from a file for testing purposes.
 
 use std::iter::Peekable;
-use std::num::ParseIntError;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
+use chrono::format::ParseError as ChronoParseError;
+use rustc_serialize::base64::FromBase64Error;
 use hyper::client::response::*;
 use std::collections::HashMap;
 use xml::reader::*;
@@ -64,6 +67,22 @@ impl From<ParseIntError> for XmlParseError{
     fn from(_e:ParseIntError) -> XmlParseError { XmlParseError::new("ParseIntError") }
 }
 
+impl From<ChronoParseError> for XmlParseError {
+    fn from(err: ChronoParseError) -> XmlParseError { XmlParseError(format!("{}", err)) }
+}
+
+impl From<ParseFloatError> for XmlParseError {
+    fn from(err: ParseFloatError) -> XmlParseError { XmlParseError(format!("{}", err)) }
+}
+
+impl From<ParseBoolError> for XmlParseError {
+    fn from(err: ParseBoolError) -> XmlParseError { XmlParseError(format!("{}", err)) }
+}
+
+impl From<FromBase64Error> for XmlParseError {
+    fn from(err: FromBase64Error) -> XmlParseError { XmlParseError(format!("{}", err)) }
+}
+
 /// Testing helper, reads from file
 pub struct XmlResponseFromFile<'a> {
 	xml_stack: Peekable<Events<'a, BufReader<File>>>,
@@ -116,6 +135,14 @@ pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError
 	}
 }
 
+/// true if the next event ends the current element, as it does for an empty one
+pub fn at_end_element<T: Peek + Next>(stack: &mut T) -> bool {
+	match stack.peek() {
+		Some(&XmlEvent::EndElement { .. }) => true,
+		_ => false,
+	}
+}
+
 /// get the name of the current element in the stack.  throw a parse error if it's not a StartElement
 pub fn peek_at_name<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError> {
 	let current = stack.peek();
@@ -158,6 +185,24 @@ pub fn end_element<T: Peek + Next>(element_name: &str, stack: &mut T)  -> Result
 	}
 }
 
+/// consume the next element, including everything inside it
+pub fn skip_tree<T: Peek + Next>(stack: &mut T) {
+	let mut depth: usize = 0;
+	loop {
+		match stack.next() {
+			Some(XmlEvent::StartElement { .. }) => depth += 1,
+			Some(XmlEvent::EndElement { .. }) => {
+				depth -= 1;
+				if depth == 0 {
+					return;
+				}
+			},
+			None => return,
+			_ => (),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,4 +295,19 @@ mod tests {
 	    }
 	}
 
+	#[test]
+	fn skip_tree_happy_path() {
+	    let file = File::open("tests/sample-data/list_queues_with_queue.xml").unwrap();
+	    let file = BufReader::new(file);
+	    let mut my_parser  = EventReader::new(file);
+	    let my_stack = my_parser.events().peekable();
+	    let mut reader = XmlResponseFromFile::new(my_stack);
+
+	    // skip two leading fields since we ignore them (xml declaration, return type declaration)
+	    reader.next();
+	    reader.next();
+
+	    skip_tree(&mut reader);
+	    assert_eq!(peek_at_name(&mut reader).unwrap(), "ResponseMetadata");
+	}
 }
@@ -1,11 +1,8 @@
 #![cfg(feature = "dynamodb")]
 
-#[macro_use]
 extern crate rusoto;
 extern crate time;
 
-use std::thread;
-
 use time::get_time;
 
 use rusoto::credentials::DefaultAWSCredentialsProviderChain;
@@ -13,15 +10,20 @@ use rusoto::dynamodb::{
     AttributeDefinition,
     AttributeValue,
     CreateTableInput,
+    DeleteTableInput,
+    DescribeTableInput,
+    DynamoDBClient,
     DynamoDBError,
-    DynamoDBHelper,
     GetItemInput,
     GetItemOutput,
     Key,
     KeySchemaElement,
+    KeyType,
+    ListTablesInput,
+    ProvisionedThroughput,
     PutItemInput,
     PutItemInputAttributeMap,
-    get_str_from_attribute,
+    ScalarAttributeType,
 };
 use rusoto::regions::Region;
 
@@ -30,7 +32,7 @@ fn main() {
     let creds = DefaultAWSCredentialsProviderChain::new();
     let region = Region::UsWest2;
 
-    let mut dynamodb = DynamoDBHelper::new(creds, &region);
+    let mut dynamodb = DynamoDBClient::new(creds, &region);
 
     match dynamo_list_tables_tests(&mut dynamodb) {
         Ok(_) => {
@@ -61,18 +63,14 @@ fn main() {
         }
     }
 
-    let mut item = Key::default();
-    item.insert("string".to_string(), val!(S => "foo"));
-    item.insert("number".to_string(), val!(N => "1234"));
-
-    match dynamo_get_item_test(&mut dynamodb, &table_name, item) {
+    match dynamo_get_item_test(&mut dynamodb, &table_name, item_key()) {
         Ok(item_from_dynamo) => {
             println!("Got item back from Dynamo");
-            match item_from_dynamo.Item {
+            match item_from_dynamo.item {
                 None => println!("nothing received from Dynamo, item may not exist"),
                 Some(attributes_map) => {
                     for (column_name, value) in attributes_map {
-                        println!("found column name '{}' with value of '{}'", column_name, get_str_from_attribute(&value).unwrap());
+                        println!("found column name '{}' with value of '{:?}'", column_name, value.s.or(value.n));
                     }
                 },
             }
@@ -92,17 +90,14 @@ fn main() {
     }
 
     println!("Trying the dynamo get again");
-    item = Key::default();
-    item.insert("string".to_string(), val!(S => "foo"));
-    item.insert("number".to_string(), val!(N => "1234"));
-    match dynamo_get_item_test(&mut dynamodb, &table_name, item) {
+    match dynamo_get_item_test(&mut dynamodb, &table_name, item_key()) {
         Ok(item_from_dynamo) => {
             println!("Got item back from Dynamo");
-            match item_from_dynamo.Item {
+            match item_from_dynamo.item {
                 None => println!("nothing received from Dynamo, item may not exist"),
                 Some(attributes_map) => {
                     for (column_name, value) in attributes_map {
-                        println!("found column name '{}' with value of '{}'", column_name, get_str_from_attribute(&value).unwrap());
+                        println!("found column name '{}' with value of '{:?}'", column_name, value.s.or(value.n));
                     }
                 },
             }
@@ -123,77 +118,64 @@ fn main() {
 
 }
 
-fn dynamo_list_tables_tests(dynamodb: &mut DynamoDBHelper) -> Result<(), DynamoDBError> {
-    let response = try!(dynamodb.list_tables());
+// the key of the item that's put and read back
+fn item_key() -> Key {
+    let mut key = Key::default();
+    key.insert("string".to_string(), AttributeValue::new().with_s("foo"));
+    key.insert("number".to_string(), AttributeValue::new().with_n("1234"));
+    key
+}
+
+fn dynamo_list_tables_tests(dynamodb: &mut DynamoDBClient) -> Result<(), DynamoDBError> {
+    let response = try!(dynamodb.list_tables(&ListTablesInput::new()));
     println!("{:#?}", response);
     Ok(())
 }
 
-fn dynamo_create_table_test(dynamodb: &mut DynamoDBHelper,
+fn dynamo_create_table_test(dynamodb: &mut DynamoDBClient,
                             table_name: &str)
                             -> Result<(), DynamoDBError> {
     println!("Creating table {} ", table_name);
 
-    let input = CreateTableInput::new()
-                        .with_name(table_name)
-                        .with_write_capacity(1)
-                        .with_read_capacity(1)
-                        .with_attributes(attributes!("string" => "S", "number" => "N"))
-                        .with_key_schema(key_schema!("string" => "HASH", "number" => "RANGE"));
+    let input = CreateTableInput::new(
+        vec![
+            AttributeDefinition::new("string", ScalarAttributeType::S),
+            AttributeDefinition::new("number", ScalarAttributeType::N),
+        ],
+        vec![
+            KeySchemaElement::new("string", KeyType::Hash),
+            KeySchemaElement::new("number", KeyType::Range),
+        ],
+        ProvisionedThroughput::new(1, 1),
+        table_name);
 
     let _result = try!(dynamodb.create_table(&input));
     Ok(())
 }
 
-fn dynamo_put_item_test(dynamodb: &mut DynamoDBHelper, table_name: &str) -> Result<(), DynamoDBError> {
-    let mut input = PutItemInput::default();
-
+fn dynamo_put_item_test(dynamodb: &mut DynamoDBClient, table_name: &str) -> Result<(), DynamoDBError> {
     let mut item = PutItemInputAttributeMap::default();
-    item.insert("string".to_string(), val!(S => "foo"));
-    item.insert("number".to_string(), val!(N => "1234"));
-
-    input.Item = item;
-    input.TableName = table_name.to_string();
+    item.insert("string".to_string(), AttributeValue::new().with_s("foo"));
+    item.insert("number".to_string(), AttributeValue::new().with_n("1234"));
 
-    try!(dynamodb.put_item(&input));
+    try!(dynamodb.put_item(&PutItemInput::new(item, table_name)));
 
     Ok(())
 }
 
-fn dynamo_get_item_test(dynamodb: &mut DynamoDBHelper, table_name: &str, item_key: Key) -> Result<GetItemOutput, DynamoDBError> {
-    let mut item_request = GetItemInput::default();
-    item_request.Key = item_key;
-    item_request.TableName = table_name.to_string();
-
-    match dynamodb.get_item(&item_request) {
-        Err(why) => Err(why),
-        Ok(output) => Ok(output),
-    }
+fn dynamo_get_item_test(dynamodb: &mut DynamoDBClient, table_name: &str, item_key: Key) -> Result<GetItemOutput, DynamoDBError> {
+    dynamodb.get_item(&GetItemInput::new(item_key, table_name))
 }
 
-fn dynamo_describe_wait_test(dynamodb: &mut DynamoDBHelper,
+fn dynamo_describe_wait_test(dynamodb: &mut DynamoDBClient,
                              table_name: &str)
                              -> Result<(), DynamoDBError> {
-
-    loop {
-        let result = try!(dynamodb.describe_table(table_name));
-
-        if let Some(ref status) = result.get_status() {
-            if status == "ACTIVE" {
-                break;
-            } else {
-                println!("\t{} not ready - {}", table_name, status);
-                thread::sleep_ms(1000);
-            }
-        }
-
-    }
-    Ok(())
+    dynamodb.wait_until_table_exists(&DescribeTableInput::new(table_name))
 }
 
-fn dynamo_delete_table_test(dynamodb: &mut DynamoDBHelper,
+fn dynamo_delete_table_test(dynamodb: &mut DynamoDBClient,
                             table_name: &str)
                             -> Result<(), DynamoDBError> {
-    let _result = try!(dynamodb.delete_table(table_name));
+    let _result = try!(dynamodb.delete_table(&DeleteTableInput::new(table_name)));
     Ok(())
 }
@@ -0,0 +1,25 @@
+#![cfg(feature = "ec2")]
+
+extern crate rusoto;
+
+use rusoto::ec2::{DescribeInstancesRequest, EC2Client};
+use rusoto::credentials::DefaultAWSCredentialsProviderChain;
+use rusoto::regions::Region;
+
+#[test]
+fn main() {
+    let credentials = DefaultAWSCredentialsProviderChain::new();
+    let region = Region::UsEast1;
+    let mut ec2 = EC2Client::new(credentials, &region);
+
+    match ec2.describe_instances(&DescribeInstancesRequest::default()) {
+        Ok(output) => {
+            for reservation in output.reservations.unwrap_or(vec![]) {
+                println!("reservation -> {:?}", reservation.reservation_id);
+            }
+        },
+        Err(err) => {
+            panic!("Error describing instances {:#?}", err);
+        }
+    }
+}
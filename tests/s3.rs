@@ -17,6 +17,7 @@ use rusoto::credentials::DefaultAWSCredentialsProviderChain;
 use rusoto::error::AWSError;
 use rusoto::s3::{
     CannedAcl,
+    CompleteMultipartUploadOutput,
     DeleteObjectOutput,
     GetObjectOutput,
     PutObjectOutput,
@@ -119,7 +120,7 @@ fn all_s3_tests() {
     // 	Ok(_) => (),
     // }
 
-    // match s3_delete_bucket_test(&mut s3, &bucket_name, &region) {
+    // match s3_delete_bucket_test(&mut s3, &bucket_name) {
     //     Ok(_) => { info!("Everything worked for S3 delete bucket."); },
     //     Err(err) => { info!("Got error in s3 delete bucket: {}", err); }
     // }
@@ -132,7 +133,7 @@ fn all_s3_tests() {
     //     Err(err) => { info!("Got error in s3 create bucket: {}", err); }
     // }
     //
-    // match s3_delete_bucket_test(&mut s3, &bucket_name, &region) {
+    // match s3_delete_bucket_test(&mut s3, &bucket_name) {
     //     Ok(_) => { info!("Everything worked for S3 delete bucket."); },
     //     Err(err) => { info!("Got error in s3 delete bucket: {}", err); }
     // }
@@ -165,7 +166,7 @@ fn s3_abort_multipart_uploads(s3: &mut S3Helper, bucket: &str, object: &str, upl
 fn s3_list_buckets_tests(s3: &mut S3Helper) -> Result<(), AWSError> {
     let response = try!(s3.list_buckets());
     info!("Got list of buckets: {:?}", response);
-    for q in response.buckets {
+    for q in response.buckets.unwrap_or(vec![]) {
         info!("Existing bucket: {:?}", q.name);
     }
 
@@ -227,7 +228,7 @@ fn s3_put_object_with_request_specified_test(s3: &mut S3Helper, bucket: &str) ->
             let mut request = PutObjectRequest::default();
             request.key = "sample-credentials".to_string();
             request.bucket = bucket.to_string();
-            request.body = Some(&contents);
+            request.body = Some(contents.into());
             // request.content_md5 = Some("foo".to_string());
 
             let response = try!(s3.put_object_with_request(&mut request));
@@ -237,7 +238,7 @@ fn s3_put_object_with_request_specified_test(s3: &mut S3Helper, bucket: &str) ->
     }
 }
 
-fn s3_multipart_upload_test(s3: &mut S3Helper, bucket: &str) -> Result<PutObjectOutput, AWSError> {
+fn s3_multipart_upload_test(s3: &mut S3Helper, bucket: &str) -> Result<CompleteMultipartUploadOutput, AWSError> {
     // Set to a > 5 MB file for testing:
     let mut f = File::open("testfile.zip").unwrap();
 
@@ -263,7 +264,7 @@ fn s3_create_bucket_test(s3: &mut S3Helper, bucket: &str, region: &Region, canne
     Ok(())
 }
 
-fn s3_delete_bucket_test(s3: &mut S3Helper, bucket: &str, region: &Region) -> Result<(), AWSError> {
-    try!(s3.delete_bucket(bucket, &region));
+fn s3_delete_bucket_test(s3: &mut S3Helper, bucket: &str) -> Result<(), AWSError> {
+    try!(s3.delete_bucket(bucket));
     Ok(())
 }